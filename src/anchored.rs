@@ -0,0 +1,328 @@
+use std::{
+    env,
+    error::Error,
+    fmt,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use crate::{home, RelativePathBuf, SugarPath, CWD};
+
+/// A well-known directory that an [`AnchoredPathBuf`] is relative to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    /// The home directory of the current user.
+    Home,
+    /// The current working directory.
+    Cwd,
+    /// The directory for user configuration: `%APPDATA%` on Windows, `$XDG_CONFIG_HOME` or `~/.config` elsewhere.
+    ConfigDir,
+    /// The directory of the `Cargo.toml` of the package being built, tested or run by Cargo: `$CARGO_MANIFEST_DIR`.
+    ManifestDir,
+    /// The output directory of a build script: `$OUT_DIR`.
+    OutDir,
+}
+
+impl Anchor {
+    /// The name the anchor is serialized as: `home`, `cwd`, `config_dir`, `manifest_dir` or `out_dir`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Anchor::Home => "home",
+            Anchor::Cwd => "cwd",
+            Anchor::ConfigDir => "config_dir",
+            Anchor::ManifestDir => "manifest_dir",
+            Anchor::OutDir => "out_dir",
+        }
+    }
+
+    /// The anchor with the given [`name`](Anchor::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "home" => Some(Anchor::Home),
+            "cwd" => Some(Anchor::Cwd),
+            "config_dir" => Some(Anchor::ConfigDir),
+            "manifest_dir" => Some(Anchor::ManifestDir),
+            "out_dir" => Some(Anchor::OutDir),
+            _ => None,
+        }
+    }
+
+    /// The environment variable that holds the directory of the anchor, for the anchors that are only found this way.
+    ///
+    /// Cargo sets these while building a package, so build scripts and procedural macros see them at run time, and so do tests and binaries run through Cargo.
+    pub fn env_var(self) -> Option<&'static str> {
+        match self {
+            Anchor::ManifestDir => Some("CARGO_MANIFEST_DIR"),
+            Anchor::OutDir => Some("OUT_DIR"),
+            Anchor::Home | Anchor::Cwd | Anchor::ConfigDir => None,
+        }
+    }
+
+    /// The normalized absolute path of the anchor on this machine, if it can be found.
+    ///
+    /// Directories coming from environment variables have to be absolute.
+    pub fn dir(self) -> Option<PathBuf> {
+        let absolute = |path: PathBuf| path.is_absolute().then(|| path.normalize());
+        let var = |name: &str| env::var_os(name).map(PathBuf::from).and_then(absolute);
+        if let Some(name) = self.env_var() {
+            return var(name);
+        }
+        match self {
+            Anchor::Home => absolute(home::home_dir(None)?),
+            Anchor::Cwd => Some(CWD.clone()),
+            Anchor::ConfigDir | Anchor::ManifestDir | Anchor::OutDir => {
+                if cfg!(target_family = "windows") {
+                    var("APPDATA")
+                } else {
+                    var("XDG_CONFIG_HOME")
+                        .or_else(|| Some(Anchor::Home.dir()?.join(".config").normalize()))
+                }
+            }
+        }
+    }
+
+    /// Joins `path` onto the directory of the anchor and normalizes the result, so data files are found the same way whatever the current directory of the process. An absolute `path` is only normalized.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::Anchor;
+    ///
+    /// let manifest = Anchor::ManifestDir.resolve("tests/../Cargo.toml").unwrap();
+    /// assert_eq!(manifest, Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"));
+    /// ```
+    pub fn resolve(self, path: impl AsRef<Path>) -> Result<PathBuf, AnchoredPathError> {
+        let dir = self.dir().ok_or(AnchoredPathError::UnknownAnchor(self))?;
+        Ok(dir.join(path).normalize())
+    }
+}
+
+/// Where the anchors of an [`AnchoredPathBuf`] are on this machine.
+///
+/// Like `BaseDir` for the serde adapters, this is a type parameter so that deserializing can use it. Tests, or tools that sandbox their users, can implement it to point the anchors elsewhere.
+pub trait AnchorDirs {
+    fn anchor_dir(anchor: Anchor) -> Option<PathBuf>;
+}
+
+/// The [`AnchorDirs`] of the current process, see [`Anchor::dir`].
+pub struct DefaultAnchors;
+
+impl AnchorDirs for DefaultAnchors {
+    fn anchor_dir(anchor: Anchor) -> Option<PathBuf> {
+        anchor.dir()
+    }
+}
+
+/// Why an [`AnchoredPathBuf`] couldn't be created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnchoredPathError {
+    /// The path relative to the anchor has a root or a Windows prefix.
+    NotRelative(PathBuf),
+    /// The directory of the anchor can't be found on this machine.
+    UnknownAnchor(Anchor),
+}
+
+impl fmt::Display for AnchoredPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnchoredPathError::NotRelative(path) => write!(f, "{:?} is not relative", path),
+            AnchoredPathError::UnknownAnchor(anchor) => {
+                write!(f, "can't find the {} directory", anchor.name())
+            }
+        }
+    }
+}
+
+impl Error for AnchoredPathError {}
+
+/// A path stored as an [`Anchor`] plus a normalized relative path, so that it keeps pointing at the same place on another machine.
+///
+/// With the `serde` feature it serializes as `{ "anchor": "home", "rel": "projects/app" }`, with `/` as the separator whatever the platform, and deserializing resolves it again against the anchors of the machine it's loaded on, see [`AnchorDirs`].
+///
+/// ```rust
+/// use std::path::{Path, PathBuf};
+/// use sugar_path::{Anchor, AnchorDirs, AnchoredPathBuf};
+///
+/// struct Fixtures;
+///
+/// impl AnchorDirs for Fixtures {
+///     fn anchor_dir(anchor: Anchor) -> Option<PathBuf> {
+///         match anchor {
+///             Anchor::Home => Some(PathBuf::from("/home/me")),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// #[cfg(target_family = "unix")]
+/// {
+///     let path = AnchoredPathBuf::<Fixtures>::from_path("/home/me/./projects/app").unwrap();
+///     assert_eq!(path.anchor(), Anchor::Home);
+///     assert_eq!(path.rel(), Path::new("projects/app"));
+///     assert_eq!(path.path(), Path::new("/home/me/projects/app"));
+/// }
+/// ```
+pub struct AnchoredPathBuf<A = DefaultAnchors> {
+    anchor: Anchor,
+    rel: RelativePathBuf,
+    path: PathBuf,
+    anchors: PhantomData<A>,
+}
+
+impl<A: AnchorDirs> AnchoredPathBuf<A> {
+    /// Anchors the normalized `rel` at `anchor`, failing if `rel` isn't relative or the anchor can't be found.
+    pub fn new(anchor: Anchor, rel: impl AsRef<Path>) -> Result<Self, AnchoredPathError> {
+        let rel = rel.as_ref();
+        let rel = RelativePathBuf::new(rel)
+            .map_err(|_| AnchoredPathError::NotRelative(rel.to_path_buf()))?;
+        let dir = A::anchor_dir(anchor).ok_or(AnchoredPathError::UnknownAnchor(anchor))?;
+        let path = dir.join(&rel).normalize();
+        Ok(Self {
+            anchor,
+            rel,
+            path,
+            anchors: PhantomData,
+        })
+    }
+
+    /// Anchors the resolved `path` at the deepest of the [`Home`](Anchor::Home), [`Cwd`](Anchor::Cwd) and [`ConfigDir`](Anchor::ConfigDir) directories that contains it, or `None` if none does.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let path = path.as_ref().resolve();
+        let (anchor, dir) = [Anchor::ConfigDir, Anchor::Home, Anchor::Cwd]
+            .into_iter()
+            .filter_map(|anchor| Some((anchor, A::anchor_dir(anchor)?)))
+            .filter(|(_, dir)| path.is_relative_to(dir))
+            // The first of the deepest directories.
+            .rev()
+            .max_by_key(|(_, dir)| dir.components().count())?;
+        Self::new(anchor, path.relative(dir)).ok()
+    }
+}
+
+impl<A> AnchoredPathBuf<A> {
+    /// The anchor the path is relative to.
+    pub fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+
+    /// The normalized path relative to the anchor.
+    pub fn rel(&self) -> &Path {
+        &self.rel
+    }
+
+    /// The normalized absolute path, resolved against the anchor when the value was created.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl<A> fmt::Debug for AnchoredPathBuf<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnchoredPathBuf")
+            .field("anchor", &self.anchor)
+            .field("rel", &self.rel)
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+impl<A> Clone for AnchoredPathBuf<A> {
+    fn clone(&self) -> Self {
+        Self {
+            anchor: self.anchor,
+            rel: self.rel.clone(),
+            path: self.path.clone(),
+            anchors: PhantomData,
+        }
+    }
+}
+
+impl<A> PartialEq for AnchoredPathBuf<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.anchor == other.anchor && self.rel == other.rel
+    }
+}
+
+impl<A> Eq for AnchoredPathBuf<A> {}
+
+impl<A> AsRef<Path> for AnchoredPathBuf<A> {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use std::{fmt, marker::PhantomData, path::Component};
+
+    use serde::{
+        de::{self, MapAccess, Visitor},
+        ser::{self, SerializeStruct},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use super::{Anchor, AnchorDirs, AnchoredPathBuf};
+
+    const FIELDS: &[&str] = &["anchor", "rel"];
+
+    impl<A> Serialize for AnchoredPathBuf<A> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let names = self
+                .rel
+                .components()
+                .map(|component| match component {
+                    Component::Normal(name) => name.to_str(),
+                    component => component.as_os_str().to_str(),
+                })
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| ser::Error::custom("path is not valid UTF-8"))?;
+            let mut state = serializer.serialize_struct("AnchoredPathBuf", 2)?;
+            state.serialize_field("anchor", self.anchor.name())?;
+            state.serialize_field("rel", &names.join("/"))?;
+            state.end()
+        }
+    }
+
+    impl<'de, A: AnchorDirs> Deserialize<'de> for AnchoredPathBuf<A> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_struct(
+                "AnchoredPathBuf",
+                FIELDS,
+                AnchoredPathVisitor(PhantomData),
+            )
+        }
+    }
+
+    struct AnchoredPathVisitor<A>(PhantomData<A>);
+
+    impl<'de, A: AnchorDirs> Visitor<'de> for AnchoredPathVisitor<A> {
+        type Value = AnchoredPathBuf<A>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an anchored path")
+        }
+
+        fn visit_map<M: MapAccess<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+            let mut anchor = None;
+            let mut rel = None;
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "anchor" => {
+                        let name = map.next_value::<String>()?;
+                        let value = Anchor::from_name(&name).ok_or_else(|| {
+                            de::Error::unknown_variant(
+                                &name,
+                                &["home", "cwd", "config_dir", "manifest_dir", "out_dir"],
+                            )
+                        })?;
+                        anchor = Some(value);
+                    }
+                    "rel" => rel = Some(map.next_value::<String>()?),
+                    _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                }
+            }
+            let anchor = anchor.ok_or_else(|| de::Error::missing_field("anchor"))?;
+            let rel = rel.ok_or_else(|| de::Error::missing_field("rel"))?;
+            AnchoredPathBuf::new(anchor, rel).map_err(de::Error::custom)
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    error::Error,
+    ffi::{OsStr, OsString},
+    fmt,
+    iter::Peekable,
+    path::{Path, PathBuf},
+};
+
+use crate::{os_str, windows, Platform, SugarPath};
+
+/// Why an archive entry was rejected by [`sanitize_archive_entry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchiveEntryError {
+    /// The entry uses `..` to climb above the destination directory.
+    Escapes,
+    /// A component can't be created on Windows: it contains a reserved character, ends with a dot or a space, or is a reserved device name.
+    InvalidName(OsString),
+    /// Nothing is left of the entry once its root and `.` segments are removed.
+    Empty,
+}
+
+impl fmt::Display for ArchiveEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchiveEntryError::Escapes => write!(f, "entry escapes the destination directory"),
+            ArchiveEntryError::InvalidName(name) => write!(f, "invalid file name {:?}", name),
+            ArchiveEntryError::Empty => write!(f, "entry is empty"),
+        }
+    }
+}
+
+impl Error for ArchiveEntryError {}
+
+/// Turns the name of a tar/zip entry into the path it should be extracted to, guaranteed to be inside `dest_dir`.
+///
+/// - both `/` and `\` are separators, whatever the host,
+/// - roots, drive letters (`C:`), UNC (`\\server\share`) and verbatim (`\\?\`) prefixes are stripped, so absolute entries are extracted relative to `dest_dir`,
+/// - `.` segments are dropped and `..` segments are resolved, but a `..` climbing above `dest_dir` rejects the entry,
+/// - components that are invalid on Windows (see [`ArchiveEntryError::InvalidName`]) reject the entry, so that archives extract the same way on every platform.
+///
+/// The result is `dest_dir` normalized, joined with the sanitized entry.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{sanitize_archive_entry, ArchiveEntryError};
+///
+/// assert_eq!(
+///     sanitize_archive_entry("/pkg/./lib/../index.js", "out").unwrap(),
+///     Path::new("out").join("pkg").join("index.js")
+/// );
+/// assert_eq!(
+///     sanitize_archive_entry("../../etc/passwd", "out"),
+///     Err(ArchiveEntryError::Escapes)
+/// );
+/// ```
+pub fn sanitize_archive_entry(
+    entry: impl AsRef<Path>,
+    dest_dir: impl AsRef<Path>,
+) -> Result<PathBuf, ArchiveEntryError> {
+    let entry = entry.as_ref().as_os_str();
+    let is_separator = |b| Platform::Windows.is_separator(b);
+    let mut pieces = os_str::split(entry, is_separator).peekable();
+
+    let bytes = entry.as_encoded_bytes();
+    if let [first, second, third, ..] = bytes {
+        if is_separator(*first) && is_separator(*second) && !is_separator(*third) {
+            skip_unc_prefix(&mut pieces);
+        }
+    }
+
+    let mut names: Vec<&OsStr> = vec![];
+    let mut first = true;
+    for mut piece in pieces {
+        if first {
+            piece = strip_drive(piece);
+            first = false;
+        }
+        if piece.is_empty() || piece == "." {
+            continue;
+        }
+        if piece == ".." {
+            if names.pop().is_none() {
+                return Err(ArchiveEntryError::Escapes);
+            }
+            continue;
+        }
+        if !is_valid_windows_name(piece) {
+            return Err(ArchiveEntryError::InvalidName(piece.to_os_string()));
+        }
+        names.push(piece);
+    }
+
+    if names.is_empty() {
+        return Err(ArchiveEntryError::Empty);
+    }
+    let mut path = dest_dir.as_ref().normalize();
+    path.extend(names);
+    Ok(path)
+}
+
+/// Skips the pieces of `\\server\share\…`, `\\?\UNC\server\share\…`, `\\?\C:\…` and `\\.\COM1\…` prefixes.
+fn skip_unc_prefix<'a>(pieces: &mut Peekable<impl Iterator<Item = &'a OsStr>>) {
+    let is_verbatim_or_device = pieces
+        .next_if(|marker| *marker == "?" || *marker == ".")
+        .is_some();
+    let is_unc = !is_verbatim_or_device
+        || pieces
+            .next_if(|piece| piece.eq_ignore_ascii_case("UNC"))
+            .is_some();
+    // Server and share, or the drive, volume or device name.
+    pieces.next();
+    if is_unc {
+        pieces.next();
+    }
+}
+
+/// Strips a leading drive letter, as in `C:` or `C:foo`.
+fn strip_drive(piece: &OsStr) -> &OsStr {
+    match piece.as_encoded_bytes() {
+        // SAFETY: the split happens right after an ASCII `:`.
+        [letter, b':', rest @ ..] if letter.is_ascii_alphabetic() => unsafe {
+            OsStr::from_encoded_bytes_unchecked(rest)
+        },
+        _ => piece,
+    }
+}
+
+fn is_valid_windows_name(name: &OsStr) -> bool {
+    let bytes = name.as_encoded_bytes();
+    let has_invalid_byte = bytes.iter().any(|b| *b < 0x20 || b"<>:\"|?*".contains(b));
+    let has_invalid_end = matches!(bytes.last(), Some(b'.' | b' '));
+    !has_invalid_byte && !has_invalid_end && !windows::is_reserved_name(name)
+}
@@ -0,0 +1,54 @@
+use std::{
+    ffi::OsString,
+    iter::FusedIterator,
+    path::{Path, PathBuf},
+    vec,
+};
+
+use crate::SugarPath;
+
+/// Iterator over the `(name, path)` pairs of a normalized path, returned by [`SugarPath::breadcrumbs`].
+#[derive(Debug, Clone)]
+pub struct Breadcrumbs {
+    crumbs: vec::IntoIter<(OsString, PathBuf)>,
+}
+
+impl Breadcrumbs {
+    pub(crate) fn new(path: &Path) -> Self {
+        let (root, rest) = path.split_root();
+        let mut crumbs = Vec::new();
+        let mut current = root.clone();
+        if !root.as_os_str().is_empty() {
+            crumbs.push((root.into_os_string(), current.clone()));
+        }
+        for component in rest.components() {
+            current.push(component);
+            crumbs.push((component.as_os_str().to_os_string(), current.clone()));
+        }
+        Breadcrumbs {
+            crumbs: crumbs.into_iter(),
+        }
+    }
+}
+
+impl Iterator for Breadcrumbs {
+    type Item = (OsString, PathBuf);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.crumbs.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.crumbs.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Breadcrumbs {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.crumbs.next_back()
+    }
+}
+
+impl ExactSizeIterator for Breadcrumbs {}
+
+impl FusedIterator for Breadcrumbs {}
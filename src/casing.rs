@@ -0,0 +1,61 @@
+/// A case convention for file names, see [`SugarPath::with_file_name_casing`](crate::SugarPath::with_file_name_casing).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Casing {
+    /// `My File` becomes `my file`.
+    Lower,
+    /// `My File` becomes `MY FILE`.
+    Upper,
+    /// `My File` and `myFile` become `my-file`.
+    Kebab,
+    /// `My File` and `myFile` become `my_file`.
+    Snake,
+}
+
+impl Casing {
+    /// Converts `s` to this casing, with Unicode-aware lowercasing and uppercasing.
+    ///
+    /// For [`Kebab`](Casing::Kebab) and [`Snake`](Casing::Snake), words are separated by `-`, `_` and whitespace, and a new word starts at an uppercase letter that follows a lowercase letter or a digit, or that ends a run of uppercase letters, as in `HTTPServer`. Other characters, like `.`, are kept as they are.
+    ///
+    /// ```rust
+    /// use sugar_path::Casing;
+    ///
+    /// assert_eq!(Casing::Kebab.apply("HTTPServer config_v2"), "http-server-config-v2");
+    /// assert_eq!(Casing::Snake.apply("Ünïcode Name"), "ünïcode_name");
+    /// assert_eq!(Casing::Upper.apply("straße"), "STRASSE");
+    /// ```
+    pub fn apply(self, s: &str) -> String {
+        match self {
+            Casing::Lower => s.to_lowercase(),
+            Casing::Upper => s.to_uppercase(),
+            Casing::Kebab => join_words(s, '-'),
+            Casing::Snake => join_words(s, '_'),
+        }
+    }
+}
+
+fn join_words(s: &str, separator: char) -> String {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut joined = String::with_capacity(s.len());
+    let mut pending_separator = false;
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '-' || c == '_' || c.is_whitespace() {
+            // Leading and trailing separators are dropped, and runs of them are collapsed.
+            pending_separator = !joined.is_empty();
+            continue;
+        }
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1);
+        let starts_word = c.is_uppercase()
+            && prev.is_some_and(|prev| {
+                prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next.is_some_and(|next| next.is_lowercase()))
+            });
+        if (pending_separator || starts_word) && !joined.is_empty() {
+            joined.push(separator);
+        }
+        pending_separator = false;
+        joined.extend(c.to_lowercase());
+    }
+    joined
+}
@@ -0,0 +1,165 @@
+use std::{ffi::OsStr, fmt::Display};
+
+use clap::{
+    builder::{TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+    Arg, Command,
+};
+
+use crate::{AbsolutePathBuf, NormalizedPathBuf, RelativePathBuf};
+
+/// Parses a command line argument into a [`NormalizedPathBuf`].
+///
+/// This is the parser `clap::value_parser!(NormalizedPathBuf)` picks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NormalizedPathParser;
+
+impl NormalizedPathParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for NormalizedPathParser {
+    type Value = NormalizedPathBuf;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        reject_empty(cmd, arg, value)?;
+        Ok(NormalizedPathBuf::new(value))
+    }
+}
+
+impl ValueParserFactory for NormalizedPathBuf {
+    type Parser = NormalizedPathParser;
+
+    fn value_parser() -> Self::Parser {
+        NormalizedPathParser
+    }
+}
+
+/// Parses a command line argument into an [`AbsolutePathBuf`].
+///
+/// A relative argument is resolved against the current working directory, unless [`require_absolute`](AbsolutePathParser::require_absolute) is set. This is the parser `clap::value_parser!(AbsolutePathBuf)` picks.
+///
+/// ```rust
+/// use clap::{Arg, Command};
+/// use std::path::Path;
+/// use sugar_path::{AbsolutePathBuf, AbsolutePathParser, SugarPath};
+///
+/// let cmd = Command::new("app").arg(Arg::new("out").value_parser(AbsolutePathParser::new()));
+/// let matches = cmd.clone().try_get_matches_from(["app", "dist/../build"]).unwrap();
+/// assert_eq!(
+///     matches.get_one::<AbsolutePathBuf>("out").unwrap().as_path(),
+///     Path::new("build").resolve()
+/// );
+///
+/// let cmd = Command::new("app")
+///     .arg(Arg::new("out").value_parser(AbsolutePathParser::new().require_absolute(true)));
+/// assert!(cmd.try_get_matches_from(["app", "build"]).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AbsolutePathParser {
+    require_absolute: bool,
+}
+
+impl AbsolutePathParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rejects relative arguments instead of resolving them. Defaults to `false`.
+    pub fn require_absolute(mut self, require_absolute: bool) -> Self {
+        self.require_absolute = require_absolute;
+        self
+    }
+}
+
+impl TypedValueParser for AbsolutePathParser {
+    type Value = AbsolutePathBuf;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        reject_empty(cmd, arg, value)?;
+        if self.require_absolute {
+            AbsolutePathBuf::new(value).map_err(|err| invalid_value(cmd, arg, value, err))
+        } else {
+            Ok(AbsolutePathBuf::resolve(value))
+        }
+    }
+}
+
+impl ValueParserFactory for AbsolutePathBuf {
+    type Parser = AbsolutePathParser;
+
+    fn value_parser() -> Self::Parser {
+        AbsolutePathParser::new()
+    }
+}
+
+/// Parses a command line argument into a [`RelativePathBuf`], rejecting absolute paths.
+///
+/// This is the parser `clap::value_parser!(RelativePathBuf)` picks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RelativePathParser;
+
+impl RelativePathParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for RelativePathParser {
+    type Value = RelativePathBuf;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        reject_empty(cmd, arg, value)?;
+        RelativePathBuf::new(value).map_err(|err| invalid_value(cmd, arg, value, err))
+    }
+}
+
+impl ValueParserFactory for RelativePathBuf {
+    type Parser = RelativePathParser;
+
+    fn value_parser() -> Self::Parser {
+        RelativePathParser
+    }
+}
+
+/// An empty argument would otherwise normalize to `.`, which is rarely what was meant.
+fn reject_empty(cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<(), clap::Error> {
+    if value.is_empty() {
+        Err(invalid_value(cmd, arg, value, "a path can't be empty"))
+    } else {
+        Ok(())
+    }
+}
+
+fn invalid_value(
+    cmd: &Command,
+    arg: Option<&Arg>,
+    value: &OsStr,
+    reason: impl Display,
+) -> clap::Error {
+    let arg = arg.map_or_else(|| "...".to_owned(), |arg| arg.to_string());
+    let message = format!(
+        "invalid value '{}' for '{}': {}\n",
+        value.to_string_lossy(),
+        arg,
+        reason
+    );
+    clap::Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+}
@@ -0,0 +1,87 @@
+use std::{
+    error::Error,
+    ffi::{OsStr, OsString},
+    fmt,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{Platform, SugarPath};
+
+/// Why a component of a path couldn't be edited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComponentError {
+    /// The index is past the components below the root.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The new segment is empty, is `.` or `..`, or contains a separator or a NUL.
+    InvalidSegment(OsString),
+    /// The normalized path doesn't end with a name that could be replaced, e.g. it is a root or `..`.
+    NoFileName,
+}
+
+impl fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentError::IndexOutOfBounds { index, len } => write!(
+                f,
+                "component index {} is out of bounds, the path has {} components",
+                index, len
+            ),
+            ComponentError::InvalidSegment(segment) => {
+                write!(f, "{:?} is not a valid path segment", segment)
+            }
+            ComponentError::NoFileName => f.write_str("the path has no file name to replace"),
+        }
+    }
+}
+
+impl Error for ComponentError {}
+
+/// The path passed to [`strip_components`](crate::SugarPath::strip_components) has fewer components than requested.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StripComponentsError {
+    /// The number of components below the root of the normalized path.
+    pub depth: usize,
+    /// The number of components that was asked to be stripped.
+    pub requested: usize,
+}
+
+impl fmt::Display for StripComponentsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "can't strip {} components from a path with {} components",
+            self.requested, self.depth
+        )
+    }
+}
+
+impl Error for StripComponentsError {}
+
+/// Checks that `segment` can be used as a single normal component.
+pub(crate) fn validate_segment(segment: &OsStr) -> Result<(), ComponentError> {
+    let platform = Platform::current();
+    let bytes = segment.as_encoded_bytes();
+    let is_valid = !bytes.is_empty()
+        && segment != "."
+        && segment != ".."
+        && !bytes.iter().any(|b| *b == 0 || platform.is_separator(*b));
+    if is_valid {
+        Ok(())
+    } else {
+        Err(ComponentError::InvalidSegment(segment.to_os_string()))
+    }
+}
+
+/// Normalizes `path`, lets `edit` change the components below the root using `segment`, and rebuilds the path.
+pub(crate) fn edit_components(
+    path: &Path,
+    segment: &OsStr,
+    edit: impl for<'a> FnOnce(&mut Vec<Component<'a>>, Component<'a>) -> Result<(), ComponentError>,
+) -> Result<PathBuf, ComponentError> {
+    validate_segment(segment)?;
+    let (mut root, rest) = path.split_root();
+    let mut components = rest.components().collect::<Vec<_>>();
+    edit(&mut components, Component::Normal(segment))?;
+    root.extend(components);
+    Ok(root)
+}
@@ -0,0 +1,97 @@
+use std::{
+    fmt::{self, Write},
+    path::{Component, Path},
+};
+
+use crate::has_trailing_separator;
+
+/// Displays the normalized form of a path without building it, returned by [`SugarPath::normalized_display`](crate::SugarPath::normalized_display).
+///
+/// Components that aren't valid Unicode are written lossily, like [`Path::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizedDisplay<'a> {
+    path: &'a Path,
+    separator: char,
+}
+
+impl<'a> NormalizedDisplay<'a> {
+    pub(crate) fn new(path: &'a Path, separator: char) -> Self {
+        NormalizedDisplay { path, separator }
+    }
+}
+
+impl fmt::Display for NormalizedDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut components = self.path.components();
+        let mut has_root = false;
+        // Whether anything has been written after the prefix and the root.
+        let mut needs_separator = false;
+        // The number of skipped normal components whose `..` hasn't come yet.
+        let mut skipped = 0usize;
+        while let Some(component) = components.next() {
+            match component {
+                Component::Prefix(prefix) => {
+                    let prefix = prefix.as_os_str().to_string_lossy();
+                    for c in prefix.chars() {
+                        f.write_char(if c == '\\' { self.separator } else { c })?;
+                    }
+                }
+                Component::RootDir => {
+                    has_root = true;
+                    f.write_char(self.separator)?;
+                }
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    if skipped > 0 {
+                        skipped -= 1;
+                    } else if !has_root {
+                        if needs_separator {
+                            f.write_char(self.separator)?;
+                        }
+                        f.write_str("..")?;
+                        needs_separator = true;
+                    }
+                }
+                Component::Normal(name) => {
+                    if is_cancelled(components.clone()) {
+                        skipped += 1;
+                        continue;
+                    }
+                    if needs_separator {
+                        f.write_char(self.separator)?;
+                    }
+                    write!(f, "{}", Path::new(name).display())?;
+                    needs_separator = true;
+                }
+            }
+        }
+        if !needs_separator && !has_root {
+            f.write_char('.')?;
+            needs_separator = true;
+        }
+        if needs_separator && has_trailing_separator(self.path) {
+            f.write_char(self.separator)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether a `..` among `rest` cancels out the normal component just before it.
+///
+/// Scanning ahead for each component keeps the display free of allocations, and paths are short enough for that not to matter.
+fn is_cancelled(rest: std::path::Components) -> bool {
+    let mut depth = 1usize;
+    for component in rest {
+        match component {
+            Component::Normal(_) => depth += 1,
+            Component::ParentDir => {
+                depth -= 1;
+                if depth == 0 {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
@@ -0,0 +1,230 @@
+use std::{
+    ffi::OsString,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{os_str, Platform, SugarPath};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Op {
+    StripPrefix(PathBuf),
+    Prepend(PathBuf),
+    Push(PathBuf),
+    Pop(PathBuf),
+    SetExtension { from: String, to: String },
+    InsertSuffix(String),
+    RemoveSuffix(String),
+}
+
+impl Op {
+    fn invert(&self) -> Op {
+        match self {
+            Op::StripPrefix(prefix) => Op::Prepend(prefix.clone()),
+            Op::Prepend(prefix) => Op::StripPrefix(prefix.clone()),
+            Op::Push(names) => Op::Pop(names.clone()),
+            Op::Pop(names) => Op::Push(names.clone()),
+            Op::SetExtension { from, to } => Op::SetExtension {
+                from: to.clone(),
+                to: from.clone(),
+            },
+            Op::InsertSuffix(suffix) => Op::RemoveSuffix(suffix.clone()),
+            Op::RemoveSuffix(suffix) => Op::InsertSuffix(suffix.clone()),
+        }
+    }
+
+    fn apply(&self, path: PathBuf) -> Option<PathBuf> {
+        match self {
+            Op::StripPrefix(prefix) => path.strip_prefix(prefix).ok().map(Path::to_path_buf),
+            Op::Prepend(prefix) => match path.components().next() {
+                Some(Component::Prefix(_) | Component::RootDir) => None,
+                // The `..` would cancel out the end of `prefix`.
+                Some(Component::ParentDir)
+                    if prefix
+                        .components()
+                        .next_back()
+                        .is_some_and(|last| last != Component::ParentDir) =>
+                {
+                    None
+                }
+                _ => Some(prefix.join(path)),
+            },
+            Op::Push(names) => {
+                let mut path = path;
+                path.push(names);
+                Some(path)
+            }
+            Op::Pop(names) => {
+                if !path.ends_with(names) {
+                    return None;
+                }
+                let mut path = path;
+                for _ in names.components() {
+                    path.pop();
+                }
+                Some(path)
+            }
+            Op::SetExtension { from, to } => {
+                let name = path.file_name()?.as_encoded_bytes();
+                let kept = if from.is_empty() {
+                    name
+                } else {
+                    name.strip_suffix(from.as_bytes())?.strip_suffix(b".")?
+                };
+                if kept.is_empty() || kept == b"." || kept == b".." {
+                    return None;
+                }
+                let mut renamed = OsString::with_capacity(kept.len() + to.len() + 1);
+                renamed.push(os_str::from_encoded_bytes(kept));
+                if !to.is_empty() {
+                    renamed.push(".");
+                    renamed.push(to);
+                }
+                Some(path.with_file_name(renamed))
+            }
+            Op::InsertSuffix(suffix) => {
+                let (stem, extension) = (path.file_stem()?, path.extension()?);
+                let mut renamed = stem.to_os_string();
+                renamed.push(suffix);
+                renamed.push(".");
+                renamed.push(extension);
+                Some(path.with_file_name(renamed))
+            }
+            Op::RemoveSuffix(suffix) => {
+                let (stem, extension) = (path.file_stem()?, path.extension()?);
+                let stem = stem.as_encoded_bytes().strip_suffix(suffix.as_bytes())?;
+                if stem.is_empty() {
+                    return None;
+                }
+                let mut renamed = os_str::from_encoded_bytes(stem).to_os_string();
+                renamed.push(".");
+                renamed.push(extension);
+                Some(path.with_file_name(renamed))
+            }
+        }
+    }
+}
+
+/// A recorded sequence of lexical edits, applied to many paths at once and inverted to map the results back, like between the sources of a build and its artifacts.
+///
+/// Every edit checks that it fits the path, so [`apply`](PathEdit::apply) returns `None` instead of a path that [`invert`](PathEdit::invert) couldn't map back: whenever `edit.apply(path)` is `Some(output)`, `edit.invert().apply(output)` is the normalized `path`.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::PathEdit;
+///
+/// let edit = PathEdit::new()
+///     .rebase("src", "dist")
+///     .set_extension("ts", "js")
+///     .insert_suffix(".min");
+/// assert_eq!(edit.apply("src/./app/main.ts").unwrap(), Path::new("dist/app/main.min.js"));
+/// assert_eq!(edit.apply("tests/main.ts"), None);
+/// assert_eq!(edit.apply("src/main.rs"), None);
+///
+/// let sources = edit.invert();
+/// assert_eq!(sources.apply("dist/app/main.min.js").unwrap(), Path::new("src/app/main.ts"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathEdit {
+    ops: Vec<Op>,
+}
+
+impl PathEdit {
+    /// An edit that leaves paths as they are.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes the normalized `prefix`, comparing whole components. Paths outside of it don't fit.
+    pub fn strip_prefix(mut self, prefix: impl AsRef<Path>) -> Self {
+        self.ops.push(Op::StripPrefix(normalized(prefix.as_ref())));
+        self
+    }
+
+    /// Appends the normalized `names`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `names` has anything but plain names once normalized, like a root or a `..`.
+    pub fn push(mut self, names: impl AsRef<Path>) -> Self {
+        let names = normalized(names.as_ref());
+        assert!(
+            names
+                .components()
+                .all(|component| matches!(component, Component::Normal(_))),
+            "only plain names can be pushed"
+        );
+        self.ops.push(Op::Push(names));
+        self
+    }
+
+    /// Replaces the extension `from` at the end of the file name, which may span several dots like `tar.gz`, with `to`. A leading `.` in either is ignored.
+    ///
+    /// Names that don't end with `from` don't fit, and neither do names that would be left empty. An empty `from` appends `to` to every name, and an empty `to` removes `from`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` contains a separator.
+    pub fn set_extension(mut self, from: &str, to: &str) -> Self {
+        let (from, to) = (extension(from), extension(to));
+        self.ops.push(Op::SetExtension { from, to });
+        self
+    }
+
+    /// Inserts `suffix` into the file name right before its extension, like `.min` in `app.min.js`. Names without an extension don't fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `suffix` contains a separator.
+    pub fn insert_suffix(mut self, suffix: &str) -> Self {
+        assert!(!has_separator(suffix), "a suffix can't contain a separator");
+        self.ops.push(Op::InsertSuffix(suffix.to_string()));
+        self
+    }
+
+    /// Moves paths from beneath the normalized `from` to beneath the normalized `to`. Paths outside of `from` don't fit.
+    pub fn rebase(self, from: impl AsRef<Path>, to: impl AsRef<Path>) -> Self {
+        let mut edit = self.strip_prefix(from);
+        edit.ops.push(Op::Prepend(normalized(to.as_ref())));
+        edit
+    }
+
+    /// Applies every edit in order to the normalized `path`, or returns `None` if one of them doesn't fit.
+    pub fn apply(&self, path: impl AsRef<Path>) -> Option<PathBuf> {
+        let mut path = normalized(path.as_ref());
+        for op in &self.ops {
+            path = op.apply(path)?;
+        }
+        if path.as_os_str().is_empty() {
+            path.push(".");
+        }
+        Some(path)
+    }
+
+    /// The edit that undoes this one, mapping its outputs back to its inputs.
+    pub fn invert(&self) -> PathEdit {
+        PathEdit {
+            ops: self.ops.iter().rev().map(Op::invert).collect(),
+        }
+    }
+}
+
+/// The normalized `path`, with `.` as the empty path so it can be joined and stripped.
+fn normalized(path: &Path) -> PathBuf {
+    let mut path = path.normalize();
+    if path == Path::new(".") {
+        path.clear();
+    }
+    path
+}
+
+fn extension(extension: &str) -> String {
+    assert!(
+        !has_separator(extension),
+        "an extension can't contain a separator"
+    );
+    extension.strip_prefix('.').unwrap_or(extension).to_string()
+}
+
+fn has_separator(s: &str) -> bool {
+    s.bytes().any(|b| Platform::current().is_separator(b))
+}
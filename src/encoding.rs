@@ -0,0 +1,96 @@
+use std::{
+    error::Error,
+    ffi::{OsStr, OsString},
+    fmt,
+    path::{Component, Path, PathBuf, MAIN_SEPARATOR_STR},
+};
+
+use crate::{os_str, SugarPath};
+
+/// Why a string couldn't be decoded by [`decode_components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeComponentsError {
+    /// The `%` at this byte offset isn't followed by two hex digits.
+    InvalidEscape(usize),
+    /// The decoded bytes aren't valid Unicode, which only Unix paths can hold.
+    NotUnicode,
+}
+
+impl fmt::Display for DecodeComponentsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeComponentsError::InvalidEscape(offset) => {
+                write!(f, "invalid escape sequence at {}", offset)
+            }
+            DecodeComponentsError::NotUnicode => f.write_str("decoded path is not valid unicode"),
+        }
+    }
+}
+
+impl Error for DecodeComponentsError {}
+
+pub(crate) fn encode_components(path: &Path) -> String {
+    let path = path.normalize();
+    let mut ret = String::with_capacity(path.as_os_str().len());
+    let mut needs_separator = false;
+    for component in path.components() {
+        match component {
+            Component::Prefix(prefix) => escape_into(&mut ret, prefix.as_os_str()),
+            Component::RootDir => ret.push('/'),
+            component => {
+                if needs_separator {
+                    ret.push('/');
+                }
+                escape_into(&mut ret, component.as_os_str());
+                needs_separator = true;
+            }
+        }
+    }
+    ret
+}
+
+fn escape_into(ret: &mut String, s: &OsStr) {
+    for chunk in s.as_encoded_bytes().utf8_chunks() {
+        for c in chunk.valid().chars() {
+            match c {
+                '%' | '/' | '\\' => ret.push_str(&format!("%{:02X}", c as u8)),
+                c => ret.push(c),
+            }
+        }
+        for b in chunk.invalid() {
+            ret.push_str(&format!("%{:02X}", b));
+        }
+    }
+}
+
+/// Decodes a string produced by [`SugarPath::encode_components`] back into the path.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{decode_components, SugarPath};
+///
+/// let path = Path::new("reports/50%/a\\b.txt");
+/// assert_eq!(decode_components(&path.encode_components()).unwrap(), path.normalize());
+/// assert!(decode_components("bad%2").is_err());
+/// ```
+pub fn decode_components(encoded: &str) -> Result<PathBuf, DecodeComponentsError> {
+    let mut ret = OsString::with_capacity(encoded.len());
+    let mut offset = 0;
+    for (index, token) in encoded.split('/').enumerate() {
+        if index > 0 {
+            ret.push(MAIN_SEPARATOR_STR);
+        }
+        ret.push(unescape(token, offset)?);
+        offset += token.len() + 1;
+    }
+    Ok(PathBuf::from(ret))
+}
+
+fn unescape(token: &str, offset: usize) -> Result<OsString, DecodeComponentsError> {
+    if !token.contains('%') {
+        return Ok(token.into());
+    }
+    let bytes = os_str::percent_decode(token)
+        .map_err(|index| DecodeComponentsError::InvalidEscape(offset + index))?;
+    os_str::from_bytes(bytes).ok_or(DecodeComponentsError::NotUnicode)
+}
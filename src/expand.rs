@@ -0,0 +1,182 @@
+use std::{
+    env,
+    error::Error,
+    ffi::OsString,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "fs")]
+use std::{io, path::Component};
+
+use crate::{ExpandUserError, Platform, SugarPath};
+#[cfg(feature = "fs")]
+use crate::{FileSystem, Pattern, PatternError, WalkOptions};
+
+/// Why [`expand`] or [`expand_glob`] failed.
+#[derive(Debug)]
+pub enum ExpandError {
+    /// The leading `~` couldn't be expanded.
+    User(ExpandUserError),
+    /// The input refers to an environment variable that isn't set.
+    MissingVariable(String),
+    /// The `${` at this byte offset is never closed.
+    UnclosedBrace(usize),
+    /// The expanded path isn't a valid glob pattern.
+    #[cfg(feature = "fs")]
+    Pattern(PatternError),
+    /// A directory couldn't be read while expanding the glob.
+    #[cfg(feature = "fs")]
+    Io(io::Error),
+}
+
+impl fmt::Display for ExpandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpandError::User(err) => err.fmt(f),
+            ExpandError::MissingVariable(name) => {
+                write!(f, "environment variable {:?} is not set", name)
+            }
+            ExpandError::UnclosedBrace(offset) => write!(f, "unclosed `${{` at {}", offset),
+            #[cfg(feature = "fs")]
+            ExpandError::Pattern(err) => err.fmt(f),
+            #[cfg(feature = "fs")]
+            ExpandError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for ExpandError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExpandError::User(err) => Some(err),
+            #[cfg(feature = "fs")]
+            ExpandError::Pattern(err) => Some(err),
+            #[cfg(feature = "fs")]
+            ExpandError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Expands a path from a config file the way a shell would, then normalizes it: first a leading `~` or `~user`, like [`SugarPath::expand_user`], then the environment variables.
+///
+/// Variables are written `$NAME` or `${NAME}`, and also `%NAME%` on Windows. A `$` or `%` that doesn't start one is kept as it is, and a variable that isn't set is an error rather than an empty string. Like in a shell, a `~` coming from a variable isn't expanded.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{expand, SugarPath};
+///
+/// std::env::set_var("SUGAR_PATH_EXPAND_DOC", "logs/./today");
+/// assert_eq!(
+///     expand("${SUGAR_PATH_EXPAND_DOC}/../$SUGAR_PATH_EXPAND_DOC/app.log").unwrap(),
+///     Path::new("logs/logs/today/app.log")
+/// );
+/// assert_eq!(expand("~/.config/$").unwrap(), Path::new("~/.config/$").expand_user().unwrap());
+/// assert!(expand("$SUGAR_PATH_EXPAND_UNSET").is_err());
+/// ```
+pub fn expand(input: &str) -> Result<PathBuf, ExpandError> {
+    let home_len = match input.strip_prefix('~') {
+        Some(rest) => {
+            1 + rest
+                .bytes()
+                .position(|b| Platform::current().is_separator(b))
+                .unwrap_or(rest.len())
+        }
+        None => 0,
+    };
+    let mut ret = OsString::with_capacity(input.len());
+    if home_len > 0 {
+        let home = Path::new(&input[..home_len])
+            .expand_user()
+            .map_err(ExpandError::User)?;
+        ret.push(home);
+    }
+    expand_vars(input, home_len, &mut ret)?;
+    Ok(PathBuf::from(ret).normalize())
+}
+
+/// Like [`expand`], then expands the glob in the result against `fs`, like a shell does last, returning the sorted matches.
+///
+/// The components before the first one with a wildcard are the directory being searched, so `~/notes/**/*.md` only reads the notes. A path without wildcards is returned as it is, without looking at `fs`, while a pattern that matches nothing returns no paths. See [`Pattern`] for the wildcards.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{expand_glob, VirtualTree};
+///
+/// let tree: VirtualTree = ["conf/a.toml", "conf/b.toml", "conf/c.json"].into_iter().collect();
+/// assert_eq!(
+///     expand_glob("./conf/*.toml", &tree).unwrap(),
+///     [Path::new("conf/a.toml"), Path::new("conf/b.toml")]
+/// );
+/// assert_eq!(expand_glob("conf/missing.toml", &tree).unwrap(), [Path::new("conf/missing.toml")]);
+/// ```
+#[cfg(feature = "fs")]
+pub fn expand_glob(input: &str, fs: &impl FileSystem) -> Result<Vec<PathBuf>, ExpandError> {
+    let expanded = expand(input)?;
+    let is_wildcard = |component: &Component| match component {
+        Component::Normal(name) => name
+            .as_encoded_bytes()
+            .iter()
+            .any(|b| matches!(b, b'*' | b'?' | b'[')),
+        _ => false,
+    };
+    let mut root = PathBuf::new();
+    let mut components = expanded.components();
+    for component in components.clone() {
+        if is_wildcard(&component) {
+            break;
+        }
+        root.push(component);
+        components.next();
+    }
+    let rest = components.as_path();
+    if rest.as_os_str().is_empty() {
+        return Ok(vec![expanded]);
+    }
+    // Patterns are `&str`, and so is everything that was expanded into them but the values of variables.
+    let pattern = Pattern::new(&rest.to_string_lossy()).map_err(ExpandError::Pattern)?;
+    WalkOptions::new()
+        .glob(fs, &root, &pattern)
+        .map_err(ExpandError::Io)
+}
+
+fn expand_vars(input: &str, start: usize, ret: &mut OsString) -> Result<(), ExpandError> {
+    let mut rest = &input[start..];
+    while let Some(index) = rest.find(['$', '%']) {
+        let offset = input.len() - rest.len() + index;
+        ret.push(&rest[..index]);
+        let after = &rest[index + 1..];
+        let (name, len) = if rest[index..].starts_with('%') {
+            match after
+                .find('%')
+                .filter(|end| *end > 0 && cfg!(target_family = "windows"))
+            {
+                Some(end) => (&after[..end], end + 1),
+                None => ("", 0),
+            }
+        } else if let Some(braced) = after.strip_prefix('{') {
+            let end = braced.find('}').ok_or(ExpandError::UnclosedBrace(offset))?;
+            (&braced[..end], end + 2)
+        } else {
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            match after.as_bytes().first() {
+                Some(first) if !first.is_ascii_digit() => (&after[..end], end),
+                _ => ("", 0),
+            }
+        };
+        if len == 0 {
+            // Not a variable.
+            ret.push(&rest[index..index + 1]);
+        } else {
+            let value =
+                env::var_os(name).ok_or_else(|| ExpandError::MissingVariable(name.to_string()))?;
+            ret.push(value);
+        }
+        rest = &after[len..];
+    }
+    ret.push(rest);
+    Ok(())
+}
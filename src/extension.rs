@@ -0,0 +1,154 @@
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt,
+    hash::{Hash, Hasher},
+    path::Path,
+    str::FromStr,
+};
+
+use crate::Platform;
+
+/// The string passed to [`Extension::new`] isn't a valid extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionError {
+    /// The string as it was passed.
+    pub extension: String,
+}
+
+impl fmt::Display for ExtensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid extension", self.extension)
+    }
+}
+
+impl Error for ExtensionError {}
+
+/// A file extension, like `rs`, compared and hashed ignoring ASCII case, so `Extension::PNG` equals the extension of `logo.PNG`.
+///
+/// An extension is a single non-empty part of a file name without any `.`, separator or NUL, as [`Path::extension`] returns it.
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use std::path::Path;
+/// use sugar_path::{Extension, SugarPath};
+///
+/// let images = HashSet::from([Extension::PNG, Extension::JPG, Extension::new("webp").unwrap()]);
+/// assert!(images.contains(&Path::new("assets/Logo.PNG").extension_typed().unwrap()));
+/// assert!(Extension::JPG.matches("photo.jpg"));
+/// assert_eq!(Extension::new(".Rs").unwrap(), Extension::RS);
+/// assert!(Extension::new("tar.gz").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Extension(Cow<'static, str>);
+
+impl Extension {
+    pub const C: Extension = Extension(Cow::Borrowed("c"));
+    pub const CSS: Extension = Extension(Cow::Borrowed("css"));
+    pub const GZ: Extension = Extension(Cow::Borrowed("gz"));
+    pub const H: Extension = Extension(Cow::Borrowed("h"));
+    pub const HTML: Extension = Extension(Cow::Borrowed("html"));
+    pub const JPG: Extension = Extension(Cow::Borrowed("jpg"));
+    pub const JS: Extension = Extension(Cow::Borrowed("js"));
+    pub const JSON: Extension = Extension(Cow::Borrowed("json"));
+    pub const JSX: Extension = Extension(Cow::Borrowed("jsx"));
+    pub const MD: Extension = Extension(Cow::Borrowed("md"));
+    pub const PNG: Extension = Extension(Cow::Borrowed("png"));
+    pub const PY: Extension = Extension(Cow::Borrowed("py"));
+    pub const RS: Extension = Extension(Cow::Borrowed("rs"));
+    pub const SVG: Extension = Extension(Cow::Borrowed("svg"));
+    pub const TOML: Extension = Extension(Cow::Borrowed("toml"));
+    pub const TS: Extension = Extension(Cow::Borrowed("ts"));
+    pub const TSX: Extension = Extension(Cow::Borrowed("tsx"));
+    pub const TXT: Extension = Extension(Cow::Borrowed("txt"));
+    pub const YAML: Extension = Extension(Cow::Borrowed("yaml"));
+    pub const ZIP: Extension = Extension(Cow::Borrowed("zip"));
+
+    /// Validates `extension`, ignoring a leading `.`. Its case is kept as it is.
+    pub fn new(extension: &str) -> Result<Self, ExtensionError> {
+        let stripped = extension.strip_prefix('.').unwrap_or(extension);
+        if stripped.is_empty()
+            || stripped
+                .bytes()
+                .any(|b| b == b'.' || b == b'\0' || Platform::current().is_separator(b))
+        {
+            return Err(ExtensionError {
+                extension: extension.to_string(),
+            });
+        }
+        Ok(Extension(Cow::Owned(stripped.to_string())))
+    }
+
+    /// The extension as it was written, without the leading `.`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the extension of `path`, as [`Path::extension`] returns it, is this one.
+    pub fn matches(&self, path: impl AsRef<Path>) -> bool {
+        path.as_ref().extension().is_some_and(|extension| {
+            extension
+                .as_encoded_bytes()
+                .eq_ignore_ascii_case(self.0.as_bytes())
+        })
+    }
+}
+
+impl PartialEq for Extension {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for Extension {}
+
+impl PartialEq<str> for Extension {
+    fn eq(&self, other: &str) -> bool {
+        self.0
+            .eq_ignore_ascii_case(other.strip_prefix('.').unwrap_or(other))
+    }
+}
+
+impl PartialEq<&str> for Extension {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl Hash for Extension {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.0.bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+        // Like `str`, so that extensions with a common prefix hash apart.
+        state.write_u8(0xff);
+    }
+}
+
+impl fmt::Display for Extension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Extension {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Extension {
+    type Err = ExtensionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Extension::new(s)
+    }
+}
+
+impl TryFrom<&str> for Extension {
+    type Error = ExtensionError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Extension::new(s)
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    error::Error,
+    ffi::OsString,
+    fmt,
+    path::{PathBuf, MAIN_SEPARATOR_STR},
+};
+
+use crate::{os_str, Platform, SugarPath};
+
+/// What to do with the host of a `file://host/...` URL on platforms where a host can't be part of a path.
+///
+/// On Windows a host other than `localhost` always becomes the server of a UNC path, so the policy only applies elsewhere.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HostPolicy {
+    /// Reject every URL with a host, even `localhost`.
+    Error,
+    /// Drop the host and keep the path.
+    Ignore,
+    /// Accept `localhost`, reject any other host.
+    #[default]
+    LocalhostOnly,
+}
+
+/// Why a URL couldn't be turned into a path by [`path_from_file_url`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileUrlError {
+    /// The URL doesn't start with `file:` or has no absolute path.
+    NotFileUrl,
+    /// The host isn't allowed by the [`HostPolicy`].
+    UnsupportedHost(String),
+    /// The `%` at this byte offset isn't followed by two hex digits.
+    InvalidEscape(usize),
+    /// A percent-encoded separator would have split a segment in two.
+    EncodedSeparator,
+    /// The decoded path isn't valid Unicode, which only Unix paths can hold.
+    NotUnicode,
+}
+
+impl fmt::Display for FileUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FileUrlError::NotFileUrl => f.write_str("not a file URL with an absolute path"),
+            FileUrlError::UnsupportedHost(host) => write!(f, "unsupported host {:?}", host),
+            FileUrlError::InvalidEscape(offset) => {
+                write!(f, "invalid escape sequence at {}", offset)
+            }
+            FileUrlError::EncodedSeparator => f.write_str("encoded separator in a path segment"),
+            FileUrlError::NotUnicode => f.write_str("decoded path is not valid unicode"),
+        }
+    }
+}
+
+impl Error for FileUrlError {}
+
+/// Converts a `file:` URL into a normalized path.
+///
+/// `file:///C:/x` and `file://localhost/C:/x` are local paths. A URL with another host, like
+/// `file://server/share/x` from a remote editor, is the UNC path `\\server\share\x` on Windows, while elsewhere
+/// `policy` decides whether the host is an error or dropped. Query strings and fragments are ignored.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{path_from_file_url, HostPolicy, SugarPath};
+///
+/// #[cfg(target_family = "unix")]
+/// {
+///   assert_eq!(
+///     path_from_file_url("file:///home/me/My%20Docs/a.txt", HostPolicy::Error).unwrap(),
+///     Path::new("/home/me/My Docs/a.txt")
+///   );
+///   assert!(path_from_file_url("file://server/share/x", HostPolicy::LocalhostOnly).is_err());
+///   assert_eq!(
+///     path_from_file_url("file://server/share/x", HostPolicy::Ignore).unwrap(),
+///     Path::new("/share/x")
+///   );
+/// }
+/// #[cfg(target_family = "windows")]
+/// assert_eq!(
+///   path_from_file_url("file://server/share/x", HostPolicy::Error).unwrap(),
+///   Path::new("\\\\server\\share\\x")
+/// );
+/// ```
+pub fn path_from_file_url(url: &str, policy: HostPolicy) -> Result<PathBuf, FileUrlError> {
+    let scheme_len = "file:".len();
+    if !url
+        .get(..scheme_len)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("file:"))
+    {
+        return Err(FileUrlError::NotFileUrl);
+    }
+    let end = url.find(['?', '#']).unwrap_or(url.len());
+    let rest = &url[scheme_len..end];
+    let (host, path) = match rest.strip_prefix("//") {
+        Some(authority) => {
+            let host_len = authority.find('/').unwrap_or(authority.len());
+            authority.split_at(host_len)
+        }
+        None => ("", rest),
+    };
+    if !path.starts_with('/') {
+        return Err(FileUrlError::NotFileUrl);
+    }
+    let path_offset = end - path.len();
+    let is_localhost = host.is_empty() || host.eq_ignore_ascii_case("localhost");
+
+    // Decode the segments after the leading `/`.
+    let mut segments = Vec::new();
+    let mut offset = path_offset + 1;
+    for segment in path[1..].split('/') {
+        let bytes = os_str::percent_decode(segment)
+            .map_err(|index| FileUrlError::InvalidEscape(offset + index))?;
+        if bytes.iter().any(|b| Platform::current().is_separator(*b)) {
+            return Err(FileUrlError::EncodedSeparator);
+        }
+        segments.push(os_str::from_bytes(bytes).ok_or(FileUrlError::NotUnicode)?);
+        offset += segment.len() + 1;
+    }
+
+    let mut ret = OsString::with_capacity(url.len());
+    if cfg!(target_family = "windows") {
+        if !is_localhost {
+            ret.push("\\\\");
+            ret.push(host);
+            ret.push("\\");
+        } else if let Some(drive) = segments
+            .first()
+            .and_then(|first| first.to_str())
+            .and_then(drive_letter)
+        {
+            // `file:///C:/x`, or the legacy `file:///C|/x`.
+            segments[0] = format!("{}:", drive).into();
+        } else {
+            ret.push("\\");
+        }
+    } else {
+        if !host.is_empty() {
+            match policy {
+                HostPolicy::Ignore => {}
+                HostPolicy::LocalhostOnly if is_localhost => {}
+                _ => return Err(FileUrlError::UnsupportedHost(host.to_string())),
+            }
+        }
+        ret.push("/");
+    }
+    for (index, segment) in segments.iter().enumerate() {
+        if index > 0 {
+            ret.push(MAIN_SEPARATOR_STR);
+        }
+        ret.push(segment);
+    }
+    Ok(PathBuf::from(ret).normalize())
+}
+
+fn drive_letter(segment: &str) -> Option<char> {
+    match segment.as_bytes() {
+        [letter, b':' | b'|'] if letter.is_ascii_alphabetic() => {
+            Some(letter.to_ascii_uppercase() as char)
+        }
+        _ => None,
+    }
+}
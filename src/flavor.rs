@@ -0,0 +1,522 @@
+use std::{
+    cell::Cell,
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::{
+    os_str,
+    windows::{self, PrefixKind},
+    CaseSensitivity, Platform, RelativeReport, TryRelativeError, CWD,
+};
+
+/// `0` for none, otherwise see `encode`.
+static DEFAULT_PLATFORM: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+    static SCOPED_PLATFORM: Cell<Option<Platform>> = const { Cell::new(None) };
+}
+
+fn encode(platform: Option<Platform>) -> u8 {
+    match platform {
+        None => 0,
+        Some(Platform::Posix) => 1,
+        Some(Platform::Windows) => 2,
+    }
+}
+
+fn decode(platform: u8) -> Option<Platform> {
+    match platform {
+        1 => Some(Platform::Posix),
+        2 => Some(Platform::Windows),
+        _ => None,
+    }
+}
+
+/// Makes [`normalize`](crate::SugarPath::normalize), [`resolve`](crate::SugarPath::resolve), [`relative`](crate::SugarPath::relative) and its variants follow the rules of `platform` instead of the host's, for the whole process, or stops doing so with `None`.
+///
+/// This is for tools whose paths all belong to another platform, like laying out a Windows installer from Linux, that would otherwise pass a [`Platform`] to every call. The paths then have the separators of `platform` and are compared like on it,. Relative paths are resolved against the current directory of the host as it is, so the override suits absolute paths best.
+///
+/// Besides those three, the override is followed by [`normalize_into`](crate::SugarPath::normalize_into), [`resolve_with`](crate::SugarPath::resolve_with), [`try_relative`](crate::SugarPath::try_relative), [`relative_with_case`](crate::SugarPath::relative_with_case), [`relative_into`](crate::SugarPath::relative_into), [`relative_with_report`](crate::SugarPath::relative_with_report), [`split_root`](crate::SugarPath::split_root), [`parts`](crate::SugarPath::parts), [`is_relative_to`](crate::SugarPath::is_relative_to), [`escape_depth`](crate::SugarPath::escape_depth), [`clamp_to`](crate::SugarPath::clamp_to), [`concat_overlapping`](crate::SugarPath::concat_overlapping), [`canonical_key`](crate::SugarPath::canonical_key), [`is_inside_hidden_dir`](crate::SugarPath::is_inside_hidden_dir), the methods of [`SugarPathBuf`](crate::SugarPathBuf), [`Scratch`](crate::Scratch), and the `par_*` helpers, which carry it over to their worker threads. Other methods, like [`components_between`](crate::SugarPath::components_between), and [`Pattern`](crate::Pattern) still read paths the way the host does.
+///
+/// [`with_default_platform`] overrides this for a single thread.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{set_default_platform, Platform, SugarPath};
+///
+/// set_default_platform(Some(Platform::Windows));
+/// assert_eq!(Path::new("C:/app/./bin/../lib").normalize().as_os_str(), r"C:\app\lib");
+/// assert_eq!(Path::new(r"C:\app\lib").relative(r"c:\APP\bin").as_os_str(), r"..\lib");
+/// set_default_platform(None);
+/// ```
+pub fn set_default_platform(platform: Option<Platform>) {
+    DEFAULT_PLATFORM.store(encode(platform), Ordering::Relaxed);
+}
+
+/// The platform whose rules paths follow by default: the one set on this thread by [`with_default_platform`], or else by [`set_default_platform`], or else the host's.
+pub fn default_platform() -> Platform {
+    SCOPED_PLATFORM
+        .with(Cell::get)
+        .or_else(|| decode(DEFAULT_PLATFORM.load(Ordering::Relaxed)))
+        .unwrap_or_else(Platform::current)
+}
+
+/// Calls `f` with the default platform of the current thread set to `platform`, see [`set_default_platform`].
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{with_default_platform, Platform, SugarPath};
+///
+/// let normalized = with_default_platform(Platform::Posix, || Path::new("a/./b").normalize());
+/// assert_eq!(normalized.as_os_str(), "a/b");
+/// ```
+pub fn with_default_platform<R>(platform: Platform, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Platform>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED_PLATFORM.with(|scoped| scoped.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SCOPED_PLATFORM.with(|scoped| scoped.replace(Some(platform))));
+    f()
+}
+
+/// The default platform, if it isn't the host's.
+pub(crate) fn overridden() -> Option<Platform> {
+    Some(default_platform()).filter(|platform| *platform != Platform::current())
+}
+
+/// A path split the way `platform` reads it, with its names normalized.
+pub(crate) struct Parts<'a> {
+    /// The prefix and the root, with the separators of `platform`.
+    pub(crate) anchor: OsString,
+    pub(crate) has_root: bool,
+    pub(crate) names: Vec<&'a OsStr>,
+    pub(crate) trailing_separator: bool,
+}
+
+pub(crate) fn split(path: &OsStr, platform: Platform) -> Parts<'_> {
+    let bytes = path.as_encoded_bytes();
+    let is_separator = |b: &u8| platform.is_separator(*b);
+    let (mut anchor, rest) = match platform {
+        Platform::Windows => match windows::parse_prefix(path) {
+            Some((kind, rest)) => {
+                let prefix = &bytes[..bytes.len() - rest.len()];
+                let mut anchor = OsString::with_capacity(prefix.len() + 1);
+                for piece in prefix.split_inclusive(is_separator) {
+                    match piece.split_last() {
+                        Some((last, name)) if is_separator(last) => {
+                            anchor.push(os_str::from_encoded_bytes(name));
+                            anchor.push("\\");
+                        }
+                        _ => anchor.push(os_str::from_encoded_bytes(piece)),
+                    }
+                }
+                // A share or a device is a root by itself.
+                if matches!(kind, PrefixKind::UNC(..) | PrefixKind::DeviceNS(_)) {
+                    anchor.push("\\");
+                }
+                (anchor, rest.as_encoded_bytes())
+            }
+            None => (OsString::new(), bytes),
+        },
+        Platform::Posix => (OsString::new(), bytes),
+    };
+    let has_root =
+        rest.first().is_some_and(is_separator) || anchor.as_encoded_bytes().ends_with(b"\\");
+    if rest.first().is_some_and(is_separator) && !anchor.as_encoded_bytes().ends_with(b"\\") {
+        anchor.push(separator(platform));
+    }
+    let mut names: Vec<&OsStr> = vec![];
+    for name in rest.split(is_separator) {
+        match name {
+            b"" | b"." => {}
+            b".." => match names.last() {
+                Some(last) if *last != ".." => {
+                    names.pop();
+                }
+                _ if has_root => {}
+                _ => names.push(os_str::from_encoded_bytes(name)),
+            },
+            name => names.push(os_str::from_encoded_bytes(name)),
+        }
+    }
+    Parts {
+        anchor,
+        has_root,
+        names,
+        trailing_separator: bytes.last().is_some_and(is_separator),
+    }
+}
+
+fn separator(platform: Platform) -> &'static str {
+    match platform {
+        Platform::Posix => "/",
+        Platform::Windows => "\\",
+    }
+}
+
+fn join(anchor: OsString, names: &[&OsStr], platform: Platform) -> OsString {
+    let mut path = anchor;
+    push_names(&mut path, names, platform);
+    path
+}
+
+fn push_names(path: &mut OsString, names: &[&OsStr], platform: Platform) {
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            path.push(separator(platform));
+        }
+        path.push(name);
+    }
+}
+
+/// Normalizes `path` following the rules of `platform`, on any host.
+pub(crate) fn normalize_as(path: &Path, platform: Platform) -> PathBuf {
+    let mut normalized = OsString::new();
+    normalize_as_into(path, platform, &mut normalized);
+    normalized.into()
+}
+
+/// Like `normalize_as`, but clears `buf` and writes the result into it.
+pub(crate) fn normalize_as_into(path: &Path, platform: Platform, buf: &mut OsString) {
+    buf.clear();
+    let path = path.as_os_str();
+    if platform == Platform::Windows
+        && windows::parse_prefix(path).is_some_and(|(kind, _)| kind.is_verbatim())
+    {
+        // Windows passes verbatim paths on untouched.
+        buf.push(path);
+        return;
+    }
+    let parts = split(path, platform);
+    buf.push(&parts.anchor);
+    push_names(buf, &parts.names, platform);
+    if parts.names.is_empty() && !parts.has_root {
+        // `C:.`, like `normalize` makes of `C:` on Windows.
+        buf.push(".");
+    }
+    let separator = separator(platform);
+    if parts.trailing_separator && !buf.as_encoded_bytes().ends_with(separator.as_bytes()) {
+        buf.push(separator);
+    }
+}
+
+fn is_absolute(path: &OsStr, platform: Platform) -> bool {
+    match platform {
+        Platform::Posix => path.as_encoded_bytes().first() == Some(&b'/'),
+        Platform::Windows => windows::classify_windows_kind(path).is_absolute(),
+    }
+}
+
+/// Resolves `path` against `base`, itself resolved against the current directory, following the rules of `platform`.
+pub(crate) fn resolve_as(path: &Path, base: &Path, platform: Platform) -> PathBuf {
+    if is_absolute(path.as_os_str(), platform) {
+        return normalize_as(path, platform);
+    }
+    let mut joined = OsString::new();
+    match platform {
+        Platform::Windows
+            if windows::classify_windows_kind(path.as_os_str())
+                == windows::WindowsPathKind::DriveRelative =>
+        {
+            // `C:foo` is taken as `C:\foo`, as on Windows.
+            let bytes = path.as_os_str().as_encoded_bytes();
+            joined.push(os_str::from_encoded_bytes(&bytes[..2]));
+            joined.push("\\");
+            joined.push(os_str::from_encoded_bytes(&bytes[2..]));
+        }
+        Platform::Windows
+            if windows::classify_windows_kind(path.as_os_str())
+                == windows::WindowsPathKind::Rooted =>
+        {
+            joined = push_as(base.as_os_str(), path.as_os_str(), platform);
+        }
+        _ => {
+            // The current directory is taken as it is, even if it isn't absolute for `platform`.
+            if !is_absolute(base.as_os_str(), platform) && base != CWD.as_path() {
+                joined.push(CWD.as_os_str());
+                joined.push(separator(platform));
+            }
+            joined.push(base.as_os_str());
+            joined.push(separator(platform));
+            joined.push(path.as_os_str());
+        }
+    }
+    normalize_as(Path::new(&joined), platform)
+}
+
+/// Appends `path` to `base` like `PathBuf::push` does on `platform`: an absolute `path` replaces `base`, and on Windows a rooted one like `\foo` keeps the drive or share of `base`.
+fn push_as(base: &OsStr, path: &OsStr, platform: Platform) -> OsString {
+    let kind = windows::classify_windows_kind(path);
+    if is_absolute(path, platform)
+        || (platform == Platform::Windows && kind == windows::WindowsPathKind::DriveRelative)
+    {
+        return path.to_os_string();
+    }
+    let mut joined = OsString::new();
+    if platform == Platform::Windows && kind == windows::WindowsPathKind::Rooted {
+        if let Some((_, rest)) = windows::parse_prefix(base) {
+            let bytes = base.as_encoded_bytes();
+            joined.push(os_str::from_encoded_bytes(
+                &bytes[..bytes.len() - rest.len()],
+            ));
+        }
+    } else {
+        joined.push(base);
+        if !base.is_empty()
+            && !base
+                .as_encoded_bytes()
+                .last()
+                .is_some_and(|b| platform.is_separator(*b))
+        {
+            joined.push(separator(platform));
+        }
+    }
+    joined.push(path);
+    joined
+}
+
+/// `path` without its prefix and root, following the rules of `platform`.
+fn strip_anchor(path: &OsStr, platform: Platform) -> &OsStr {
+    let rest = match platform {
+        Platform::Windows => windows::parse_prefix(path).map_or(path, |(_, rest)| rest),
+        Platform::Posix => path,
+    };
+    let bytes = rest.as_encoded_bytes();
+    let start = bytes
+        .iter()
+        .position(|b| !platform.is_separator(*b))
+        .unwrap_or(bytes.len());
+    os_str::from_encoded_bytes(&bytes[start..])
+}
+
+/// See [`SugarPath::split_root`](crate::SugarPath::split_root).
+pub(crate) fn split_root_as(path: &Path, platform: Platform) -> (PathBuf, PathBuf) {
+    let parts = split(path.as_os_str(), platform);
+    let rest = join(OsString::new(), &parts.names, platform);
+    (parts.anchor.into(), rest.into())
+}
+
+/// See [`SugarPath::parts`](crate::SugarPath::parts).
+pub(crate) fn parts_as(path: &Path, platform: Platform) -> Vec<OsString> {
+    let parts = split(path.as_os_str(), platform);
+    let anchor = (!parts.anchor.is_empty()).then_some(parts.anchor);
+    anchor
+        .into_iter()
+        .chain(parts.names.iter().map(|name| name.to_os_string()))
+        .collect()
+}
+
+/// See [`SugarPath::is_relative_to`](crate::SugarPath::is_relative_to).
+pub(crate) fn is_relative_to_as(path: &Path, base: &Path, platform: Platform) -> bool {
+    let case = case(platform);
+    let (path, base) = (
+        split(path.as_os_str(), platform),
+        split(base.as_os_str(), platform),
+    );
+    if base.anchor.is_empty() && base.names.is_empty() {
+        // `.` contains every relative path that doesn't walk out of it.
+        return path.anchor.is_empty() && path.names.first() != Some(&OsStr::new(".."));
+    }
+    case.eq_os_str(&path.anchor, &base.anchor)
+        && base.names.len() <= path.names.len()
+        && base
+            .names
+            .iter()
+            .zip(&path.names)
+            .all(|(a, b)| case.eq_os_str(a, b))
+}
+
+/// See [`SugarPath::escape_depth`](crate::SugarPath::escape_depth).
+pub(crate) fn escape_depth_as(path: &Path, platform: Platform) -> usize {
+    split(path.as_os_str(), platform)
+        .names
+        .iter()
+        .take_while(|name| **name == "..")
+        .count()
+}
+
+/// See [`SugarPath::clamp_to`](crate::SugarPath::clamp_to).
+pub(crate) fn clamp_to_as(path: &Path, base: &Path, platform: Platform) -> PathBuf {
+    // A root already absorbed every `..`, and those left lead out of a relative path.
+    let parts = split(path.as_os_str(), platform);
+    let names = parts.names.iter().filter(|name| **name != "..");
+    let base_parts = split(base.as_os_str(), platform);
+    if names.clone().next().is_none() {
+        return normalize_as(base, platform);
+    }
+    let mut all = base_parts.names.clone();
+    all.extend(names);
+    join(base_parts.anchor, &all, platform).into()
+}
+
+/// See [`SugarPath::concat_overlapping`](crate::SugarPath::concat_overlapping).
+pub(crate) fn concat_overlapping_as(path: &Path, other: &Path, platform: Platform) -> PathBuf {
+    let other_parts = split(other.as_os_str(), platform);
+    if !other_parts.anchor.is_empty() {
+        let joined = push_as(path.as_os_str(), other.as_os_str(), platform);
+        return normalize_as(Path::new(&joined), platform);
+    }
+    let case = case(platform);
+    let parts = split(path.as_os_str(), platform);
+    let (names, other) = (&parts.names, &other_parts.names);
+    let overlap = (1..=names.len().min(other.len()))
+        .rev()
+        .find(|&len| {
+            other[..len]
+                .iter()
+                .zip(&names[names.len() - len..])
+                .all(|(a, b)| *a != ".." && case.eq_os_str(a, b))
+        })
+        .unwrap_or(0);
+    let mut all = names.clone();
+    all.extend(&other[overlap..]);
+    let joined = join(parts.anchor, &all, platform);
+    normalize_as(Path::new(&joined), platform)
+}
+
+/// See [`SugarPath::is_inside_hidden_dir`](crate::SugarPath::is_inside_hidden_dir).
+pub(crate) fn is_inside_hidden_dir_as(path: &Path, platform: Platform) -> bool {
+    let parts = split(path.as_os_str(), platform);
+    let dirs = parts.names.split_last().map_or(&[][..], |(_, dirs)| dirs);
+    dirs.iter()
+        .any(|name| *name != ".." && name.as_encoded_bytes().starts_with(b"."))
+}
+
+/// See [`SugarPathBuf::pop_normalized`](crate::SugarPathBuf::pop_normalized), returning `None` for a root.
+pub(crate) fn pop_normalized_as(path: &Path, platform: Platform) -> Option<PathBuf> {
+    let mut parts = split(path.as_os_str(), platform);
+    match parts.names.last() {
+        None if parts.has_root => return None,
+        Some(name) if *name != ".." => {
+            parts.names.pop();
+        }
+        _ => parts.names.push(OsStr::new("..")),
+    }
+    let joined = join(parts.anchor, &parts.names, platform);
+    Some(normalize_as(Path::new(&joined), platform))
+}
+
+/// See [`SugarPathBuf::push_normalized`](crate::SugarPathBuf::push_normalized), and [`push_normalized_allow_absolute`](crate::SugarPathBuf::push_normalized_allow_absolute) if `allow_absolute`.
+pub(crate) fn push_normalized_as(
+    buf: &Path,
+    path: &Path,
+    allow_absolute: bool,
+    platform: Platform,
+) -> PathBuf {
+    let path = if allow_absolute {
+        path.as_os_str()
+    } else {
+        strip_anchor(path.as_os_str(), platform)
+    };
+    let base = if buf.as_os_str().is_empty() {
+        OsStr::new(".")
+    } else {
+        buf.as_os_str()
+    };
+    let joined = push_as(base, path, platform);
+    normalize_as(Path::new(&joined), platform)
+}
+
+/// How `platform` compares names.
+pub(crate) fn case(platform: Platform) -> CaseSensitivity {
+    match platform {
+        Platform::Posix => CaseSensitivity::Sensitive,
+        Platform::Windows => CaseSensitivity::AsciiInsensitive,
+    }
+}
+
+/// How many names `target` shares with `base` below their common anchor, if a relative path leads from `base` to `target`.
+fn shared_names(
+    target: &Parts<'_>,
+    base: &Parts<'_>,
+    case: CaseSensitivity,
+) -> Result<usize, TryRelativeError> {
+    if !case.eq_os_str(&target.anchor, &base.anchor) {
+        return Err(TryRelativeError::DifferentRoots);
+    }
+    let shared = target
+        .names
+        .iter()
+        .zip(&base.names)
+        .take_while(|(a, b)| case.eq_os_str(a, b))
+        .count();
+    // Going back down a `..` of the base would need the name of the directory it left.
+    if base.names[shared..].contains(&OsStr::new("..")) {
+        return Err(TryRelativeError::UnresolvedParent);
+    }
+    Ok(shared)
+}
+
+/// The path from `to` to `path` after resolving both following the rules of `platform`.
+pub(crate) fn try_relative_as(
+    path: &Path,
+    to: &Path,
+    platform: Platform,
+    case: CaseSensitivity,
+) -> Result<PathBuf, TryRelativeError> {
+    let (target, base) = (
+        resolve_as(path, &CWD, platform),
+        resolve_as(to, &CWD, platform),
+    );
+    let (target, base) = (
+        split(target.as_os_str(), platform),
+        split(base.as_os_str(), platform),
+    );
+    let shared = shared_names(&target, &base, case)?;
+    let mut names = vec![OsStr::new(".."); base.names.len() - shared];
+    names.extend(&target.names[shared..]);
+    Ok(join(OsString::new(), &names, platform).into())
+}
+
+/// Like `try_relative_as`, but returns the resolved `path` if there is no relative path.
+pub(crate) fn relative_as(
+    path: &Path,
+    to: &Path,
+    platform: Platform,
+    case: CaseSensitivity,
+) -> PathBuf {
+    try_relative_as(path, to, platform, case).unwrap_or_else(|_| resolve_as(path, &CWD, platform))
+}
+
+/// Like `relative_as`, also reporting how the relative path was found.
+pub(crate) fn relative_with_report_as(
+    path: &Path,
+    to: &Path,
+    platform: Platform,
+    case: CaseSensitivity,
+) -> (PathBuf, RelativeReport) {
+    let mut report = RelativeReport {
+        common_ancestor: None,
+        ascended: 0,
+        descended: 0,
+        case,
+        case_folded: false,
+    };
+    let (target, base) = (
+        resolve_as(path, &CWD, platform),
+        resolve_as(to, &CWD, platform),
+    );
+    let (target_parts, base_parts) = (
+        split(target.as_os_str(), platform),
+        split(base.as_os_str(), platform),
+    );
+    let Ok(shared) = shared_names(&target_parts, &base_parts, case) else {
+        return (target, report);
+    };
+    report.case_folded = target_parts.anchor != base_parts.anchor
+        || target_parts.names[..shared] != base_parts.names[..shared];
+    report.ascended = base_parts.names.len() - shared;
+    report.descended = target_parts.names.len() - shared;
+    let mut names = vec![OsStr::new(".."); report.ascended];
+    names.extend(&target_parts.names[shared..]);
+    let relative = join(OsString::new(), &names, platform).into();
+    report.common_ancestor =
+        Some(join(target_parts.anchor, &target_parts.names[..shared], platform).into());
+    (relative, report)
+}
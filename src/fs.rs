@@ -0,0 +1,289 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::CaseSensitivity;
+
+/// What kind of entry a path points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    File,
+    Dir,
+    Symlink,
+}
+
+/// The subset of metadata the crate's filesystem operations rely on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Metadata {
+    pub kind: FileKind,
+    /// The size in bytes, or 0 where the filesystem has no notion of it.
+    pub len: u64,
+}
+
+impl Metadata {
+    pub fn is_file(&self) -> bool {
+        self.kind == FileKind::File
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.kind == FileKind::Dir
+    }
+
+    pub fn is_symlink(&self) -> bool {
+        self.kind == FileKind::Symlink
+    }
+}
+
+/// The filesystem queries the fs-aware operations of the crate are built on.
+///
+/// [`StdFileSystem`] goes to the real filesystem through `std::fs`; implement the trait to run the same
+/// operations over an in-memory, virtual or remote one. A [`VirtualTree`](crate::VirtualTree) is a filesystem
+/// whose inserted paths are files and whose ancestors of those are directories.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{FileSystem, VirtualTree};
+///
+/// let tree: VirtualTree = ["src/main.rs", "src/util/mod.rs"].into_iter().collect();
+/// assert!(tree.metadata(Path::new("src/util")).unwrap().is_dir());
+/// assert_eq!(
+///     tree.read_dir(Path::new("src")).unwrap(),
+///     [Path::new("src/main.rs"), Path::new("src/util")]
+/// );
+/// ```
+pub trait FileSystem {
+    /// The metadata of `path`, following symbolic links.
+    fn metadata(&self, path: &Path) -> io::Result<Metadata>;
+
+    /// The metadata of `path` itself, without following a symbolic link.
+    fn symlink_metadata(&self, path: &Path) -> io::Result<Metadata> {
+        self.metadata(path)
+    }
+
+    /// The target of the symbolic link at `path`.
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf>;
+
+    /// The paths of the entries of the directory at `path`, each joined onto `path`, in sorted order.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
+    /// Whether `path` points at an existing entry, following symbolic links.
+    fn exists(&self, path: &Path) -> bool {
+        self.metadata(path).is_ok()
+    }
+}
+
+impl<T: FileSystem + ?Sized> FileSystem for &T {
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        (**self).metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<Metadata> {
+        (**self).symlink_metadata(path)
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        (**self).read_link(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        (**self).read_dir(path)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        (**self).exists(path)
+    }
+}
+
+/// The real filesystem, through `std::fs`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StdFileSystem;
+
+impl From<std::fs::Metadata> for Metadata {
+    fn from(metadata: std::fs::Metadata) -> Self {
+        let file_type = metadata.file_type();
+        let kind = if file_type.is_symlink() {
+            FileKind::Symlink
+        } else if file_type.is_dir() {
+            FileKind::Dir
+        } else {
+            FileKind::File
+        };
+        Metadata {
+            kind,
+            len: metadata.len(),
+        }
+    }
+}
+
+impl FileSystem for StdFileSystem {
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        std::fs::metadata(path).map(Metadata::from)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<Metadata> {
+        std::fs::symlink_metadata(path).map(Metadata::from)
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        std::fs::read_link(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut entries = std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        Ok(entries)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+}
+
+/// Finds out whether names in the directory `dir` are compared case-sensitively, by asking its filesystem rather than guessing from the OS, since macOS, Windows and WSL all mount both kinds.
+///
+/// An entry of `dir` whose name has a letter with a one-char other case is looked up with its case swapped. Only if there is none, an empty probe file is created and removed again, which needs write access. Case-insensitive filesystems fold more than ASCII, so they are reported as [`CaseSensitivity::UnicodeInsensitive`].
+///
+/// Requires the `fs` feature.
+///
+/// ```rust
+/// use sugar_path::{probe_case_sensitivity, CaseSensitivity};
+///
+/// let case = probe_case_sensitivity(std::env::temp_dir()).unwrap();
+/// assert!(matches!(case, CaseSensitivity::Sensitive | CaseSensitivity::UnicodeInsensitive));
+/// ```
+pub fn probe_case_sensitivity(dir: impl AsRef<Path>) -> io::Result<CaseSensitivity> {
+    let dir = dir.as_ref();
+    let names = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.file_name()))
+        .collect::<io::Result<Vec<_>>>()?;
+    for name in &names {
+        let Some(swapped) = name.to_str().and_then(swap_case) else {
+            continue;
+        };
+        // A sibling spelled that way proves nothing.
+        if names.iter().any(|name| *name == *swapped) {
+            continue;
+        }
+        return Ok(case_sensitivity(
+            dir.join(swapped).symlink_metadata().is_ok(),
+        ));
+    }
+
+    // Threads probing the same directory each need their own file.
+    static PROBES: AtomicUsize = AtomicUsize::new(0);
+    let probe = format!(
+        ".sugar_path-case-probe-{}-{}",
+        std::process::id(),
+        PROBES.fetch_add(1, Ordering::Relaxed)
+    );
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(dir.join(&probe))?;
+    let _probe = RemoveOnDrop(dir.join(&probe));
+    let insensitive = dir.join(probe.to_uppercase()).symlink_metadata().is_ok();
+    Ok(case_sensitivity(insensitive))
+}
+
+struct RemoveOnDrop(PathBuf);
+
+impl Drop for RemoveOnDrop {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Swaps the case of every letter whose other case is a single char, so the name keeps its length. Case-insensitive filesystems fold one char to one char, so none would find `ß` spelled `SS` or `İ` spelled `i̇`.
+fn swap_case(name: &str) -> Option<String> {
+    let swapped = name
+        .chars()
+        .map(|c| {
+            let mut upper = c.to_uppercase();
+            let mut lower = c.to_lowercase();
+            match (upper.next(), upper.next(), lower.next(), lower.next()) {
+                (Some(upper), None, _, _) if upper != c => upper,
+                (_, _, Some(lower), None) if lower != c => lower,
+                _ => c,
+            }
+        })
+        .collect::<String>();
+    (swapped != name).then_some(swapped)
+}
+
+fn case_sensitivity(insensitive: bool) -> CaseSensitivity {
+    if insensitive {
+        CaseSensitivity::UnicodeInsensitive
+    } else {
+        CaseSensitivity::Sensitive
+    }
+}
+
+#[cfg(target_family = "windows")]
+pub(crate) fn expand_short_names(path: &Path) -> io::Result<PathBuf> {
+    use std::{
+        ffi::OsString,
+        os::windows::ffi::{OsStrExt, OsStringExt},
+    };
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetLongPathNameW(short_path: *const u16, long_path: *mut u16, len: u32) -> u32;
+    }
+
+    let short = path
+        .as_os_str()
+        .encode_wide()
+        .chain(Some(0))
+        .collect::<Vec<_>>();
+    let mut buf = vec![0u16; 260];
+    loop {
+        // SAFETY: `short` is NUL-terminated and `buf` is valid for `buf.len()` units.
+        let len = unsafe { GetLongPathNameW(short.as_ptr(), buf.as_mut_ptr(), buf.len() as u32) }
+            as usize;
+        if len == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if len < buf.len() {
+            buf.truncate(len);
+            return Ok(PathBuf::from(OsString::from_wide(&buf)));
+        }
+        // The buffer was too small and `len` is the required size, including the terminator.
+        buf.resize(len, 0);
+    }
+}
+
+#[cfg(not(target_family = "windows"))]
+pub(crate) fn expand_short_names(path: &Path) -> io::Result<PathBuf> {
+    // Fail for a missing path the way `GetLongPathNameW` does.
+    std::fs::symlink_metadata(path)?;
+    Ok(path.to_path_buf())
+}
+
+/// Whether the filesystem flags `path` as hidden, regardless of its name.
+pub(crate) fn has_hidden_attribute(path: &Path) -> bool {
+    #[cfg(target_family = "windows")]
+    {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+        std::fs::symlink_metadata(path)
+            .map(|metadata| metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0)
+            .unwrap_or(false)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        use std::os::macos::fs::MetadataExt;
+        const UF_HIDDEN: u32 = 0x8000;
+        std::fs::symlink_metadata(path)
+            .map(|metadata| metadata.st_flags() & UF_HIDDEN != 0)
+            .unwrap_or(false)
+    }
+    #[cfg(not(any(target_family = "windows", target_os = "macos")))]
+    {
+        let _ = path;
+        false
+    }
+}
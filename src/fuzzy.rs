@@ -0,0 +1,163 @@
+use std::path::Path;
+
+use crate::{Platform, SugarPath};
+
+const SCORE_MATCH: i32 = 16;
+const GAP_START: i32 = 3;
+const GAP_EXTENSION: i32 = 1;
+const BONUS_SEPARATOR: i32 = 10;
+const BONUS_DELIMITER: i32 = 8;
+const BONUS_CAMEL_CASE: i32 = 7;
+const BONUS_CONSECUTIVE: i32 = 6;
+/// The first character of the query counts its bonus this many times.
+const FIRST_CHAR_MULTIPLIER: i32 = 2;
+
+/// A successful match of [`fuzzy_match`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FuzzyMatch {
+    /// How well the query matches, higher is better. Only meaningful compared with other scores for the same query.
+    pub score: i32,
+    /// The indices of the matched characters, in `char`s, within the lossy string of the normalized path.
+    pub positions: Vec<usize>,
+}
+
+/// Matches `query` against the normalized `path` the way fuzzy file pickers do: every character of the query has to appear in the path, in order, but not necessarily next to each other.
+///
+/// Matches are ranked by bonuses for characters that start a component, follow a `-`, `_`, `.` or space, start a camel-case hump or continue the previous match, and by penalties for the gaps between them. So `slib` ranks `src/lib.rs` above `tests/slice_lib.rs`. The best scoring alignment is returned, with the positions of its characters for highlighting.
+///
+/// Matching ignores ASCII case, unless the query has an uppercase letter. An empty query matches everything with a score of `0`.
+///
+/// ```rust
+/// use sugar_path::fuzzy_match;
+///
+/// let src = fuzzy_match("slib", "src/lib.rs").unwrap();
+/// assert_eq!(src.positions, [0, 4, 5, 6]);
+/// let tests = fuzzy_match("slib", "tests/slice_lib.rs").unwrap();
+/// assert!(src.score > tests.score);
+/// assert_eq!(fuzzy_match("slib", "src/main.rs"), None);
+/// ```
+pub fn fuzzy_match(query: &str, path: impl AsRef<Path>) -> Option<FuzzyMatch> {
+    let path = path.as_ref().normalize();
+    let text = path.as_os_str().to_string_lossy();
+    let case_sensitive = query.chars().any(char::is_uppercase);
+    let fold = |c: char| {
+        if case_sensitive {
+            c
+        } else {
+            c.to_ascii_lowercase()
+        }
+    };
+    let query = query.chars().map(fold).collect::<Vec<_>>();
+    let chars = text.chars().collect::<Vec<_>>();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: vec![],
+        });
+    }
+    if query.len() > chars.len() {
+        return None;
+    }
+
+    let bonuses = (0..chars.len())
+        .map(|i| bonus(i.checked_sub(1).map(|prev| chars[prev]), chars[i]))
+        .collect::<Vec<_>>();
+    let is_match = |i: usize, j: usize| fold(chars[j]) == query[i];
+
+    // `scores[i][j]` is the best score of `query[..=i]` with `query[i]` matched at `chars[j]`, and `from[i][j]` is
+    // where `query[i - 1]` was matched in that alignment.
+    let width = chars.len();
+    let mut scores = vec![None::<i32>; query.len() * width];
+    let mut from = vec![0; query.len() * width];
+    for j in 0..width {
+        if is_match(0, j) {
+            scores[j] = Some(SCORE_MATCH + bonuses[j] * FIRST_CHAR_MULTIPLIER);
+        }
+    }
+    for i in 1..query.len() {
+        let (previous, current) = scores.split_at_mut(i * width);
+        let previous = &previous[(i - 1) * width..];
+        // The best alignment of `query[..i]` ending before `j - 1`, with the gap up to `j` already paid for.
+        let mut gapped: Option<(i32, usize)> = None;
+        for j in 1..width {
+            if j >= 2 {
+                let extended = gapped.map(|(score, k)| (score - GAP_EXTENSION, k));
+                let started = previous[j - 2].map(|score| (score - GAP_START, j - 2));
+                gapped = match (extended, started) {
+                    (Some(extended), Some(started)) if started.0 > extended.0 => Some(started),
+                    (Some(extended), _) => Some(extended),
+                    (None, started) => started,
+                };
+            }
+            if !is_match(i, j) {
+                continue;
+            }
+            let consecutive = previous[j - 1].map(|score| (score + BONUS_CONSECUTIVE, j - 1));
+            let best = match (consecutive, gapped) {
+                (Some(consecutive), Some(gapped)) if gapped.0 > consecutive.0 => Some(gapped),
+                (Some(consecutive), _) => Some(consecutive),
+                (None, gapped) => gapped,
+            };
+            if let Some((score, k)) = best {
+                current[j] = Some(score + SCORE_MATCH + bonuses[j]);
+                from[i * width + j] = k;
+            }
+        }
+    }
+
+    let last = query.len() - 1;
+    let (mut j, score) = scores[last * width..]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, score)| score.map(|score| (j, score)))
+        // The leftmost of the best alignments.
+        .max_by(|(a_j, a), (b_j, b)| a.cmp(b).then(b_j.cmp(a_j)))?;
+    let mut positions = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        positions[i] = j;
+        j = from[i * width + j];
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Matches `query` against every path with [`fuzzy_match`] and returns the indices of the matching paths with their matches, best first.
+///
+/// Ties are broken in favor of shorter paths, then of the order of `paths`.
+///
+/// ```rust
+/// use sugar_path::fuzzy_rank;
+///
+/// let paths = ["tests/slice_lib.rs", "src/main.rs", "src/lib.rs"];
+/// let ranked = fuzzy_rank("slib", &paths);
+/// assert_eq!(ranked.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [2, 0]);
+/// ```
+pub fn fuzzy_rank<P: AsRef<Path>>(query: &str, paths: &[P]) -> Vec<(usize, FuzzyMatch)> {
+    let mut ranked = paths
+        .iter()
+        .enumerate()
+        .filter_map(|(i, path)| fuzzy_match(query, path).map(|m| (i, m)))
+        .collect::<Vec<_>>();
+    ranked.sort_by(|(a_i, a), (b_i, b)| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| {
+                let len = |i: usize| paths[i].as_ref().as_os_str().len();
+                len(*a_i).cmp(&len(*b_i))
+            })
+            .then(a_i.cmp(b_i))
+    });
+    ranked
+}
+
+/// The bonus for matching `c`, which follows `prev`.
+fn bonus(prev: Option<char>, c: char) -> i32 {
+    let is_separator = |c: char| c.is_ascii() && Platform::current().is_separator(c as u8);
+    match prev {
+        None => BONUS_SEPARATOR,
+        Some(prev) if is_separator(prev) => BONUS_SEPARATOR,
+        Some('-' | '_' | '.' | ' ') if c.is_alphanumeric() => BONUS_DELIMITER,
+        Some(prev) if prev.is_lowercase() && c.is_uppercase() => BONUS_CAMEL_CASE,
+        Some(prev) if !prev.is_ascii_digit() && c.is_ascii_digit() => BONUS_CAMEL_CASE,
+        _ => 0,
+    }
+}
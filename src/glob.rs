@@ -0,0 +1,314 @@
+use std::{
+    error::Error,
+    ffi::OsString,
+    fmt,
+    path::{Component, Path},
+};
+
+use crate::{component_eq, CaseSensitivity, Platform, SugarPath};
+
+/// Why a glob pattern couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    /// A `[` in this segment is never closed.
+    UnclosedClass(String),
+    /// A character range in this segment runs backwards, like `[z-a]`.
+    InvalidRange(String),
+    /// The pattern is empty where it has to match something, see [`SugarPath::match_pattern`].
+    Empty,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::UnclosedClass(segment) => {
+                write!(f, "unclosed character class in {:?}", segment)
+            }
+            PatternError::InvalidRange(segment) => {
+                write!(f, "invalid character range in {:?}", segment)
+            }
+            PatternError::Empty => f.write_str("empty pattern"),
+        }
+    }
+}
+
+impl Error for PatternError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Char(char),
+    AnyChar,
+    AnyChars,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// A root, prefix or `..`, or a name without wildcards.
+    Exact(OsString),
+    Wildcard(Vec<Token>),
+    /// `**`, any number of names.
+    AnyDepth,
+}
+
+/// A glob pattern matched against whole, normalized paths one component at a time.
+///
+/// `?` matches one character, `*` any run of characters within a component, `**` as a whole component any
+/// number of components, and `[abc]`, `[a-z]` or `[!a-z]` one character out of (or not in) a class.
+/// Wildcards never match a separator, a root or a prefix. Wrap a wildcard in a class, like `[*]`, to match it literally.
+///
+/// Patterns and paths are read the way the host does, even under [`set_default_platform`](crate::set_default_platform).
+///
+/// ```rust
+/// use sugar_path::Pattern;
+///
+/// let pattern = Pattern::new("src/**/*.rs").unwrap();
+/// assert!(pattern.matches("src/lib.rs"));
+/// assert!(pattern.matches("./src/a/b/../mod.rs"));
+/// assert!(!pattern.matches("tests/lib.rs"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    original: String,
+    segments: Vec<Segment>,
+    case: CaseSensitivity,
+}
+
+impl Pattern {
+    /// Parses `pattern`. It is normalized first, so `a/./b` and `a//b` mean `a/b`.
+    pub fn new(pattern: &str) -> Result<Self, PatternError> {
+        let normalized = Path::new(pattern).normalize_as(Platform::current());
+        let mut segments = Vec::new();
+        for component in normalized.components() {
+            let segment = match component {
+                Component::Normal(name) => {
+                    // Patterns are `&str`, so their components are too.
+                    parse_segment(name.to_str().unwrap())?
+                }
+                // A normalized path only has `.` when it is empty.
+                Component::CurDir => continue,
+                other => Segment::Exact(other.as_os_str().to_os_string()),
+            };
+            if segment == Segment::AnyDepth && segments.last() == Some(&Segment::AnyDepth) {
+                continue;
+            }
+            segments.push(segment);
+        }
+        Ok(Pattern {
+            original: pattern.to_string(),
+            segments,
+            case: CaseSensitivity::Sensitive,
+        })
+    }
+
+    /// Sets how names and characters are compared. Patterns are case-sensitive by default.
+    pub fn case_sensitivity(mut self, case: CaseSensitivity) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// The pattern as it was written.
+    pub fn as_str(&self) -> &str {
+        &self.original
+    }
+
+    /// Whether the normalized `path` matches the whole pattern.
+    pub fn matches(&self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref().normalize_as(Platform::current());
+        let components = path.components().collect::<Vec<_>>();
+        self.matches_components(&components)
+    }
+
+    /// The pattern with `**/` in front, which matches at any depth.
+    #[cfg(feature = "fs")]
+    pub(crate) fn recursive(&self) -> Pattern {
+        let mut segments = self.segments.clone();
+        if segments.first() != Some(&Segment::AnyDepth) {
+            segments.insert(0, Segment::AnyDepth);
+        }
+        Pattern {
+            original: format!("**/{}", self.original),
+            segments,
+            case: self.case,
+        }
+    }
+
+    /// Whether some path starting with the normalized `components` could match, so walking below them is worthwhile.
+    #[cfg(feature = "fs")]
+    pub(crate) fn matches_prefix(&self, components: &[Component]) -> bool {
+        let mut segments = self.segments.iter();
+        for component in components {
+            match segments.next() {
+                None => return false,
+                Some(Segment::AnyDepth) => return true,
+                Some(segment) => {
+                    if !match_segments(
+                        std::slice::from_ref(segment),
+                        std::slice::from_ref(component),
+                        self.case,
+                    ) {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Like [`Pattern::matches`] for a path that is already normalized.
+    pub(crate) fn matches_components(&self, components: &[Component]) -> bool {
+        let components = match components {
+            [Component::CurDir] => &[],
+            components => components,
+        };
+        match_segments(&self.segments, components, self.case)
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.original)
+    }
+}
+
+fn parse_segment(segment: &str) -> Result<Segment, PatternError> {
+    if segment == "**" {
+        return Ok(Segment::AnyDepth);
+    }
+    if !segment.contains(['*', '?', '[']) {
+        return Ok(Segment::Exact(segment.into()));
+    }
+    let mut tokens = Vec::new();
+    let mut chars = segment.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '?' => Token::AnyChar,
+            '*' => {
+                if tokens.last() == Some(&Token::AnyChars) {
+                    continue;
+                }
+                Token::AnyChars
+            }
+            '[' => {
+                let negated = chars.next_if(|c| matches!(c, '!' | '^')).is_some();
+                let mut ranges = Vec::new();
+                let mut first = true;
+                loop {
+                    let start = match chars.next() {
+                        Some(']') if !first => break,
+                        Some(c) => c,
+                        None => return Err(PatternError::UnclosedClass(segment.to_string())),
+                    };
+                    first = false;
+                    let mut end = start;
+                    if chars.next_if_eq(&'-').is_some() {
+                        match chars.peek() {
+                            Some(']') | None => ranges.push(('-', '-')),
+                            Some(&c) => {
+                                chars.next();
+                                end = c;
+                            }
+                        }
+                    }
+                    if end < start {
+                        return Err(PatternError::InvalidRange(segment.to_string()));
+                    }
+                    ranges.push((start, end));
+                }
+                Token::Class { negated, ranges }
+            }
+            c => Token::Char(c),
+        };
+        tokens.push(token);
+    }
+    Ok(Segment::Wildcard(tokens))
+}
+
+fn match_segments(segments: &[Segment], components: &[Component], case: CaseSensitivity) -> bool {
+    let Some((segment, rest)) = segments.split_first() else {
+        return components.is_empty();
+    };
+    match segment {
+        Segment::AnyDepth => {
+            let names = components
+                .iter()
+                .take_while(|component| matches!(component, Component::Normal(_)))
+                .count();
+            (0..=names).any(|skip| match_segments(rest, &components[skip..], case))
+        }
+        Segment::Exact(expected) => match components.split_first() {
+            Some((component, components)) => {
+                let expected = Path::new(expected).components().next();
+                expected.is_some_and(|expected| component_eq(&expected, component, case))
+                    && match_segments(rest, components, case)
+            }
+            None => false,
+        },
+        Segment::Wildcard(tokens) => match components.split_first() {
+            Some((Component::Normal(name), components)) => {
+                let name = name.to_string_lossy().chars().collect::<Vec<_>>();
+                match_tokens(tokens, &name, case) && match_segments(rest, components, case)
+            }
+            _ => false,
+        },
+    }
+}
+
+fn match_tokens(tokens: &[Token], name: &[char], case: CaseSensitivity) -> bool {
+    // The usual greedy matcher: on a mismatch, let the last `*` swallow one more character and retry.
+    let (mut t, mut n) = (0, 0);
+    let mut backtrack = None;
+    while n < name.len() {
+        match tokens.get(t) {
+            Some(Token::AnyChars) => {
+                backtrack = Some((t, n));
+                t += 1;
+                continue;
+            }
+            Some(token) if match_token(token, name[n], case) => {
+                t += 1;
+                n += 1;
+                continue;
+            }
+            _ => {}
+        }
+        match backtrack {
+            Some((star, start)) => {
+                backtrack = Some((star, start + 1));
+                t = star + 1;
+                n = start + 1;
+            }
+            None => return false,
+        }
+    }
+    tokens[t..].iter().all(|token| *token == Token::AnyChars)
+}
+
+fn match_token(token: &Token, c: char, case: CaseSensitivity) -> bool {
+    match token {
+        Token::Char(expected) => case.eq_char(*expected, c),
+        Token::AnyChar => true,
+        Token::AnyChars => false,
+        Token::Class { negated, ranges } => {
+            let lower = match case {
+                CaseSensitivity::Sensitive => c,
+                CaseSensitivity::AsciiInsensitive => c.to_ascii_lowercase(),
+                CaseSensitivity::UnicodeInsensitive => {
+                    let mut lower = c.to_lowercase();
+                    match (lower.next(), lower.next()) {
+                        (Some(lower), None) => lower,
+                        _ => c,
+                    }
+                }
+            };
+            let in_class = ranges.iter().any(|&(start, end)| {
+                let range = start..=end;
+                range.contains(&c) || range.contains(&case.fold_char(c)) || range.contains(&lower)
+            });
+            in_class != *negated
+        }
+    }
+}
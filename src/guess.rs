@@ -0,0 +1,74 @@
+use std::ffi::OsStr;
+
+use crate::Platform;
+
+/// How strongly a path string points at the platform returned by [`guess_platform`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Confidence {
+    /// Nothing in the string is specific to a platform, e.g. `foo` or `a/b`.
+    Low,
+    /// The string is only usual on one platform, e.g. `a\b` or `C:foo`.
+    Medium,
+    /// The string only makes sense on one platform, e.g. `C:\a`, `\\server\share` or `/usr/bin`.
+    High,
+}
+
+/// The result of [`guess_platform`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PlatformGuess {
+    pub platform: Platform,
+    pub confidence: Confidence,
+}
+
+impl PlatformGuess {
+    fn new(platform: Platform, confidence: Confidence) -> Self {
+        Self {
+            platform,
+            confidence,
+        }
+    }
+}
+
+/// Guesses which platform a path string was written for, looking at drive letters, UNC and verbatim markers, leading separators and which separators are used.
+///
+/// This is meant for data of mixed origin, such as paths read from logs, manifests or databases, where the flavor has to be picked per entry. Strings without any platform specific feature fall back to [`Platform::current`] with [`Confidence::Low`].
+///
+/// ```rust
+/// use sugar_path::{guess_platform, Confidence, Platform};
+///
+/// let guess = guess_platform(r"C:\Users\me");
+/// assert_eq!(guess.platform, Platform::Windows);
+/// assert_eq!(guess.confidence, Confidence::High);
+///
+/// let guess = guess_platform("/usr/lib");
+/// assert_eq!(guess.platform, Platform::Posix);
+/// assert_eq!(guess.confidence, Confidence::High);
+///
+/// assert_eq!(guess_platform("src/lib.rs").confidence, Confidence::Low);
+/// ```
+pub fn guess_platform(path: impl AsRef<OsStr>) -> PlatformGuess {
+    use Confidence::*;
+    use Platform::*;
+
+    let bytes = path.as_ref().as_encoded_bytes();
+    let has_backslash = bytes.contains(&b'\\');
+    let has_slash = bytes.contains(&b'/');
+
+    match bytes {
+        // `\\server\share`, `\\?\C:\`, `\\.\pipe\x` and the NT `\??\C:\`.
+        [b'\\', b'\\', ..] | [b'\\', b'?', b'?', b'\\', ..] => PlatformGuess::new(Windows, High),
+        [letter, b':', rest @ ..] if letter.is_ascii_alphabetic() => match rest {
+            [] | [b'\\' | b'/', ..] => PlatformGuess::new(Windows, High),
+            _ => PlatformGuess::new(Windows, Medium),
+        },
+        [b'/', ..] if !has_backslash => PlatformGuess::new(Posix, High),
+        [b'~', b'/', ..] if !has_backslash => PlatformGuess::new(Posix, Medium),
+        // A leading `/` with backslashes later on, e.g. `/a\b`, is only a valid mix on Windows.
+        [b'/', ..] => PlatformGuess::new(Windows, Medium),
+        [b'\\', ..] => PlatformGuess::new(Windows, Medium),
+        _ if has_backslash && !has_slash => PlatformGuess::new(Windows, Medium),
+        _ if has_backslash => PlatformGuess::new(Windows, Low),
+        _ if has_slash => PlatformGuess::new(Posix, Low),
+        _ => PlatformGuess::new(Platform::current(), Low),
+    }
+}
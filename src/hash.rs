@@ -0,0 +1,71 @@
+use std::{
+    ffi::OsStr,
+    hash::Hasher,
+    path::{Component, Path},
+};
+
+use crate::CaseSensitivity;
+
+const ROOT: u8 = 0xfe;
+const END_OF_COMPONENT: u8 = 0xff;
+
+/// Feeds the normalized components of `path` into `state`, last component first.
+///
+/// Walking the components backwards lets `..` be resolved with a counter instead of a stack, so nothing is allocated.
+pub(crate) fn hash_normalized<H: Hasher>(path: &Path, state: &mut H, case: CaseSensitivity) {
+    let mut pending_parents = 0usize;
+    for component in path.components().rev() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => pending_parents += 1,
+            Component::Normal(name) => {
+                if pending_parents > 0 {
+                    pending_parents -= 1;
+                } else {
+                    write_component(state, name, case);
+                }
+            }
+            Component::RootDir => {
+                // `..` can't go above the root.
+                pending_parents = 0;
+                state.write_u8(ROOT);
+            }
+            Component::Prefix(prefix) => {
+                write_parents(state, &mut pending_parents);
+                write_component(state, prefix.as_os_str(), case);
+            }
+        }
+    }
+    write_parents(state, &mut pending_parents);
+}
+
+fn write_parents<H: Hasher>(state: &mut H, pending_parents: &mut usize) {
+    for _ in 0..*pending_parents {
+        write_component(state, OsStr::new(".."), CaseSensitivity::Sensitive);
+    }
+    *pending_parents = 0;
+}
+
+fn write_component<H: Hasher>(state: &mut H, name: &OsStr, case: CaseSensitivity) {
+    let bytes = name.as_encoded_bytes();
+    match (case, name.to_str()) {
+        (CaseSensitivity::Sensitive, _) => state.write(bytes),
+        (CaseSensitivity::UnicodeInsensitive, Some(name)) => {
+            let mut buf = [0u8; 4];
+            for c in name.chars() {
+                state.write(case.fold_char(c).encode_utf8(&mut buf).as_bytes());
+            }
+        }
+        // Names that aren't valid Unicode are compared ignoring ASCII case, see `CaseSensitivity::eq_os_str`.
+        (CaseSensitivity::AsciiInsensitive | CaseSensitivity::UnicodeInsensitive, _) => {
+            let mut buf = [0u8; 64];
+            for chunk in bytes.chunks(buf.len()) {
+                let buf = &mut buf[..chunk.len()];
+                buf.copy_from_slice(chunk);
+                buf.make_ascii_lowercase();
+                state.write(buf);
+            }
+        }
+    }
+    state.write_u8(END_OF_COMPONENT);
+}
@@ -0,0 +1,110 @@
+use std::{
+    env,
+    error::Error,
+    ffi::{OsStr, OsString},
+    fmt,
+    path::{Component, Path, PathBuf},
+};
+
+/// Why a leading `~` couldn't be expanded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpandUserError {
+    /// The home directory of the current user is unknown.
+    NoHome,
+    /// The user named by `~user` couldn't be found.
+    UnknownUser(OsString),
+}
+
+impl fmt::Display for ExpandUserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpandUserError::NoHome => f.write_str("can't determine the home directory"),
+            ExpandUserError::UnknownUser(user) => write!(f, "unknown user {:?}", user),
+        }
+    }
+}
+
+impl Error for ExpandUserError {}
+
+pub(crate) fn expand_user_with(
+    path: &Path,
+    lookup: impl FnOnce(Option<&OsStr>) -> Option<PathBuf>,
+) -> Result<PathBuf, ExpandUserError> {
+    let mut components = path.components();
+    let user = match components.next() {
+        Some(Component::Normal(first)) => match first.as_encoded_bytes().strip_prefix(b"~") {
+            // SAFETY: `~` is ASCII, so the rest of `first` is still a valid `OsStr`.
+            Some(user) => unsafe { OsStr::from_encoded_bytes_unchecked(user) },
+            None => return Ok(path.to_path_buf()),
+        },
+        _ => return Ok(path.to_path_buf()),
+    };
+    let user = (!user.is_empty()).then_some(user);
+    let mut home = lookup(user).ok_or_else(|| match user {
+        Some(user) => ExpandUserError::UnknownUser(user.to_os_string()),
+        None => ExpandUserError::NoHome,
+    })?;
+    let rest = components.as_path();
+    if !rest.as_os_str().is_empty() {
+        home.push(rest);
+    }
+    Ok(home)
+}
+
+/// Looks up the home directory of `user`, or of the current user.
+pub(crate) fn home_dir(user: Option<&OsStr>) -> Option<PathBuf> {
+    let current = current_home();
+    match user {
+        None => current,
+        Some(user) => user_home(user, current),
+    }
+}
+
+fn current_home() -> Option<PathBuf> {
+    let non_empty = |name: &str| env::var_os(name).filter(|value| !value.is_empty());
+    if cfg!(target_family = "windows") {
+        non_empty("USERPROFILE").map(PathBuf::from).or_else(|| {
+            let mut home = PathBuf::from(non_empty("HOMEDRIVE")?);
+            home.push(non_empty("HOMEPATH")?);
+            Some(home)
+        })
+    } else {
+        non_empty("HOME").map(PathBuf::from)
+    }
+}
+
+#[cfg(target_family = "windows")]
+fn user_home(user: &OsStr, current: Option<PathBuf>) -> Option<PathBuf> {
+    // Profiles live side by side, so another user's is a sibling of the current one, the way Python guesses it.
+    let current = current?;
+    if env::var_os("USERNAME").as_deref() == Some(user) {
+        return Some(current);
+    }
+    Some(current.parent()?.join(user))
+}
+
+#[cfg(all(target_family = "unix", feature = "fs"))]
+fn user_home(user: &OsStr, _current: Option<PathBuf>) -> Option<PathBuf> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let passwd = std::fs::read("/etc/passwd").ok()?;
+    passwd_home(&passwd, user.as_bytes())
+}
+
+#[cfg(not(any(target_family = "windows", all(target_family = "unix", feature = "fs"))))]
+fn user_home(_user: &OsStr, _current: Option<PathBuf>) -> Option<PathBuf> {
+    None
+}
+
+/// Finds the home directory of `user` in the contents of `/etc/passwd`.
+#[cfg(all(target_family = "unix", feature = "fs"))]
+fn passwd_home(passwd: &[u8], user: &[u8]) -> Option<PathBuf> {
+    use std::os::unix::ffi::OsStrExt;
+
+    // name:password:uid:gid:gecos:home:shell
+    passwd
+        .split(|b| *b == b'\n')
+        .map(|line| line.split(|b| *b == b':').collect::<Vec<_>>())
+        .find(|fields| fields.len() >= 7 && fields[0] == user)
+        .map(|fields| PathBuf::from(OsStr::from_bytes(fields[5])))
+}
@@ -0,0 +1,59 @@
+use std::{borrow::Cow, ffi::OsStr, ffi::OsString, ops::Range, path::Path};
+
+/// Characters that render as nothing but change the bytes of a name: the byte order mark, zero-width characters and bidirectional controls.
+pub(crate) fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{FEFF}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{2060}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{061C}'
+    )
+}
+
+pub(crate) fn has_invisible(path: &Path) -> bool {
+    path.as_os_str()
+        .as_encoded_bytes()
+        .utf8_chunks()
+        .any(|chunk| chunk.valid().chars().any(is_invisible))
+}
+
+/// The byte ranges of the invisible characters in `s`.
+fn invisible_ranges(s: &OsStr) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut offset = 0;
+    for chunk in s.as_encoded_bytes().utf8_chunks() {
+        for (index, c) in chunk.valid().char_indices() {
+            if is_invisible(c) {
+                let start = offset + index;
+                ranges.push(start..start + c.len_utf8());
+            }
+        }
+        offset += chunk.valid().len() + chunk.invalid().len();
+    }
+    ranges
+}
+
+pub(crate) fn strip_invisible(path: &Path) -> Cow<'_, Path> {
+    let s = path.as_os_str();
+    let ranges = invisible_ranges(s);
+    if ranges.is_empty() {
+        return Cow::Borrowed(path);
+    }
+    let bytes = s.as_encoded_bytes();
+    let mut ret = OsString::with_capacity(bytes.len());
+    let mut start = 0;
+    let ends = ranges
+        .iter()
+        .map(|range| (range.start, range.end))
+        .chain(std::iter::once((bytes.len(), bytes.len())));
+    for (end, next_start) in ends {
+        // SAFETY: the pieces are split right before and after whole UTF-8 characters, which is allowed by `from_encoded_bytes_unchecked`.
+        // `OsString::push` takes care of joining the pieces on Windows.
+        ret.push(unsafe { OsStr::from_encoded_bytes_unchecked(&bytes[start..end]) });
+        start = next_start;
+    }
+    Cow::Owned(ret.into())
+}
@@ -0,0 +1,44 @@
+use std::path::{Path, PathBuf};
+
+use crate::{CaseSensitivity, SugarPath};
+
+/// Sugar methods for iterators of paths, so listings can be processed in a single chain.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::SugarPathIter;
+///
+/// let paths = ["src/./lib.rs", "src/../Cargo.toml", "src/bin/../main.rs"];
+/// let sources = paths.iter().normalize_all().filter_descendants_of("src").collect::<Vec<_>>();
+/// assert_eq!(sources, [Path::new("src/lib.rs"), Path::new("src/main.rs")]);
+/// ```
+pub trait SugarPathIter: Iterator + Sized
+where
+    Self::Item: AsRef<Path>,
+{
+    /// Normalizes every path, see [`SugarPath::normalize`].
+    fn normalize_all(self) -> impl Iterator<Item = PathBuf> {
+        self.map(|path| path.as_ref().normalize())
+    }
+
+    /// Resolves every path against the current working directory, see [`SugarPath::resolve`].
+    fn resolve_all(self) -> impl Iterator<Item = PathBuf> {
+        self.map(|path| path.as_ref().resolve())
+    }
+
+    /// Keeps the paths strictly beneath `root`, comparing normalized components like [`SugarPath::is_relative_to`]. `root` itself is dropped.
+    fn filter_descendants_of(self, root: impl AsRef<Path>) -> impl Iterator<Item = Self::Item> {
+        let root = root.as_ref().normalize();
+        self.filter(move |path| {
+            let path = path.as_ref();
+            path.is_relative_to(&root) && !path.path_eq(&root, CaseSensitivity::platform())
+        })
+    }
+}
+
+impl<I> SugarPathIter for I
+where
+    I: Iterator,
+    I::Item: AsRef<Path>,
+{
+}
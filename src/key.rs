@@ -0,0 +1,71 @@
+use std::path::{Component, Path};
+
+use crate::{flavor, normalize_to_component_vec, CaseSensitivity, Platform};
+
+pub(crate) fn canonical_key(path: &Path, platform: Platform, case: CaseSensitivity) -> String {
+    let parts = flavor::split(path.as_os_str(), platform);
+    // Only a Windows anchor has `\`, a Posix name keeps it since it isn't a separator there.
+    let mut key = parts.anchor.to_string_lossy().replace('\\', "/");
+    for (i, name) in parts.names.iter().enumerate() {
+        if i > 0 {
+            key.push('/');
+        }
+        key.push_str(&name.to_string_lossy());
+    }
+    if parts.names.is_empty() && !parts.has_root {
+        key.push('.');
+    }
+    match case {
+        CaseSensitivity::Sensitive => {}
+        CaseSensitivity::AsciiInsensitive => key.make_ascii_lowercase(),
+        CaseSensitivity::UnicodeInsensitive => {
+            key = key.chars().map(|c| case.fold_char(c)).collect();
+        }
+    }
+    key
+}
+
+pub(crate) fn sort_key(path: &Path, case: CaseSensitivity) -> Vec<u8> {
+    let components = normalize_to_component_vec(path);
+    let mut key = Vec::with_capacity(path.as_os_str().len());
+    let mut needs_separator = false;
+    for component in &components {
+        let bytes = component.as_os_str().as_encoded_bytes();
+        match component {
+            Component::Prefix(_) => {
+                key.extend(bytes.iter().map(|&b| if b == b'\\' { b'/' } else { b }));
+            }
+            Component::RootDir => key.push(b'/'),
+            Component::CurDir => unreachable!(),
+            Component::ParentDir | Component::Normal(_) => {
+                if needs_separator {
+                    key.push(b'/');
+                }
+                key.extend_from_slice(bytes);
+                needs_separator = true;
+            }
+        }
+    }
+    if matches!(components.as_slice(), [] | [Component::Prefix(_)]) {
+        key.push(b'.');
+    }
+    match case {
+        CaseSensitivity::Sensitive => key,
+        CaseSensitivity::AsciiInsensitive => {
+            key.make_ascii_lowercase();
+            key
+        }
+        CaseSensitivity::UnicodeInsensitive => {
+            // Only the valid parts are folded, bytes that aren't UTF-8 are kept as they are.
+            let mut folded = Vec::with_capacity(key.len());
+            for chunk in key.utf8_chunks() {
+                let mut buf = [0; 4];
+                for c in chunk.valid().chars() {
+                    folded.extend_from_slice(case.fold_char(c).encode_utf8(&mut buf).as_bytes());
+                }
+                folded.extend_from_slice(chunk.invalid());
+            }
+            folded
+        }
+    }
+}
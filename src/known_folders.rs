@@ -0,0 +1,197 @@
+use std::path::{Path, PathBuf};
+
+use crate::SugarPath;
+
+/// One of the Windows known folders that applications store their files in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KnownFolder {
+    /// `FOLDERID_RoamingAppData`, like `C:\Users\me\AppData\Roaming`. Follows the user across machines of a domain.
+    RoamingAppData,
+    /// `FOLDERID_LocalAppData`, like `C:\Users\me\AppData\Local`. Caches and machine-specific data go here.
+    LocalAppData,
+    /// `FOLDERID_Documents`, like `C:\Users\me\Documents`.
+    Documents,
+    /// `FOLDERID_ProgramData`, like `C:\ProgramData`. Shared by all users.
+    ProgramData,
+}
+
+/// Looks up the Windows known folders, as normalized absolute paths.
+///
+/// This is the Windows counterpart of `XdgDirs`. Folders are asked to the shell with `SHGetKnownFolderPath`, so they follow redirection and group policies. There are no known folders on other platforms, where every lookup returns `None`. The lookup can be replaced with [`with_lookup`](KnownFolders::with_lookup), e.g. in tests.
+///
+/// ```rust
+/// use std::path::{Path, PathBuf};
+/// use sugar_path::{KnownFolder, KnownFolders};
+///
+/// let folders = KnownFolders::with_lookup(|folder| match folder {
+///     KnownFolder::RoamingAppData => Some(PathBuf::from("/users/me/AppData/Roaming")),
+///     _ => None,
+/// });
+/// #[cfg(target_family = "unix")]
+/// assert_eq!(
+///     folders.join(KnownFolder::RoamingAppData, "tool/./config.toml").unwrap(),
+///     Path::new("/users/me/AppData/Roaming/tool/config.toml")
+/// );
+/// assert_eq!(folders.documents(), None);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct KnownFolders<F = fn(KnownFolder) -> Option<PathBuf>> {
+    lookup: F,
+}
+
+impl KnownFolders {
+    /// Asks the shell for the folders of the current user.
+    pub fn new() -> Self {
+        Self {
+            lookup: sys::known_folder,
+        }
+    }
+}
+
+impl Default for KnownFolders {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Fn(KnownFolder) -> Option<PathBuf>> KnownFolders<F> {
+    /// Looks the folders up with `lookup` rather than the shell.
+    pub fn with_lookup(lookup: F) -> Self {
+        Self { lookup }
+    }
+
+    /// The normalized absolute path of `folder`, or `None` if it can't be found or isn't absolute.
+    pub fn dir(&self, folder: KnownFolder) -> Option<PathBuf> {
+        let path = (self.lookup)(folder).filter(|path| path.is_absolute())?;
+        // Collecting the components drops a trailing separator, which `normalize` would keep.
+        Some(path.components().collect::<PathBuf>().normalize())
+    }
+
+    /// [`KnownFolder::RoamingAppData`].
+    pub fn roaming_app_data(&self) -> Option<PathBuf> {
+        self.dir(KnownFolder::RoamingAppData)
+    }
+
+    /// [`KnownFolder::LocalAppData`].
+    pub fn local_app_data(&self) -> Option<PathBuf> {
+        self.dir(KnownFolder::LocalAppData)
+    }
+
+    /// [`KnownFolder::Documents`].
+    pub fn documents(&self) -> Option<PathBuf> {
+        self.dir(KnownFolder::Documents)
+    }
+
+    /// [`KnownFolder::ProgramData`].
+    pub fn program_data(&self) -> Option<PathBuf> {
+        self.dir(KnownFolder::ProgramData)
+    }
+
+    /// Joins `path` onto `folder` and normalizes the result, so an application can locate its files in one call.
+    pub fn join(&self, folder: KnownFolder, path: impl AsRef<Path>) -> Option<PathBuf> {
+        Some(self.dir(folder)?.join(path).normalize())
+    }
+}
+
+#[cfg(target_family = "windows")]
+mod sys {
+    use std::{
+        ffi::{c_void, OsString},
+        os::windows::ffi::OsStringExt,
+        path::PathBuf,
+        ptr, slice,
+    };
+
+    use super::KnownFolder;
+
+    #[repr(C)]
+    struct Guid {
+        data1: u32,
+        data2: u16,
+        data3: u16,
+        data4: [u8; 8],
+    }
+
+    #[link(name = "shell32")]
+    extern "system" {
+        fn SHGetKnownFolderPath(
+            rfid: *const Guid,
+            flags: u32,
+            token: *mut c_void,
+            path: *mut *mut u16,
+        ) -> i32;
+    }
+
+    #[link(name = "ole32")]
+    extern "system" {
+        fn CoTaskMemFree(pv: *mut c_void);
+    }
+
+    fn folder_id(folder: KnownFolder) -> Guid {
+        let (data1, data2, data3, data4) = match folder {
+            // {3EB685DB-65F9-4CF6-A03A-E3EF65729F3D}
+            KnownFolder::RoamingAppData => (
+                0x3EB685DB,
+                0x65F9,
+                0x4CF6,
+                [0xA0, 0x3A, 0xE3, 0xEF, 0x65, 0x72, 0x9F, 0x3D],
+            ),
+            // {F1B32785-6FBA-4FCF-9D55-7B8E7F157091}
+            KnownFolder::LocalAppData => (
+                0xF1B32785,
+                0x6FBA,
+                0x4FCF,
+                [0x9D, 0x55, 0x7B, 0x8E, 0x7F, 0x15, 0x70, 0x91],
+            ),
+            // {FDD39AD0-238F-46AF-ADB4-6C85480369C7}
+            KnownFolder::Documents => (
+                0xFDD39AD0,
+                0x238F,
+                0x46AF,
+                [0xAD, 0xB4, 0x6C, 0x85, 0x48, 0x03, 0x69, 0xC7],
+            ),
+            // {62AB5D82-FDC1-4DC3-A9DD-070D1D495D97}
+            KnownFolder::ProgramData => (
+                0x62AB5D82,
+                0xFDC1,
+                0x4DC3,
+                [0xA9, 0xDD, 0x07, 0x0D, 0x1D, 0x49, 0x5D, 0x97],
+            ),
+        };
+        Guid {
+            data1,
+            data2,
+            data3,
+            data4,
+        }
+    }
+
+    pub(super) fn known_folder(folder: KnownFolder) -> Option<PathBuf> {
+        let id = folder_id(folder);
+        let mut path = ptr::null_mut();
+        // SAFETY: `id` and `path` are valid for the call, and a null token asks for the current user.
+        let result = unsafe { SHGetKnownFolderPath(&id, 0, ptr::null_mut(), &mut path) };
+        let folder = (result >= 0 && !path.is_null()).then(|| {
+            // SAFETY: on success `path` points at a NUL-terminated wide string.
+            let wide = unsafe {
+                let len = (0..).take_while(|&i| *path.add(i) != 0).count();
+                slice::from_raw_parts(path, len)
+            };
+            PathBuf::from(OsString::from_wide(wide))
+        });
+        // SAFETY: the buffer is allocated by the shell, even when the call fails, and must be freed by the caller.
+        unsafe { CoTaskMemFree(path.cast()) };
+        folder
+    }
+}
+
+#[cfg(not(target_family = "windows"))]
+mod sys {
+    use std::path::PathBuf;
+
+    use super::KnownFolder;
+
+    pub(super) fn known_folder(_folder: KnownFolder) -> Option<PathBuf> {
+        None
+    }
+}
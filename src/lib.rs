@@ -1,162 +1,1456 @@
 //! Sugar functions for manipulating paths.
-//! 
+//!
 //! [![document](https://docs.rs/sugar_path/badge.svg)](https://docs.rs/crate/sugar_path)
-//! [![crate version](https://img.shields.io/crates/v/sugar_path.svg)](https://crates.io/crates/sugar_path) 
+//! [![crate version](https://img.shields.io/crates/v/sugar_path.svg)](https://crates.io/crates/sugar_path)
 //! [![MIT](https://img.shields.io/badge/License-MIT-yellow.svg)](https://opensource.org/licenses/MIT)
-//! 
-//! 
+//!
+//!
 //! - [Examples](https://github.com/iheyunfei/sugar_path/tree/main/tests)
 //! - [Usages](https://docs.rs/sugar_path/latest/sugar_path/trait.SugarPath.html)
 
 use std::{
-    path::{Component, Path, PathBuf},
+    borrow::Cow,
+    ffi::{OsStr, OsString},
+    path::{Component, Path, PathBuf, Prefix, PrefixComponent},
 };
 
 use once_cell::sync::Lazy;
 
+mod anchored;
+mod archive;
+mod breadcrumbs;
+mod casing;
+#[cfg(feature = "clap")]
+mod cli;
+mod component;
+mod display;
+mod edit;
+mod encoding;
+mod expand;
+mod extension;
+mod file_url;
+mod flavor;
+#[cfg(feature = "fs")]
+mod fs;
+mod fuzzy;
+mod glob;
+mod guess;
+mod hash;
+mod home;
+mod invisible;
+mod iter;
+mod key;
+#[cfg(feature = "known-folders")]
+mod known_folders;
+mod limits;
+mod log_path;
+pub mod low_level;
+mod mount;
+#[cfg(feature = "node-compat")]
+mod node;
+mod normalizer;
+mod options;
+mod os_str;
+#[cfg(feature = "rayon")]
+mod par;
+mod path_buf;
+#[cfg(feature = "fs")]
+mod project;
+mod query;
+mod redact;
+mod relative;
+mod relative_cache;
+#[cfg(feature = "relative-path")]
+mod relative_path;
+mod scan;
+mod scratch;
+#[cfg(feature = "serde")]
+mod serde_adapters;
+mod set;
+mod shard;
+#[cfg(feature = "url")]
+mod sugar_url;
+mod table;
+mod template;
+#[cfg(feature = "chrono")]
+mod time;
+mod tree;
+mod tree_order;
+mod typed;
+mod utf8;
+#[cfg(feature = "vfs")]
+mod vfs;
+#[cfg(feature = "fs")]
+mod walk;
+pub mod windows;
+#[cfg(feature = "xdg")]
+mod xdg;
+
+pub use anchored::{Anchor, AnchorDirs, AnchoredPathBuf, AnchoredPathError, DefaultAnchors};
+pub use archive::{sanitize_archive_entry, ArchiveEntryError};
+pub use breadcrumbs::Breadcrumbs;
+pub use casing::Casing;
+#[cfg(feature = "clap")]
+pub use cli::{AbsolutePathParser, NormalizedPathParser, RelativePathParser};
+pub use component::{ComponentError, StripComponentsError};
+pub use display::NormalizedDisplay;
+pub use edit::PathEdit;
+pub use encoding::{decode_components, DecodeComponentsError};
+#[cfg(feature = "fs")]
+pub use expand::expand_glob;
+pub use expand::{expand, ExpandError};
+pub use extension::{Extension, ExtensionError};
+pub use file_url::{path_from_file_url, FileUrlError, HostPolicy};
+pub use flavor::{default_platform, set_default_platform, with_default_platform};
+#[cfg(feature = "fs")]
+pub use fs::{probe_case_sensitivity, FileKind, FileSystem, Metadata, StdFileSystem};
+pub use fuzzy::{fuzzy_match, fuzzy_rank, FuzzyMatch};
+pub use glob::{Pattern, PatternError};
+pub use guess::{guess_platform, Confidence, PlatformGuess};
+pub use home::ExpandUserError;
+pub use iter::SugarPathIter;
+#[cfg(feature = "known-folders")]
+pub use known_folders::{KnownFolder, KnownFolders};
+pub use limits::{LimitViolation, NAME_MAX, POSIX_PATH_MAX, WINDOWS_MAX_PATH};
+pub use log_path::LogPath;
+pub use mount::MountTable;
+#[cfg(feature = "node-compat")]
+pub use node::NodePath;
+pub use normalizer::Normalizer;
+pub use options::{DriveLetterCase, NormalizeOptions};
+#[cfg(feature = "rayon")]
+pub use par::{par_filter_matches, par_normalize_many, par_resolve_many};
+pub use path_buf::SugarPathBuf;
+#[cfg(feature = "fs")]
+pub use project::{find_project_root, ProjectRoots, DEFAULT_PROJECT_MARKERS};
+pub use query::{Query, QueryError};
+pub use redact::Redactor;
+pub use relative::{ComponentsBetween, RelativeReport, TryRelativeError};
+pub use relative_cache::RelativeCache;
+#[cfg(feature = "relative-path")]
+pub use relative_path::SugarRelativePath;
+pub use scratch::{with_scratch, Scratch};
+#[cfg(feature = "serde")]
+pub use serde_adapters::{
+    BaseDir, CurrentDir, NormalizeOnDeserialize, RequireRelative, ResolveAgainst,
+};
+pub use set::{diff_path_sets, relativize_set, MovedSubtree, PathSetDiff};
+pub use shard::{DigestError, ShardedLayout};
+#[cfg(feature = "url")]
+pub use sugar_url::{url_from_path, SugarUrl};
+pub use table::{PathId, PathTable};
+pub use template::{interpolate, InterpolateError};
+#[cfg(feature = "chrono")]
+pub use time::{expand_time_template, parse_time_template, TimeTemplateError};
+pub use tree::VirtualTree;
+pub use tree_order::{natural_cmp, DirPlacement, TreeOrder};
+pub use typed::{
+    AbsolutePathBuf, CollectNormalized, NormalizedPathBuf, RelativePathBuf, TypedPathError,
+};
+pub use utf8::NonUtf8PathError;
+#[cfg(feature = "fs")]
+pub use walk::WalkOptions;
+#[cfg(feature = "xdg")]
+pub use xdg::{XdgDir, XdgDirs};
+
 pub(crate) static CWD: Lazy<PathBuf> = Lazy::new(|| {
     // TODO: better way to get the current working directory?
-    let cwd = std::env::current_dir().unwrap();
-    cwd
+    std::env::current_dir().unwrap()
 });
 
-pub trait SugarPath {
+/// A path flavor, for operations that follow the rules of a given platform rather than the host's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// `/` is the only separator.
+    Posix,
+    /// Both `\` and `/` are separators.
+    Windows,
+}
+
+impl Platform {
+    /// The platform the crate is compiled for.
+    pub fn current() -> Self {
+        if cfg!(target_family = "windows") {
+            Platform::Windows
+        } else {
+            Platform::Posix
+        }
+    }
+
+    pub(crate) fn is_separator(self, b: u8) -> bool {
+        match self {
+            Platform::Posix => b == b'/',
+            Platform::Windows => b == b'/' || b == b'\\',
+        }
+    }
+}
+
+/// How path components are compared with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseSensitivity {
+    /// Components must match exactly.
+    Sensitive,
+    /// Components match if they are equal ignoring ASCII case.
+    AsciiInsensitive,
+    /// Components match if they are equal after mapping every character to its simple (one-to-one) uppercase form, the way NTFS compares names.
+    ///
+    /// Unlike [`CaseSensitivity::AsciiInsensitive`], this also matches `Ä` with `ä` and the Turkish `ı` with `i`. Characters whose uppercase form is more than one character, such as `ß`, only match themselves. Components that aren't valid Unicode are compared ignoring ASCII case.
+    UnicodeInsensitive,
+}
+
+impl CaseSensitivity {
+    /// The convention of the host: case-insensitive on Windows and case-sensitive elsewhere.
+    pub fn platform() -> Self {
+        if cfg!(target_family = "windows") {
+            CaseSensitivity::AsciiInsensitive
+        } else {
+            CaseSensitivity::Sensitive
+        }
+    }
+
+    /// Maps `c` to the form it is compared in.
+    pub(crate) fn fold_char(self, c: char) -> char {
+        match self {
+            CaseSensitivity::Sensitive => c,
+            CaseSensitivity::AsciiInsensitive => c.to_ascii_uppercase(),
+            CaseSensitivity::UnicodeInsensitive => {
+                let mut upper = c.to_uppercase();
+                match (upper.next(), upper.next()) {
+                    (Some(upper), None) => upper,
+                    _ => c,
+                }
+            }
+        }
+    }
+
+    pub(crate) fn eq_char(self, a: char, b: char) -> bool {
+        a == b || self.fold_char(a) == self.fold_char(b)
+    }
+
+    /// Compares in place, without folding copies of `a` and `b`, as it runs for every component `relative` compares.
+    pub(crate) fn eq_os_str(self, a: &OsStr, b: &OsStr) -> bool {
+        match self {
+            CaseSensitivity::Sensitive => a == b,
+            CaseSensitivity::AsciiInsensitive => a.eq_ignore_ascii_case(b),
+            CaseSensitivity::UnicodeInsensitive => match (a.to_str(), b.to_str()) {
+                (Some(a), Some(b)) => a
+                    .chars()
+                    .map(|c| self.fold_char(c))
+                    .eq(b.chars().map(|c| self.fold_char(c))),
+                _ => a.eq_ignore_ascii_case(b),
+            },
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for std::path::Path {}
+}
+
+/// Sugar methods for [`Path`], which `PathBuf` gets through `Deref`.
+///
+/// The trait is sealed: only [`Path`] implements it, so methods can be added to it without breaking anyone.
+pub trait SugarPath: sealed::Sealed {
     /// normalizes the given path, resolving `'..'` and `'.'` segments.
     ///
-    /// When multiple, sequential path segment separation characters are found (e.g. `/` on POSIX and either `\` or `/` on Windows), they are replaced by a single instance of the platform-specific path segment separator (`/` on POSIX and `\` on Windows). Trailing separators are preserved.
+    /// When multiple, sequential path segment separation characters are found (e.g. `/` on POSIX and either `\` or `/` on Windows), they are replaced by a single instance of the platform-specific path segment separator (`/` on POSIX and `\` on Windows). Trailing separators are preserved.
+    ///
+    /// If the path is a zero-length string, `'.'` is returned, representing the current working directory.
+    ///
+    /// See [`is_dir_like`](SugarPath::is_dir_like) to query the trailing separator afterwards.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// // For example, on POSIX:
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(
+    ///   Path::new("/foo/bar//baz/asdf/quux/..").normalize(),
+    ///   Path::new("/foo/bar/baz/asdf")
+    /// );
+    ///
+    /// // On Windows:
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(
+    ///   Path::new("C:\\temp\\\\foo\\bar\\..\\").normalize(),
+    ///   Path::new("C:\\temp\\foo\\")
+    /// );
+    ///
+    /// // Since Windows recognizes multiple path separators, both separators will be replaced by instances of the Windows preferred separator (`\`):
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(
+    ///   Path::new("C:////temp\\\\/\\/\\/foo/bar").normalize(),
+    ///   Path::new("C:\\temp\\foo\\bar")
+    /// );
+    /// ```
+    fn normalize(&self) -> PathBuf;
+
+    /// If the path is absolute, normalize and return it.
+    ///
+    /// If the path is not absolute, Using CWD concat the path, normalize and return it.
+    fn resolve(&self) -> PathBuf;
+
+    /// Like [`resolve`](SugarPath::resolve), but against `base` instead of the current working directory, like resolving an import against the directory of the file that imports it.
+    ///
+    /// An absolute path is only normalized. A relative `base` is itself resolved against the current working directory first, so the result is always absolute.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "unix")]
+    /// {
+    ///     assert_eq!(Path::new("../utils/./fmt.ts").resolve_with("/app/src/pages"), Path::new("/app/src/utils/fmt.ts"));
+    ///     assert_eq!(Path::new("/lib/../vendor").resolve_with("/app"), Path::new("/vendor"));
+    /// }
+    /// assert_eq!(Path::new("a").resolve_with("src"), Path::new("src/a").resolve());
+    /// ```
+    fn resolve_with(&self, base: impl AsRef<Path>) -> PathBuf;
+
+    /// Resolves the path against the directory of the package's `Cargo.toml` instead of the current working directory, see [`Anchor::ManifestDir`].
+    ///
+    /// Cargo runs tests, build scripts and procedural macros from directories that vary with how it was invoked, so fixtures and data files found this way don't move with them. Fails with [`AnchoredPathError::UnknownAnchor`] when not run by Cargo.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// let fixture = Path::new("./tests/fixtures/../fixtures").resolve_from_manifest_dir().unwrap();
+    /// assert_eq!(fixture, Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").normalize());
+    /// ```
+    fn resolve_from_manifest_dir(&self) -> Result<PathBuf, AnchoredPathError>;
+
+    /// The path as a key of a map keyed by [`NormalizedPathBuf`], only normalizing it when that would change its components.
+    ///
+    /// Paths compare and hash by their components, which already ignore repeated separators, a trailing separator and a `.` in the middle, so a path without `..` to fold, a leading `.` or an empty component list is borrowed as it is.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use std::collections::HashMap;
+    /// use std::path::Path;
+    /// use sugar_path::{NormalizedPathBuf, SugarPath};
+    ///
+    /// let mut sizes = HashMap::new();
+    /// sizes.insert(NormalizedPathBuf::new("src/lib.rs"), 10);
+    ///
+    /// let key = Path::new("src//lib.rs").normalize_key();
+    /// assert!(matches!(key, Cow::Borrowed(_)));
+    /// assert_eq!(sizes.get(&*key), Some(&10));
+    ///
+    /// let key = Path::new("./src/bin/../lib.rs").normalize_key();
+    /// assert!(matches!(key, Cow::Owned(_)));
+    /// *sizes.entry(NormalizedPathBuf::new(key)).or_default() += 1;
+    /// assert_eq!(sizes[Path::new("src/lib.rs")], 11);
+    /// ```
+    fn normalize_key(&self) -> Cow<'_, Path>;
+
+    /// Like [`normalize`](SugarPath::normalize), but follows the rules of `platform` whatever the host, with its separators and, on Windows, its prefixes.
+    ///
+    /// Verbatim Windows paths (`\\?\`) are returned as they are, since Windows doesn't normalize them either. See [`set_default_platform`] to make `normalize` itself follow another platform.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{Platform, SugarPath};
+    ///
+    /// assert_eq!(Path::new("C:/a/./b/../c/").normalize_as(Platform::Windows).as_os_str(), "C:\\a\\c\\");
+    /// assert_eq!(Path::new("//server/share/../x").normalize_as(Platform::Windows).as_os_str(), "\\\\server\\share\\x");
+    /// assert_eq!(Path::new("a//b/../../..").normalize_as(Platform::Posix).as_os_str(), "..");
+    /// ```
+    fn normalize_as(&self, platform: Platform) -> PathBuf;
+
+    /// Like [`SugarPath::normalize`], but clears `buf` and writes the result into it, so one buffer can be recycled across a batch of paths.
+    ///
+    /// The result always matches [`SugarPath::normalize`], including under [`set_default_platform`]. The allocation of `buf` is reused in every case but a Windows UNC or verbatim path written with `/`.
+    ///
+    /// ```rust
+    /// use std::path::{Path, PathBuf};
+    /// use sugar_path::SugarPath;
+    ///
+    /// let mut buf = PathBuf::new();
+    /// for (path, expected) in [("a/./b", "a/b"), ("a/../..", "..")] {
+    ///     Path::new(path).normalize_into(&mut buf);
+    ///     assert_eq!(buf, Path::new(expected));
+    /// }
+    /// ```
+    fn normalize_into(&self, buf: &mut PathBuf);
+
+    /// Returns a value that displays the normalized path with its components joined by `separator`, without building the normalized path.
+    ///
+    /// A Windows prefix is written with its backslashes replaced by `separator` too.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// let path = Path::new("a/./b/../c");
+    /// assert_eq!(path.normalized_display('/').to_string(), "a/c");
+    /// assert_eq!(path.normalized_display('\\').to_string(), "a\\c");
+    /// assert_eq!(format!("{}", Path::new("a/..").normalized_display('/')), ".");
+    /// ```
+    fn normalized_display(&self, separator: char) -> NormalizedDisplay<'_>;
+
+    /// Returns the path that leads from `to` to this path, after resolving both against the current working directory.
+    ///
+    /// Components are compared following the conventions of the host, see [`CaseSensitivity::platform`]. If there is no such path, because the paths are on different Windows drives or `to` still has a `..` after resolving, the resolved path is returned instead; use [`try_relative`](SugarPath::try_relative) to tell these cases apart.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    /// assert_eq!(
+    ///   Path::new("/var").relative("/var/lib"),
+    ///   Path::new("..")
+    /// );
+    /// assert_eq!(
+    ///   Path::new("/bin").relative("/var/lib"),
+    ///   Path::new("../../bin")
+    /// );
+    /// assert_eq!(
+    ///   Path::new("/a/b/c/d").relative("/a/b/f/g"),
+    ///   Path::new("../../c/d")
+    /// );
+    /// ```
+    fn relative(&self, to: impl AsRef<Path>) -> PathBuf {
+        self.relative_with_case(to, CaseSensitivity::platform())
+    }
+
+    /// Like [`SugarPath::relative`], comparing components following `case` instead of the conventions of the default platform.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{CaseSensitivity, SugarPath};
+    ///
+    /// assert_eq!(
+    ///   Path::new("/Ärger/b").relative_with_case("/ärger/c", CaseSensitivity::UnicodeInsensitive),
+    ///   Path::new("../b")
+    /// );
+    /// assert_eq!(
+    ///   Path::new("/Ärger/b").relative_with_case("/ärger/c", CaseSensitivity::AsciiInsensitive),
+    ///   Path::new("../../Ärger/b")
+    /// );
+    /// ```
+    fn relative_with_case(&self, to: impl AsRef<Path>, case: CaseSensitivity) -> PathBuf;
+
+    /// Like [`SugarPath::relative`], but clears `buf` and writes the result into it, so a loop can reuse one allocation.
+    ///
+    /// ```rust
+    /// use std::path::{Path, PathBuf};
+    /// use sugar_path::SugarPath;
+    ///
+    /// let mut buf = PathBuf::new();
+    /// for (path, to, expected) in [("/a/b", "/a", "b"), ("/a", "/a/b/c", "../..")] {
+    ///     Path::new(path).relative_into(to, &mut buf);
+    ///     assert_eq!(buf, Path::new(expected));
+    /// }
+    /// ```
+    fn relative_into(&self, to: impl AsRef<Path>, buf: &mut PathBuf);
+
+    /// Like [`SugarPath::relative`], but fails instead of returning an absolute path when no relative path leads from `to` to this path.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{SugarPath, TryRelativeError};
+    ///
+    /// assert_eq!(Path::new("/a/b").try_relative("/a/c").unwrap(), Path::new("../b"));
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(
+    ///   Path::new("C:\\a").try_relative("D:\\a"),
+    ///   Err(TryRelativeError::DifferentRoots)
+    /// );
+    /// ```
+    fn try_relative(&self, to: impl AsRef<Path>) -> Result<PathBuf, TryRelativeError>;
+
+    /// Like [`SugarPath::relative`], but also reports how the relative path was found, so tools can explain a surprising `../..` instead of just using it.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// let (path, report) = Path::new("/project/dist/app.js").relative_with_report("/project/out/js");
+    /// assert_eq!(path, Path::new("../../dist/app.js"));
+    /// assert_eq!(report.common_ancestor.unwrap(), Path::new("/project").resolve());
+    /// assert_eq!((report.ascended, report.descended), (2, 2));
+    /// if report.ascended > 0 {
+    ///     let message = format!("output escapes the out dir by {} levels", report.ascended);
+    ///     assert_eq!(message, "output escapes the out dir by 2 levels");
+    /// }
+    /// ```
+    fn relative_with_report(&self, to: impl AsRef<Path>) -> (PathBuf, RelativeReport);
+
+    /// The components of the relative path from `base` to this path, without building it, for callers that write them out or count them right away.
+    ///
+    /// Unlike [`SugarPath::try_relative`], the paths are compared as they are, without resolving or normalizing them, so pass two normalized paths that are both absolute or both relative. Components are parsed and compared following the conventions of the host, even under [`set_default_platform`], since they are borrowed from the paths as they are.
+    ///
+    /// ```rust
+    /// use std::path::{Component, Path};
+    /// use sugar_path::{SugarPath, TryRelativeError};
+    ///
+    /// let between = Path::new("src/utils/fmt.ts").components_between("src/pages/home").unwrap();
+    /// let specifier = between
+    ///     .map(|component| component.as_os_str().to_string_lossy())
+    ///     .collect::<Vec<_>>()
+    ///     .join("/");
+    /// assert_eq!(specifier, "../../utils/fmt.ts");
+    ///
+    /// let ups = Path::new("/a").components_between("/a/b/c").unwrap();
+    /// assert_eq!(ups.filter(|component| *component == Component::ParentDir).count(), 2);
+    /// assert_eq!(
+    ///     Path::new("/a").components_between("a").unwrap_err(),
+    ///     TryRelativeError::DifferentRoots
+    /// );
+    /// ```
+    fn components_between<'a, B>(
+        &'a self,
+        base: &'a B,
+    ) -> Result<ComponentsBetween<'a>, TryRelativeError>
+    where
+        B: AsRef<Path> + ?Sized;
+
+    /// Whether both paths normalize to the same path, comparing components following `case`.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{CaseSensitivity, SugarPath};
+    ///
+    /// assert!(Path::new("a/./b/../C").path_eq("a//c", CaseSensitivity::AsciiInsensitive));
+    /// assert!(!Path::new("a/C").path_eq("a/c", CaseSensitivity::Sensitive));
+    /// assert!(Path::new("straße/ı").path_eq("STRAßE/I", CaseSensitivity::UnicodeInsensitive));
+    /// ```
+    fn path_eq(&self, other: impl AsRef<Path>, case: CaseSensitivity) -> bool;
+
+    /// Expands Windows 8.3 short-name components (e.g. `PROGRA~1`) to their long forms.
+    ///
+    /// The path must exist, since the long names are looked up on disk, and a missing one fails with [`NotFound`](std::io::ErrorKind::NotFound) everywhere. On platforms other than Windows there are no short names and an existing path is returned as is.
+    ///
+    /// Requires the `fs` feature.
+    #[cfg(feature = "fs")]
+    fn expand_short_names(&self) -> std::io::Result<PathBuf>;
+
+    /// Returns the normalized paths below this directory that match `pattern`, relative to it, like `Path.glob` in Python.
+    ///
+    /// Symbolic links aren't followed. Use [`WalkOptions`] to limit the depth, follow links, or walk another [`FileSystem`].
+    ///
+    /// Requires the `fs` feature.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{Pattern, SugarPath};
+    ///
+    /// let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    /// let manifests = root.glob(&Pattern::new("Cargo.*").unwrap()).unwrap();
+    /// assert!(manifests.contains(&root.join("Cargo.toml").normalize()));
+    /// ```
+    #[cfg(feature = "fs")]
+    fn glob(&self, pattern: &Pattern) -> std::io::Result<Vec<PathBuf>>;
+
+    /// Like [`glob`](SugarPath::glob), with the pattern matching at any depth, like `Path.rglob` in Python.
+    ///
+    /// Requires the `fs` feature.
+    #[cfg(feature = "fs")]
+    fn rglob(&self, pattern: &Pattern) -> std::io::Result<Vec<PathBuf>>;
+
+    /// Reports every component longer than [`NAME_MAX`] and whether the whole path exceeds `PATH_MAX`/`MAX_PATH`, following the rules of `platform`.
+    ///
+    /// Lengths are counted in bytes for [`Platform::Posix`] and in UTF-16 code units for [`Platform::Windows`].
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{Platform, SugarPath};
+    ///
+    /// assert!(Path::new("foo/bar").check_limits(Platform::Posix).is_empty());
+    /// let long = "a".repeat(256);
+    /// assert_eq!(Path::new(&long).check_limits(Platform::Windows).len(), 1);
+    /// ```
+    fn check_limits(&self, platform: Platform) -> Vec<LimitViolation>;
+
+    /// Whether any component of the path is a reserved Windows device name, such as `CON` or `nul.txt`.
+    ///
+    /// See [`windows::is_reserved_name`] for the exact rules.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert!(Path::new("foo/aux.js/bar").has_reserved_component());
+    /// assert!(!Path::new("foo/auxiliary.js").has_reserved_component());
+    /// ```
+    fn has_reserved_component(&self) -> bool;
+
+    /// Whether the file name starts with a `.`.
+    ///
+    /// With the `fs` feature, files flagged as hidden by the filesystem (the hidden attribute on Windows, `UF_HIDDEN` on macOS) count as hidden as well.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert!(Path::new("foo/.gitignore").is_hidden());
+    /// assert!(!Path::new(".config/foo").is_hidden());
+    /// ```
+    fn is_hidden(&self) -> bool;
+
+    /// Whether any directory above the file name, after normalization, starts with a `.`.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert!(Path::new(".config/foo").is_inside_hidden_dir());
+    /// assert!(!Path::new(".config/../foo").is_inside_hidden_dir());
+    /// ```
+    fn is_inside_hidden_dir(&self) -> bool;
+
+    /// Whether the path contains characters that aren't rendered but make it differ from how it looks: a byte order mark, zero-width characters (U+200B to U+200D, U+2060) or bidirectional controls.
+    ///
+    /// Such characters break comparisons between paths that look the same, and bidirectional overrides can make a name display as something it isn't, like `exe.txt` for `txt.exe`.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert!(Path::new("\u{FEFF}src/lib.rs").has_invisible_chars());
+    /// assert!(Path::new("invoice\u{202E}fdp.exe").has_invisible_chars());
+    /// assert!(!Path::new("src/lib.rs").has_invisible_chars());
+    /// ```
+    fn has_invisible_chars(&self) -> bool;
+
+    /// Removes the characters found by [`has_invisible_chars`](SugarPath::has_invisible_chars), borrowing the path if there are none.
+    ///
+    /// Zero-width joiners and non-joiners are also removed, though they are legitimate in emoji sequences and some scripts, like Persian.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("src/\u{200B}lib.rs").strip_invisible(), Path::new("src/lib.rs"));
+    /// ```
+    fn strip_invisible(&self) -> Cow<'_, Path>;
+
+    /// Feeds the normalized form of the path into `state` without allocating.
+    ///
+    /// Paths that normalize to the same path under `case` produce the same hash. The hash is not the same as the one of the normalized `PathBuf`, so it should only be compared with other hashes produced by this method.
+    ///
+    /// ```rust
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    /// use std::path::Path;
+    /// use sugar_path::{CaseSensitivity, SugarPath};
+    ///
+    /// let hash = |path: &str| {
+    ///     let mut state = DefaultHasher::new();
+    ///     Path::new(path).hash_normalized(&mut state, CaseSensitivity::AsciiInsensitive);
+    ///     state.finish()
+    /// };
+    /// assert_eq!(hash("/foo/bar/../Baz"), hash("/foo/baz"));
+    /// ```
+    fn hash_normalized<H: std::hash::Hasher>(&self, state: &mut H, case: CaseSensitivity);
+
+    /// Returns a string key for the path, suitable for persistent caches and lockfiles, following the rules of the default platform.
+    ///
+    /// The default platform is the host's unless [`set_default_platform`] says otherwise, so the same path can get different keys on Linux and Windows. Use [`canonical_key_as`](SugarPath::canonical_key_as) with a fixed platform for keys shared between hosts.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/foo//bar/../Baz").canonical_key(), "/foo/Baz");
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("C:\\foo\\bar\\..\\Baz").canonical_key(), "c:/foo/baz");
+    /// ```
+    fn canonical_key(&self) -> String;
+
+    /// Returns a string key for the path, suitable for persistent caches and lockfiles, reading it the way `platform` does whatever the host.
+    ///
+    /// The key is built from the path normalized following `platform`:
+    ///
+    /// - components are joined with `/`, and a root is written as a leading `/`,
+    /// - Windows prefixes are kept with their `\` replaced by `/` (e.g. `c:` or `//server/share/`),
+    /// - for [`Platform::Windows`], whose paths are case-insensitive, the key is ASCII-lowercased,
+    /// - components that aren't valid Unicode are converted lossily,
+    /// - an empty path, or a prefix without anything after it, ends with `.`.
+    ///
+    /// The same path and platform give the same key on every host. This format is part of the public API: keys produced by one version of the crate stay equal to the keys produced by later versions, until the next breaking release.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{Platform, SugarPath};
+    ///
+    /// assert_eq!(Path::new("C:/foo/bar/../Baz").canonical_key_as(Platform::Windows), "c:/foo/baz");
+    /// assert_eq!(Path::new("/foo//bar/../Baz").canonical_key_as(Platform::Posix), "/foo/Baz");
+    /// ```
+    fn canonical_key_as(&self, platform: Platform) -> String;
+
+    /// Returns a byte key that sorts paths the same way on every platform, for manifests, archives and lockfiles that have to be reproducible.
+    ///
+    /// The key is laid out like [`canonical_key`](SugarPath::canonical_key), with components of the normalized path joined with `/`, but `case` is applied as given rather than following the platform and names are kept byte for byte instead of being converted lossily. Comparing keys compares the paths as strings with `/` separators, the order `git` lists files in.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{CaseSensitivity, SugarPath};
+    ///
+    /// let mut paths = ["src/lib.rs", "README.md", "src-old/x", "./src/a/../main.rs"];
+    /// paths.sort_by_key(|path| Path::new(path).sort_key(CaseSensitivity::AsciiInsensitive));
+    /// assert_eq!(paths, ["README.md", "src-old/x", "src/lib.rs", "./src/a/../main.rs"]);
+    /// assert_eq!(Path::new("a/./B").sort_key(CaseSensitivity::Sensitive), b"a/B");
+    /// ```
+    fn sort_key(&self, case: CaseSensitivity) -> Vec<u8>;
+
+    /// Rewrites the path for logs and telemetry with the home directory shown as `~` and the username replaced with `<user>`, using [`Redactor::new`]. See [`Redactor`] for the rules and to hash the other names too.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("src/./lib.rs").redact(), "src/lib.rs");
+    /// if let Some(home) = std::env::var_os("HOME") {
+    ///     assert_eq!(Path::new(&home).join("a").redact(), "~/a");
+    /// }
+    /// ```
+    fn redact(&self) -> String;
+
+    /// Returns the Windows prefix of the path: a drive like `C:`, or a UNC root like `\\server\share`.
+    ///
+    /// Always returns `None` on platforms other than Windows, where paths have no prefix.
+    ///
+    /// ```rust
+    /// use std::ffi::OsStr;
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("C:\\foo").drive(), Some(OsStr::new("C:")));
+    /// assert_eq!(Path::new("/foo").drive(), None);
+    /// ```
+    fn drive(&self) -> Option<&OsStr>;
+
+    /// Returns the path with its Windows prefix replaced by `drive`, or added if it has none.
+    ///
+    /// Does nothing on platforms other than Windows.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("C:\\foo").with_drive("D:"), Path::new("D:\\foo"));
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/foo").with_drive("D:"), Path::new("/foo"));
+    /// ```
+    fn with_drive(&self, drive: impl AsRef<OsStr>) -> PathBuf;
+
+    /// Returns the server of a UNC path, i.e. `server` in `\\server\share\foo` or `\\?\UNC\server\share\foo`.
+    ///
+    /// Always returns `None` on platforms other than Windows.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("\\\\server\\share\\foo").unc_server().unwrap(), "server");
+    /// assert_eq!(Path::new("/foo").unc_server(), None);
+    /// ```
+    fn unc_server(&self) -> Option<&OsStr>;
+
+    /// Returns the share of a UNC path, i.e. `share` in `\\server\share\foo` or `\\?\UNC\server\share\foo`.
+    ///
+    /// Always returns `None` on platforms other than Windows.
+    fn unc_share(&self) -> Option<&OsStr>;
+
+    /// Returns the part of a UNC path after `\\server\share\`, or `None` if the path isn't a UNC path.
+    ///
+    /// Always returns `None` on platforms other than Windows.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(
+    ///   Path::new("\\\\?\\UNC\\server\\share\\foo\\bar").strip_unc_root().unwrap(),
+    ///   Path::new("foo\\bar")
+    /// );
+    /// ```
+    fn strip_unc_root(&self) -> Option<&Path>;
+
+    /// Splits the normalized path into its root (including any Windows prefix) and the relative remainder.
+    ///
+    /// The root is empty for relative paths, and the remainder is empty when nothing follows the root.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(
+    ///   Path::new("/foo/../bar/baz").split_root(),
+    ///   (Path::new("/").to_path_buf(), Path::new("bar/baz").to_path_buf())
+    /// );
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(
+    ///   Path::new("C:\\foo\\..\\bar").split_root(),
+    ///   (Path::new("C:\\").to_path_buf(), Path::new("bar").to_path_buf())
+    /// );
+    /// ```
+    fn split_root(&self) -> (PathBuf, PathBuf);
+
+    /// Returns the root of an absolute path, together with any Windows prefix, like `/`, `C:\` or `\\server\share\`.
+    ///
+    /// Returns `None` for paths without a root, including drive-relative ones like `C:foo`. Nothing but the root is looked at, so the rest of the path isn't normalized.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("//foo/bar").root().unwrap(), Path::new("/"));
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("C:/foo").root().unwrap(), Path::new("C:\\"));
+    /// assert_eq!(Path::new("foo/bar").root(), None);
+    /// ```
+    fn root(&self) -> Option<PathBuf>;
+
+    /// Returns the Windows prefix and the root of the path joined together, like `anchor` in Python's `pathlib`.
+    ///
+    /// Unlike [`root`](SugarPath::root), this is never missing: it is `C:` for a drive-relative path like `C:foo`, and empty for a relative path. Joining the relative part of a path back onto its anchor rebuilds it.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/foo/bar").anchor(), Path::new("/"));
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("C:foo").anchor(), Path::new("C:"));
+    /// assert_eq!(Path::new("foo/bar").anchor(), Path::new(""));
+    /// ```
+    fn anchor(&self) -> PathBuf;
+
+    /// Returns the normalized path without its Windows prefix and root, so that an absolute path becomes relative, like the entries zip and tar writers expect.
+    ///
+    /// This is the second half of [`split_root`](SugarPath::split_root): it is empty for a bare root, and a relative path is only normalized, so a leading `..` is kept. See [`sanitize_archive_entry`] for the way back.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/srv/./app/lib.rs").strip_root(), Path::new("srv/app/lib.rs"));
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("\\\\server\\share\\app\\lib.rs").strip_root(), Path::new("app\\lib.rs"));
+    /// assert_eq!(Path::new("app/../lib.rs").strip_root(), Path::new("lib.rs"));
+    /// ```
+    fn strip_root(&self) -> PathBuf;
+
+    /// Like [`strip_root`](SugarPath::strip_root), but joins the names with `separator` into a string, keeping a trailing separator, as archive formats use for directories.
+    ///
+    /// Names that aren't valid Unicode are written lossily, like [`Path::display`].
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/srv/app/").strip_root_with_separator('/'), "srv/app/");
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("C:\\srv\\app\\lib.rs").strip_root_with_separator('/'), "srv/app/lib.rs");
+    /// assert_eq!(Path::new("a/./b").strip_root_with_separator('\\'), "a\\b");
+    /// ```
+    fn strip_root_with_separator(&self, separator: char) -> String;
+
+    /// Returns the normalized path truncated to its first `depth` components below the root.
+    ///
+    /// The root and any Windows prefix are kept. A relative path truncated to nothing becomes `.`.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("src/./foo/bar.rs").truncate_to_depth(1), Path::new("src"));
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/a/b/../c/d").truncate_to_depth(2), Path::new("/a/c"));
+    /// ```
+    fn truncate_to_depth(&self, depth: usize) -> PathBuf;
+
+    /// Returns the normalized path with the component at `index` below the root replaced by `segment`.
+    ///
+    /// `segment` must be a single normal component: not empty, not `.` or `..`, without separators or NUL.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(
+    ///   Path::new("a/b/c").replace_component(1, "x").unwrap(),
+    ///   Path::new("a/x/c")
+    /// );
+    /// assert!(Path::new("a/b/c").replace_component(3, "x").is_err());
+    /// assert!(Path::new("a/b/c").replace_component(0, "x/y").is_err());
+    /// ```
+    fn replace_component(
+        &self,
+        index: usize,
+        segment: impl AsRef<OsStr>,
+    ) -> Result<PathBuf, ComponentError>;
+
+    /// Returns the normalized path with `segment` inserted at `index` below the root, shifting the following components.
+    ///
+    /// `index` may be equal to the number of components, to append. `segment` is validated like in [`replace_component`](SugarPath::replace_component).
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(
+    ///   Path::new("a/b").insert_component(1, "x").unwrap(),
+    ///   Path::new("a/x/b")
+    /// );
+    /// assert_eq!(
+    ///   Path::new("a/b").insert_component(2, "x").unwrap(),
+    ///   Path::new("a/b/x")
+    /// );
+    /// ```
+    fn insert_component(
+        &self,
+        index: usize,
+        segment: impl AsRef<OsStr>,
+    ) -> Result<PathBuf, ComponentError>;
+
+    /// Drops the root and the first `count` components of the normalized path, like `tar --strip-components`.
+    ///
+    /// The remainder is relative, and empty if the path has exactly `count` components. Fails if it has fewer.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(
+    ///   Path::new("package/lib/index.js").strip_components(1).unwrap(),
+    ///   Path::new("lib/index.js")
+    /// );
+    /// assert!(Path::new("package").strip_components(2).is_err());
+    /// ```
+    fn strip_components(&self, count: usize) -> Result<PathBuf, StripComponentsError>;
+
+    /// Replaces the file name of the normalized path with `name`.
+    ///
+    /// Unlike [`PathBuf::set_file_name`], `name` has to be a single plain component: it is rejected if it is empty, `.` or `..`, or contains a separator or a NUL. Fails with [`ComponentError::NoFileName`] if the normalized path is a root, `.`, or ends with `..`.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{ComponentError, SugarPath};
+    ///
+    /// assert_eq!(Path::new("a/./b.txt").with_name("c.md").unwrap(), Path::new("a/c.md"));
+    /// assert!(Path::new("a/b.txt").with_name("../x").is_err());
+    /// assert_eq!(Path::new("..").with_name("x"), Err(ComponentError::NoFileName));
+    /// ```
+    fn with_name(&self, name: impl AsRef<OsStr>) -> Result<PathBuf, ComponentError>;
+
+    /// Whether the file name ends with the extension `extension`, which may span several dots like `tar.gz`, ignoring ASCII case.
+    ///
+    /// A leading `.` in `extension` is ignored. Like [`Path::extension`], the part of the name before the extension can't be empty, so `.gz` has no extension. An empty `extension` never matches.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert!(Path::new("dist/app.TAR.GZ").ends_with_extension("tar.gz"));
+    /// assert!(Path::new("dist/app.tar.gz").ends_with_extension(".gz"));
+    /// assert!(!Path::new("dist/app.tgz").ends_with_extension("gz"));
+    /// assert!(!Path::new("dist/.tar.gz").ends_with_extension("tar.gz"));
+    /// ```
+    fn ends_with_extension(&self, extension: &str) -> bool;
+
+    /// Whether the file name ends with any of `extensions`, see [`ends_with_extension`](SugarPath::ends_with_extension).
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert!(Path::new("src/lib.RS").has_extension_in(&["rs", "toml"]));
+    /// assert!(Path::new("a.d.ts").has_extension_in(&["d.ts"]));
+    /// assert!(!Path::new("README").has_extension_in(&["rs", "toml"]));
+    /// ```
+    fn has_extension_in(&self, extensions: &[&str]) -> bool;
+
+    /// The extension of the path as [`Path::extension`] returns it, as an [`Extension`] that compares ignoring ASCII case, or `None` if there is none or it isn't valid Unicode.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{Extension, SugarPath};
+    ///
+    /// assert_eq!(Path::new("src/Main.RS").extension_typed(), Some(Extension::RS));
+    /// assert_eq!(Path::new("archive.tar.gz").extension_typed().unwrap().as_str(), "gz");
+    /// assert_eq!(Path::new(".gitignore").extension_typed(), None);
+    /// ```
+    fn extension_typed(&self) -> Option<Extension>;
+
+    /// The path as a `str`, or an error telling where it stops being valid UTF-8, for applications that can't work with anything else, like serializers and UIs.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("src/main.rs").to_str_checked().unwrap(), "src/main.rs");
+    /// #[cfg(target_family = "unix")]
+    /// {
+    ///     use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+    ///
+    ///     let err = Path::new(OsStr::from_bytes(b"src/ma\xffin.rs")).to_str_checked().unwrap_err();
+    ///     assert_eq!(err.valid_up_to, 6);
+    /// }
+    /// ```
+    fn to_str_checked(&self) -> Result<&str, NonUtf8PathError>;
+
+    /// The file name as [`Path::file_name`] returns it, as a `str`, or an error telling where the path stops being valid UTF-8 within it. The error is only about the file name, the rest of the path may be anything.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("src/main.rs").file_name_str().unwrap(), Some("main.rs"));
+    /// assert_eq!(Path::new("/").file_name_str().unwrap(), None);
+    /// ```
+    fn file_name_str(&self) -> Result<Option<&str>, NonUtf8PathError>;
+
+    /// The extension as [`Path::extension`] returns it, as a `str`, or an error telling where the path stops being valid UTF-8 within it. The error is only about the extension.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("archive.tar.gz").extension_str().unwrap(), Some("gz"));
+    /// assert_eq!(Path::new("README").extension_str().unwrap(), None);
+    /// ```
+    fn extension_str(&self) -> Result<Option<&str>, NonUtf8PathError>;
+
+    /// Converts the file stem to `casing`, and the extension too if `include_extension` is set, leaving the directories untouched.
+    ///
+    /// An extension is lowercased for [`Casing::Kebab`] and [`Casing::Snake`]. Paths without a file name, or whose file name isn't valid Unicode, are returned unchanged.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{Casing, SugarPath};
+    ///
+    /// let path = Path::new("Assets/Hero Image.PNG");
+    /// assert_eq!(path.with_file_name_casing(Casing::Kebab, false), Path::new("Assets/hero-image.PNG"));
+    /// assert_eq!(path.with_file_name_casing(Casing::Kebab, true), Path::new("Assets/hero-image.png"));
+    /// assert_eq!(path.with_file_name_casing(Casing::Lower, true), Path::new("Assets/hero image.png"));
+    /// ```
+    fn with_file_name_casing(&self, casing: Casing, include_extension: bool) -> PathBuf;
+
+    /// Returns the `(name, path up to here)` pairs of the normalized path, from the outermost directory down to the file name.
+    ///
+    /// The root, together with any Windows prefix, is a single crumb named after itself, like `/` or `C:\`. A path that normalizes to `.` has no crumbs.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// let crumbs = Path::new("docs/./guide/intro.md").breadcrumbs().collect::<Vec<_>>();
+    /// assert_eq!(crumbs[1].0, "guide");
+    /// assert_eq!(crumbs[1].1, Path::new("docs/guide"));
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(
+    ///   Path::new("/home/me").breadcrumbs().map(|(name, _)| name).collect::<Vec<_>>(),
+    ///   ["/", "home", "me"]
+    /// );
+    /// ```
+    fn breadcrumbs(&self) -> Breadcrumbs;
+
+    /// Encodes the normalized components into a single string that contains no `\` and no `/` other than the ones between components.
+    ///
+    /// `%`, `/` and `\` inside components, as well as bytes that aren't valid Unicode, are escaped as `%XX`, so [`decode_components`] gives the normalized path back exactly. This suits cache keys and flat key-value stores. Components are joined with `/` on every platform, with a leading `/` for a root, and a Windows prefix is escaped as a whole.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("cache/./a%b/c").encode_components(), "cache/a%25b/c");
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/tmp/a\\b").encode_components(), "/tmp/a%5Cb");
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("C:\\tmp\\a").encode_components(), "C:/tmp/a");
+    /// ```
+    fn encode_components(&self) -> String;
+
+    /// Returns the components of the normalized path, like `PurePath.parts` in Python.
+    ///
+    /// The root, together with any Windows prefix, is the first part, like `/` or `C:\`. A path that normalizes to `.` has no parts.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("a/./b/../c").parts(), ["a", "c"]);
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/usr//bin/").parts(), ["/", "usr", "bin"]);
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(Path::new("c:/Users").parts(), ["C:\\", "Users"]);
+    /// ```
+    fn parts(&self) -> Vec<OsString>;
+
+    /// Returns the ancestor `n` levels above the normalized path, where `0` is the path itself and `1` its parent.
+    ///
+    /// Returns `None` if that walks above the root of an absolute path or the start (`.`) of a relative one; it never appends `..`.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// let path = Path::new("a/b/./c/d.txt");
+    /// assert_eq!(path.nth_ancestor(0).unwrap(), Path::new("a/b/c/d.txt"));
+    /// assert_eq!(path.nth_ancestor(3).unwrap(), Path::new("a"));
+    /// assert_eq!(path.nth_ancestor(4).unwrap(), Path::new("."));
+    /// assert_eq!(path.nth_ancestor(5), None);
+    /// ```
+    fn nth_ancestor(&self, n: usize) -> Option<PathBuf>;
+
+    /// Returns every proper ancestor of the normalized path, nearest first, like `PurePath.parents` in Python.
+    ///
+    /// `parents()[i]` is [`nth_ancestor(i + 1)`](SugarPath::nth_ancestor).
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("a/b/c").parents(), [Path::new("a/b"), Path::new("a"), Path::new(".")]);
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/usr/bin").parents(), [Path::new("/usr"), Path::new("/")]);
+    /// ```
+    fn parents(&self) -> Vec<PathBuf>;
+
+    /// Replaces a leading `~` or `~user` with the home directory of the current or the named user, like `PurePath.expanduser` in Python.
+    ///
+    /// The current user's home comes from `HOME`, or `USERPROFILE` (then `HOMEDRIVE` and `HOMEPATH`) on Windows. On Windows another user's home is assumed to sit next to the current one. Elsewhere `~user` is looked up in `/etc/passwd`, which requires the `fs` feature; without it, such paths fail with [`ExpandUserError::UnknownUser`]. Paths that don't start with `~` are returned as they are.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("a/~b").expand_user().unwrap(), Path::new("a/~b"));
+    /// if let Some(home) = std::env::var_os("HOME") {
+    ///   assert_eq!(Path::new("~/x").expand_user().unwrap(), Path::new(&home).join("x"));
+    /// }
+    /// ```
+    fn expand_user(&self) -> Result<PathBuf, ExpandUserError>;
+
+    /// Like [`expand_user`](SugarPath::expand_user), with home directories coming from `lookup`, which gets the user name or `None` for the current user.
+    ///
+    /// ```rust
+    /// use std::path::{Path, PathBuf};
+    /// use sugar_path::{ExpandUserError, SugarPath};
+    ///
+    /// let lookup = |user: Option<&std::ffi::OsStr>| match user?.to_str()? {
+    ///   "alice" => Some(PathBuf::from("/home/alice")),
+    ///   _ => None,
+    /// };
+    /// assert_eq!(
+    ///   Path::new("~alice/project").expand_user_with(lookup).unwrap(),
+    ///   Path::new("/home/alice/project")
+    /// );
+    /// assert_eq!(Path::new("~").expand_user_with(lookup), Err(ExpandUserError::NoHome));
+    /// ```
+    fn expand_user_with(
+        &self,
+        lookup: impl FnOnce(Option<&OsStr>) -> Option<PathBuf>,
+    ) -> Result<PathBuf, ExpandUserError>;
+
+    /// Whether the path, as written, can only name a directory: it ends with a separator, or with a `.` or `..` component, or is a bare root.
+    ///
+    /// Tools like rsync treat `src/` differently from `src`. [`normalize`](SugarPath::normalize), [`resolve`](SugarPath::resolve), [`Normalizer`] and the `push_normalized` methods of [`SugarPathBuf`] keep a trailing separator so this survives them; `relative`, `Path::join` and the component editing methods don't.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert!(Path::new("src/").is_dir_like());
+    /// assert!(Path::new("src/.").is_dir_like());
+    /// assert!(!Path::new("src").is_dir_like());
+    /// assert!(Path::new("a//b/../").normalize().is_dir_like());
+    /// ```
+    fn is_dir_like(&self) -> bool;
+
+    /// Whether the path, as written, starts with `./` or `../`, or with `.\` or `..\` on Windows.
+    ///
+    /// Module specifiers and command line arguments often give `./foo` a different meaning from `foo`, but normalization turns the former into the latter, so ask before normalizing. A bare `.` or `..` isn't followed by a separator and doesn't count.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert!(Path::new("./foo").is_explicitly_relative());
+    /// assert!(Path::new("../foo").is_explicitly_relative());
+    /// assert!(!Path::new("foo").is_explicitly_relative());
+    /// assert!(!Path::new(".foo/bar").is_explicitly_relative());
+    /// assert!(!Path::new("./foo").normalize().is_explicitly_relative());
+    /// ```
+    fn is_explicitly_relative(&self) -> bool;
+
+    /// Whether the normalized path is the same as or beneath the normalized `base`, comparing components following the conventions of the host (see [`CaseSensitivity::platform`]).
+    ///
+    /// This is purely lexical: a relative path is never relative to an absolute one, and a path that starts with `..` isn't beneath `.`.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert!(Path::new("src/lib.rs").is_relative_to("src"));
+    /// assert!(Path::new("src/../src/lib.rs").is_relative_to("./src/"));
+    /// assert!(Path::new("src").is_relative_to("src"));
+    /// assert!(!Path::new("src2/lib.rs").is_relative_to("src"));
+    /// assert!(!Path::new("../x").is_relative_to("."));
+    /// ```
+    fn is_relative_to(&self, base: impl AsRef<Path>) -> bool;
+
+    /// How many levels the path climbs above its starting directory, i.e. the number of leading `..` left once it is normalized.
+    ///
+    /// Paths that stay beneath their start have a depth of `0`, and so do absolute paths, since `..` stops at the root. This allows policies such as "at most one level up" without inspecting strings.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// assert_eq!(Path::new("a/b").escape_depth(), 0);
+    /// assert_eq!(Path::new("a/../..").escape_depth(), 1);
+    /// assert_eq!(Path::new("../../a/../b").escape_depth(), 2);
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(Path::new("/../..").escape_depth(), 0);
+    /// ```
+    fn escape_depth(&self) -> usize;
+
+    /// Joins the path onto `base`, confining it to `base` the way a chroot would.
+    ///
+    /// The path is treated as relative to `base`: its root and prefix are ignored, `.` segments are dropped and a `..` that would climb above `base` is discarded, like `cd ..` at `/`. The result is `base` normalized, joined with what is left, so it always stays beneath `base`.
+    ///
+    /// This suits file managers and FTP-like servers, where `..` at the top should stay put. Use [`sanitize_archive_entry`] to reject such paths instead.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::SugarPath;
+    ///
+    /// let base = Path::new("srv/ftp");
+    /// assert_eq!(Path::new("pub/../docs").clamp_to(base), base.join("docs"));
+    /// assert_eq!(Path::new("../../etc/passwd").clamp_to(base), base.join("etc/passwd"));
+    /// assert_eq!(Path::new("/etc/passwd").clamp_to(base), base.join("etc/passwd"));
+    /// assert_eq!(Path::new("..").clamp_to(base), base);
+    /// ```
+    fn clamp_to(&self, base: impl AsRef<Path>) -> PathBuf;
+
+    /// Joins `other` onto the path, stitching them where the end of the path overlaps the start of `other`.
     ///
-    /// If the path is a zero-length string, `'.'` is returned, representing the current working directory.
+    /// Tools that truncate paths differently, like a compiler reporting `crates/app/src/main.rs` and another reporting `src/main.rs` relative to `crates/app`, end up with such pairs. The longest run of names ending the normalized path that also starts the normalized `other` is only kept once; without an overlap, this is a normalized join. Names are compared following the conventions of the host, see [`CaseSensitivity::platform`], and an absolute `other` replaces the path, like [`Path::join`].
     ///
     /// ```rust
     /// use std::path::Path;
     /// use sugar_path::SugarPath;
     ///
-    /// // For example, on POSIX:
     /// #[cfg(target_family = "unix")]
-    /// assert_eq!(
-    ///   Path::new("/foo/bar//baz/asdf/quux/..").normalize(),
-    ///   Path::new("/foo/bar/baz/asdf")
-    /// );
-    ///
-    /// // On Windows:
-    /// #[cfg(target_family = "windows")]
-    /// assert_eq!(
-    ///   Path::new("C:\\temp\\\\foo\\bar\\..\\").normalize(),
-    ///   Path::new("C:\\temp\\foo\\")
-    /// );
-    ///
-    /// // Since Windows recognizes multiple path separators, both separators will be replaced by instances of the Windows preferred separator (`\`):
+    /// {
+    ///     assert_eq!(Path::new("a/b/c").concat_overlapping("b/c/d").as_os_str(), "a/b/c/d");
+    ///     assert_eq!(Path::new("crates/app/src").concat_overlapping("src/main.rs").as_os_str(), "crates/app/src/main.rs");
+    ///     assert_eq!(Path::new("a/b").concat_overlapping("c/d").as_os_str(), "a/b/c/d");
+    ///     assert_eq!(Path::new("a/b").concat_overlapping("./b").as_os_str(), "a/b");
+    /// }
     /// #[cfg(target_family = "windows")]
-    /// assert_eq!(
-    ///   Path::new("C:////temp\\\\/\\/\\/foo/bar").normalize(),
-    ///   Path::new("C:\\temp\\foo\\bar")
-    /// );
+    /// assert_eq!(Path::new("a\\b").concat_overlapping("./b").as_os_str(), "a\\b");
     /// ```
-    fn normalize(&self) -> PathBuf;
+    fn concat_overlapping(&self, other: impl AsRef<Path>) -> PathBuf;
 
-    /// If the path is absolute, normalize and return it.
+    /// Matches the normalized path against a glob pattern from the right, like `PurePath.match` in Python.
     ///
-    /// If the path is not absolute, Using CWD concat the path, normalize and return it.
-    fn resolve(&self) -> PathBuf;
+    /// A relative pattern matches the trailing components of the path, so `*.py` matches `a/b.py`. An absolute pattern, or one with a Windows prefix, has to match the whole path. See [`Pattern`] for the syntax; `**` matches any number of components. Fails if the pattern is empty or malformed.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{CaseSensitivity, SugarPath};
+    ///
+    /// let path = Path::new("src/utils/mod.rs");
+    /// assert!(path.match_pattern("*.rs", CaseSensitivity::Sensitive).unwrap());
+    /// assert!(path.match_pattern("utils/*.rs", CaseSensitivity::Sensitive).unwrap());
+    /// assert!(!path.match_pattern("src/*.rs", CaseSensitivity::Sensitive).unwrap());
+    /// assert!(path.match_pattern("MOD.RS", CaseSensitivity::AsciiInsensitive).unwrap());
+    /// ```
+    fn match_pattern(&self, pattern: &str, case: CaseSensitivity) -> Result<bool, PatternError>;
 
+    /// Matches the whole normalized path against a glob pattern, like `PurePath.full_match` in Python.
     ///
     /// ```rust
     /// use std::path::Path;
-    /// use sugar_path::SugarPath;
-    /// assert_eq!(
-    ///   Path::new("/var").relative("/var/lib"),
-    ///   Path::new("..")
-    /// );
-    /// assert_eq!(
-    ///   Path::new("/bin").relative("/var/lib"),
-    ///   Path::new("../../bin")
-    /// );
-    /// assert_eq!(
-    ///   Path::new("/a/b/c/d").relative("/a/b/f/g"),
-    ///   Path::new("../../c/d")
-    /// );
+    /// use sugar_path::{CaseSensitivity, SugarPath};
+    ///
+    /// let path = Path::new("src/utils/mod.rs");
+    /// assert!(path.full_match("src/**/*.rs", CaseSensitivity::Sensitive).unwrap());
+    /// assert!(!path.full_match("*.rs", CaseSensitivity::Sensitive).unwrap());
     /// ```
-    fn relative(&self, to: impl AsRef<Path>) -> PathBuf;
+    fn full_match(&self, pattern: &str, case: CaseSensitivity) -> Result<bool, PatternError>;
 }
 
 #[inline]
-fn normalize_to_component_vec(path: &Path) -> Vec<Component> {
-    let mut components = path.components().peekable();
-    let mut ret = if let Some(c @ Component::Prefix(..)) = components.peek().cloned() {
-        components.next();
-        vec![c]
-    } else {
-        vec![]
-    };
+fn normalize_to_component_vec(path: &Path) -> Vec<Component<'_>> {
+    low_level::normalize_components(path)
+}
 
-    for component in components {
-        match component {
-            Component::Prefix(..) => unreachable!(),
-            Component::RootDir => {
-                ret.push(component);
-            }
-            Component::CurDir => {}
-            c @ Component::ParentDir => {
-                let is_last_none = matches!(ret.last(), None | Some(Component::Prefix(_)));
-                if is_last_none {
-                    ret.push(c);
-                } else {
-                    let is_last_root = matches!(ret.last().unwrap(), Component::RootDir);
-                    if is_last_root {
-                        // do nothing
-                    } else {
-                        let is_last_parent_dir =
-                            matches!(ret.last().unwrap(), Component::ParentDir);
-                        if is_last_parent_dir {
-                            ret.push(c);
-                        } else {
-                            ret.pop();
-                        }
-                    }
-                }
-            }
-            c @ Component::Normal(_) => {
-                ret.push(c);
-            }
+#[inline]
+fn component_vec_to_path_buf(components: Vec<Component>) -> PathBuf {
+    low_level::components_to_path_buf(&components)
+}
+
+/// Compares two components, ignoring the case of normal components if `case` says so.
+pub(crate) fn component_eq(a: &Component, b: &Component, case: CaseSensitivity) -> bool {
+    match (a, b) {
+        (Component::Normal(a), Component::Normal(b)) => case.eq_os_str(a, b),
+        (Component::Prefix(a), Component::Prefix(b)) => prefix_eq(a, b, case),
+        _ => a == b,
+    }
+}
+
+/// Compares Windows prefixes. Drive letters never depend on case, so they are compared ignoring it whatever `case` is.
+fn prefix_eq(a: &PrefixComponent, b: &PrefixComponent, case: CaseSensitivity) -> bool {
+    match (a.kind(), b.kind()) {
+        (Prefix::Disk(a), Prefix::Disk(b)) | (Prefix::VerbatimDisk(a), Prefix::VerbatimDisk(b)) => {
+            a.eq_ignore_ascii_case(&b)
+        }
+        (Prefix::UNC(..), Prefix::UNC(..))
+        | (Prefix::VerbatimUNC(..), Prefix::VerbatimUNC(..))
+        | (Prefix::Verbatim(_), Prefix::Verbatim(_))
+        | (Prefix::DeviceNS(_), Prefix::DeviceNS(_)) => {
+            case.eq_os_str(a.as_os_str(), b.as_os_str())
         }
+        _ => false,
     }
-    ret
 }
 
-#[inline]
-fn component_vec_to_path_buf(components: Vec<Component>) -> PathBuf {
-    components
-        .into_iter()
-        .map(|c| c.as_os_str())
-        .fold(PathBuf::new(), |mut acc, cur| {
-            acc.push(cur);
-            acc
-        })
+/// `path` with every `/` replaced by `\`, keeping what isn't valid Unicode, like unpaired surrogates on Windows.
+fn with_backslashes(path: &Path) -> PathBuf {
+    let bytes = path
+        .as_os_str()
+        .as_encoded_bytes()
+        .iter()
+        .map(|b| if *b == b'/' { b'\\' } else { *b })
+        .collect::<Vec<_>>();
+    // SAFETY: only ASCII bytes were replaced, by other ASCII bytes.
+    PathBuf::from(unsafe { OsString::from_encoded_bytes_unchecked(bytes) })
+}
+
+/// [`SugarPath::normalize`] following the rules of the host.
+fn normalize_host(path: &Path) -> PathBuf {
+    let mut ret = if cfg!(target_family = "windows") {
+        // TODO: we may need to do it more delegated
+        let path = with_backslashes(path);
+        let mut components = normalize_to_component_vec(&path);
+        if components.is_empty()
+            || (components.len() == 1 && matches!(components[0], Component::Prefix(_)))
+        {
+            components.push(Component::CurDir)
+        }
+        component_vec_to_path_buf(components)
+    } else {
+        if scan::is_normalized_unix(path.as_os_str().as_encoded_bytes()) {
+            return path.to_path_buf();
+        }
+        let mut components = normalize_to_component_vec(path);
+        if components.is_empty() {
+            components.push(Component::CurDir)
+        }
+        component_vec_to_path_buf(components)
+    };
+    if has_trailing_separator(path) && !path.as_os_str().is_empty() {
+        // Pushing an empty path appends a separator unless there already is one.
+        ret.push("");
+    }
+    ret
 }
 
 impl SugarPath for Path {
     fn normalize(&self) -> PathBuf {
-        if cfg!(target_family = "windows") {
-            // TODO: we may need to do it more delegated
-            let path = PathBuf::from(self.to_string_lossy().to_string().replace("/", "\\"));
-            let mut components = normalize_to_component_vec(&path);
-            if components.is_empty()
-                || (components.len() == 1 && matches!(components[0], Component::Prefix(_)))
-            {
-                components.push(Component::CurDir)
+        match flavor::overridden() {
+            Some(platform) => flavor::normalize_as(self, platform),
+            None => normalize_host(self),
+        }
+    }
+
+    fn normalize_key(&self) -> Cow<'_, Path> {
+        if flavor::overridden().is_some() {
+            return Cow::Owned(self.normalize());
+        }
+        let has_slash = self.as_os_str().as_encoded_bytes().contains(&b'/');
+        let mut last = None;
+        for component in self.components() {
+            let folds = match component {
+                Component::CurDir => true,
+                Component::ParentDir => {
+                    matches!(last, Some(Component::Normal(_) | Component::RootDir))
+                }
+                // Verbatim paths only split on `\`, which `normalize` turns `/` into.
+                Component::Prefix(prefix) => prefix.kind().is_verbatim() && has_slash,
+                Component::RootDir | Component::Normal(_) => false,
+            };
+            if folds {
+                return Cow::Owned(self.normalize());
             }
-            component_vec_to_path_buf(components)
+            last = Some(component);
+        }
+        match last {
+            // `normalize` turns these into `.` and `C:.`.
+            None | Some(Component::Prefix(_)) => Cow::Owned(self.normalize()),
+            _ => Cow::Borrowed(self),
+        }
+    }
+
+    fn normalize_as(&self, platform: Platform) -> PathBuf {
+        if platform == Platform::current() {
+            normalize_host(self)
         } else {
-            let mut components = normalize_to_component_vec(self);
-            if components.len() == 0 {
-                components.push(Component::CurDir)
-            }
-            component_vec_to_path_buf(components)
+            flavor::normalize_as(self, platform)
+        }
+    }
+    fn normalized_display(&self, separator: char) -> NormalizedDisplay<'_> {
+        NormalizedDisplay::new(self, separator)
+    }
+
+    fn normalize_into(&self, buf: &mut PathBuf) {
+        if let Some(platform) = flavor::overridden() {
+            flavor::normalize_as_into(self, platform, buf.as_mut_os_string());
+            return;
+        }
+        let bytes = self.as_os_str().as_encoded_bytes();
+        if cfg!(target_family = "windows")
+            && bytes.len() >= 2
+            && bytes[..2]
+                .iter()
+                .all(|b| Platform::Windows.is_separator(*b))
+            && bytes.contains(&b'/')
+        {
+            // std only reads a UNC or verbatim prefix written with `\`, which `normalize` switches to first.
+            *buf = normalize_host(self);
+            return;
         }
+        let mut normalizer = Normalizer::with_buffer(std::mem::take(buf));
+        normalizer.feed(self);
+        *buf = normalizer.finish();
     }
+
     fn resolve(&self) -> PathBuf {
-        if cfg!(target_family = "windows") {
-            let path = PathBuf::from(self.to_string_lossy().to_string().replace("/", "\\"));
+        self.resolve_with(&*CWD)
+    }
+
+    fn resolve_with(&self, base: impl AsRef<Path>) -> PathBuf {
+        let base = base.as_ref();
+        if self.as_os_str().is_empty() {
+            // Pushing an empty path onto `base` would add a trailing separator the input never had.
+            return Path::new(".").resolve_with(base);
+        }
+        if let Some(platform) = flavor::overridden() {
+            flavor::resolve_as(self, base, platform)
+        } else if !base.is_absolute() {
+            // Ends with the current working directory, which is absolute.
+            self.resolve_with(base.resolve())
+        } else if cfg!(target_family = "windows") {
+            let path = with_backslashes(self);
             // Consider c:
             if path.is_absolute() {
                 path.normalize()
@@ -170,92 +1464,594 @@ impl SugarPath for Path {
                     // absolute path, get cwd for that drive, or the process cwd if
                     // the drive cwd is not available. We're sure the device is not
                     // a UNC path at this points, because UNC paths are always absolute.
-                    let mut components = path.components().into_iter().collect::<Vec<_>>();
+                    let mut components = path.components().collect::<Vec<_>>();
                     components.insert(1, Component::RootDir);
                     component_vec_to_path_buf(components).normalize()
                 } else {
-                    let mut cwd = CWD.clone();
-                    cwd.push(path);
-                    cwd.normalize()
+                    let mut base = base.to_path_buf();
+                    base.push(path);
+                    base.normalize()
                 }
             }
         } else {
             if self.is_absolute() {
                 self.normalize()
             } else {
-                let mut cwd = CWD.clone();
-                cwd.push(self);
-                cwd.normalize()
+                let mut base = base.to_path_buf();
+                base.push(self);
+                base.normalize()
             }
         }
     }
 
+    fn resolve_from_manifest_dir(&self) -> Result<PathBuf, AnchoredPathError> {
+        Anchor::ManifestDir.resolve(self)
+    }
+
+    fn relative_with_case(&self, to: impl AsRef<Path>, case: CaseSensitivity) -> PathBuf {
+        if let Some(platform) = flavor::overridden() {
+            return flavor::relative_as(self, to.as_ref(), platform, case);
+        }
+        let mut ret = PathBuf::new();
+        relative::relative_into(self, to.as_ref(), case, &mut ret);
+        ret
+    }
+
     fn relative(&self, to: impl AsRef<Path>) -> PathBuf {
-        // println!("start from: {:?}, to: {:?}", self, to.as_ref());
-        let base = to.as_ref().resolve();
-        let target = self.resolve();
-        if base == target {
-            PathBuf::new()
+        match flavor::overridden() {
+            Some(platform) => {
+                flavor::relative_as(self, to.as_ref(), platform, flavor::case(platform))
+            }
+            None => self.relative_with_case(to, CaseSensitivity::platform()),
+        }
+    }
+
+    fn relative_into(&self, to: impl AsRef<Path>, buf: &mut PathBuf) {
+        if let Some(platform) = flavor::overridden() {
+            *buf = flavor::relative_as(self, to.as_ref(), platform, flavor::case(platform));
+            return;
+        }
+        relative::relative_into(self, to.as_ref(), CaseSensitivity::platform(), buf);
+    }
+
+    fn try_relative(&self, to: impl AsRef<Path>) -> Result<PathBuf, TryRelativeError> {
+        if let Some(platform) = flavor::overridden() {
+            return flavor::try_relative_as(self, to.as_ref(), platform, flavor::case(platform));
+        }
+        let mut ret = PathBuf::new();
+        relative::try_relative_into(self, to.as_ref(), CaseSensitivity::platform(), &mut ret)?;
+        Ok(ret)
+    }
+    fn relative_with_report(&self, to: impl AsRef<Path>) -> (PathBuf, RelativeReport) {
+        if let Some(platform) = flavor::overridden() {
+            return flavor::relative_with_report_as(
+                self,
+                to.as_ref(),
+                platform,
+                flavor::case(platform),
+            );
+        }
+        relative::relative_with_report(self, to.as_ref(), CaseSensitivity::platform())
+    }
+
+    fn components_between<'a, B>(
+        &'a self,
+        base: &'a B,
+    ) -> Result<ComponentsBetween<'a>, TryRelativeError>
+    where
+        B: AsRef<Path> + ?Sized,
+    {
+        relative::components_between(self, base.as_ref(), CaseSensitivity::platform())
+    }
+
+    fn path_eq(&self, other: impl AsRef<Path>, case: CaseSensitivity) -> bool {
+        let (a, b) = (self.normalize(), other.as_ref().normalize());
+        let (mut a, mut b) = (a.components(), b.components());
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => return true,
+                (Some(a), Some(b)) if component_eq(&a, &b, case) => {}
+                _ => return false,
+            }
+        }
+    }
+    #[cfg(feature = "fs")]
+    fn expand_short_names(&self) -> std::io::Result<PathBuf> {
+        fs::expand_short_names(self)
+    }
+    #[cfg(feature = "fs")]
+    fn glob(&self, pattern: &Pattern) -> std::io::Result<Vec<PathBuf>> {
+        WalkOptions::new().glob(&StdFileSystem, self, pattern)
+    }
+    #[cfg(feature = "fs")]
+    fn rglob(&self, pattern: &Pattern) -> std::io::Result<Vec<PathBuf>> {
+        WalkOptions::new().rglob(&StdFileSystem, self, pattern)
+    }
+    fn check_limits(&self, platform: Platform) -> Vec<LimitViolation> {
+        limits::check_limits(self, platform)
+    }
+    fn has_reserved_component(&self) -> bool {
+        self.components().any(|component| match component {
+            Component::Normal(name) => windows::is_reserved_name(name),
+            _ => false,
+        })
+    }
+    fn is_hidden(&self) -> bool {
+        let is_dotfile = self
+            .file_name()
+            .is_some_and(|name| name.as_encoded_bytes().starts_with(b"."));
+        #[cfg(feature = "fs")]
+        {
+            is_dotfile || fs::has_hidden_attribute(self)
+        }
+        #[cfg(not(feature = "fs"))]
+        {
+            is_dotfile
+        }
+    }
+
+    fn has_invisible_chars(&self) -> bool {
+        invisible::has_invisible(self)
+    }
+
+    fn strip_invisible(&self) -> Cow<'_, Path> {
+        invisible::strip_invisible(self)
+    }
+
+    fn is_inside_hidden_dir(&self) -> bool {
+        if let Some(platform) = flavor::overridden() {
+            return flavor::is_inside_hidden_dir_as(self, platform);
+        }
+        let components = normalize_to_component_vec(self);
+        let dirs = components.split_last().map_or(&[][..], |(_, dirs)| dirs);
+        dirs.iter().any(|component| match component {
+            Component::Normal(name) => name.as_encoded_bytes().starts_with(b"."),
+            _ => false,
+        })
+    }
+    fn hash_normalized<H: std::hash::Hasher>(&self, state: &mut H, case: CaseSensitivity) {
+        hash::hash_normalized(self, state, case)
+    }
+    fn canonical_key(&self) -> String {
+        self.canonical_key_as(default_platform())
+    }
+
+    fn canonical_key_as(&self, platform: Platform) -> String {
+        key::canonical_key(self, platform, flavor::case(platform))
+    }
+
+    fn sort_key(&self, case: CaseSensitivity) -> Vec<u8> {
+        key::sort_key(self, case)
+    }
+
+    fn redact(&self) -> String {
+        Redactor::new().redact(self)
+    }
+    fn drive(&self) -> Option<&OsStr> {
+        match self.components().next() {
+            Some(Component::Prefix(prefix)) => Some(prefix.as_os_str()),
+            _ => None,
+        }
+    }
+
+    fn with_drive(&self, drive: impl AsRef<OsStr>) -> PathBuf {
+        if !cfg!(target_family = "windows") {
+            return self.to_path_buf();
+        }
+        let drive = drive.as_ref();
+        let mut components = self.components();
+        if self.drive().is_some() {
+            components.next();
+        }
+        let rest = components.as_path().as_os_str();
+        let mut ret = drive.to_os_string();
+        // A UNC prefix must be followed by a separator, unlike a drive letter (`C:foo`).
+        let is_drive_letter = drive.as_encoded_bytes().ends_with(b":");
+        let starts_with_separator = rest
+            .as_encoded_bytes()
+            .first()
+            .is_some_and(|b| Platform::Windows.is_separator(*b));
+        if !rest.is_empty() && !is_drive_letter && !starts_with_separator {
+            ret.push("\\");
+        }
+        ret.push(rest);
+        PathBuf::from(ret)
+    }
+    fn unc_server(&self) -> Option<&OsStr> {
+        unc_server_and_share(self).map(|(server, _)| server)
+    }
+
+    fn unc_share(&self) -> Option<&OsStr> {
+        unc_server_and_share(self).map(|(_, share)| share)
+    }
+
+    fn strip_unc_root(&self) -> Option<&Path> {
+        unc_server_and_share(self)?;
+        let mut components = self.components();
+        components.next();
+        let mut rest = components.clone();
+        if let Some(Component::RootDir) = rest.next() {
+            components = rest;
+        }
+        Some(components.as_path())
+    }
+    fn split_root(&self) -> (PathBuf, PathBuf) {
+        if let Some(platform) = flavor::overridden() {
+            return flavor::split_root_as(self, platform);
+        }
+        let mut root = PathBuf::new();
+        let mut rest = PathBuf::new();
+        for component in normalize_to_component_vec(self) {
+            match component {
+                Component::Prefix(_) | Component::RootDir => root.push(component),
+                _ => rest.push(component),
+            }
+        }
+        (root, rest)
+    }
+
+    fn root(&self) -> Option<PathBuf> {
+        if self.has_root() {
+            Some(self.anchor())
         } else {
-            let base_components = base
-                .components()
-                .into_iter()
-                .filter(|com| {
-                    matches!(
-                        com,
-                        Component::Normal(_) | Component::Prefix(_) | Component::RootDir
-                    )
-                })
-                .collect::<Vec<_>>();
-            let target_components = target
-                .components()
-                .into_iter()
-                .filter(|com| {
-                    matches!(
-                        com,
-                        Component::Normal(_) | Component::Prefix(_) | Component::RootDir
-                    )
-                })
-                .collect::<Vec<_>>();
-            let mut ret = PathBuf::new();
-            let longest_len = if base_components.len() > target_components.len() {
-                base_components.len()
-            } else {
-                target_components.len()
-            };
-            let mut i = 0;
-            while i < longest_len {
-                let from_component = base_components.get(i);
-                let to_component = target_components.get(i);
-                // println!("process from: {:?}, to: {:?}", from_component, to_component);
-                if cfg!(target_family = "windows") {
-                    if let Some(Component::Normal(from_seg)) = from_component {
-                        if let Some(Component::Normal(to_seg)) = to_component {
-                            if from_seg.to_ascii_lowercase() == to_seg.to_ascii_lowercase() {
-                                i += 1;
-                                continue;
-                            }
-                        }
-                    }
-                }
-                if from_component != to_component {
-                    break;
-                }
-                i += 1;
+            None
+        }
+    }
+
+    fn anchor(&self) -> PathBuf {
+        self.components()
+            .take_while(|component| matches!(component, Component::Prefix(_) | Component::RootDir))
+            .collect()
+    }
+
+    fn strip_root(&self) -> PathBuf {
+        self.split_root().1
+    }
+
+    fn strip_root_with_separator(&self, separator: char) -> String {
+        let rest = self.strip_root();
+        let mut joined = String::with_capacity(rest.as_os_str().len() + 1);
+        for component in rest.components() {
+            if !joined.is_empty() {
+                joined.push(separator);
+            }
+            joined.push_str(&component.as_os_str().to_string_lossy());
+        }
+        if !joined.is_empty() && has_trailing_separator(self) {
+            joined.push(separator);
+        }
+        joined
+    }
+
+    fn truncate_to_depth(&self, depth: usize) -> PathBuf {
+        let (mut root, rest) = self.split_root();
+        root.extend(rest.components().take(depth));
+        if matches!(
+            root.components().next_back(),
+            None | Some(Component::Prefix(_))
+        ) {
+            root.push(".");
+        }
+        root
+    }
+
+    fn replace_component(
+        &self,
+        index: usize,
+        segment: impl AsRef<OsStr>,
+    ) -> Result<PathBuf, ComponentError> {
+        component::edit_components(self, segment.as_ref(), |components, segment| {
+            let len = components.len();
+            let component = components
+                .get_mut(index)
+                .ok_or(ComponentError::IndexOutOfBounds { index, len })?;
+            *component = segment;
+            Ok(())
+        })
+    }
+
+    fn insert_component(
+        &self,
+        index: usize,
+        segment: impl AsRef<OsStr>,
+    ) -> Result<PathBuf, ComponentError> {
+        component::edit_components(self, segment.as_ref(), |components, segment| {
+            let len = components.len();
+            if index > len {
+                return Err(ComponentError::IndexOutOfBounds { index, len });
+            }
+            components.insert(index, segment);
+            Ok(())
+        })
+    }
+
+    fn strip_components(&self, count: usize) -> Result<PathBuf, StripComponentsError> {
+        let (_, rest) = self.split_root();
+        let depth = rest.components().count();
+        if depth < count {
+            return Err(StripComponentsError {
+                depth,
+                requested: count,
+            });
+        }
+        Ok(rest.components().skip(count).collect())
+    }
+
+    fn with_name(&self, name: impl AsRef<OsStr>) -> Result<PathBuf, ComponentError> {
+        component::edit_components(self, name.as_ref(), |components, name| {
+            match components.last_mut() {
+                Some(last @ Component::Normal(_)) => *last = name,
+                _ => return Err(ComponentError::NoFileName),
             }
-            let mut from_start = i;
-            while from_start < base_components.len() {
-                ret.push("..");
-                from_start += 1;
+            Ok(())
+        })
+    }
+
+    fn ends_with_extension(&self, extension: &str) -> bool {
+        let extension = extension.strip_prefix('.').unwrap_or(extension).as_bytes();
+        let Some(name) = self.file_name() else {
+            return false;
+        };
+        let name = name.as_encoded_bytes();
+        // The name needs a non-empty stem, a `.` and then the extension.
+        !extension.is_empty()
+            && name.len() > extension.len() + 1
+            && name[name.len() - extension.len() - 1] == b'.'
+            && name[name.len() - extension.len()..].eq_ignore_ascii_case(extension)
+    }
+
+    fn has_extension_in(&self, extensions: &[&str]) -> bool {
+        extensions
+            .iter()
+            .any(|extension| self.ends_with_extension(extension))
+    }
+
+    fn extension_typed(&self) -> Option<Extension> {
+        Extension::new(self.extension()?.to_str()?).ok()
+    }
+
+    fn to_str_checked(&self) -> Result<&str, NonUtf8PathError> {
+        utf8::to_str(self, self.as_os_str())
+    }
+
+    fn file_name_str(&self) -> Result<Option<&str>, NonUtf8PathError> {
+        self.file_name()
+            .map(|name| utf8::to_str(self, name))
+            .transpose()
+    }
+
+    fn extension_str(&self) -> Result<Option<&str>, NonUtf8PathError> {
+        self.extension()
+            .map(|extension| utf8::to_str(self, extension))
+            .transpose()
+    }
+
+    fn with_file_name_casing(&self, casing: Casing, include_extension: bool) -> PathBuf {
+        let Some(name) = self.file_name().and_then(OsStr::to_str) else {
+            return self.to_path_buf();
+        };
+        let name = Path::new(name);
+        let (Some(stem), extension) = (name.file_stem(), name.extension()) else {
+            return self.to_path_buf();
+        };
+        // Both are slices of a `str`, split on a `.`.
+        let (stem, extension) = (
+            stem.to_str().unwrap(),
+            extension.map(|e| e.to_str().unwrap()),
+        );
+        let mut renamed = casing.apply(stem);
+        if let Some(extension) = extension {
+            renamed.push('.');
+            match (include_extension, casing) {
+                (false, _) => renamed.push_str(extension),
+                (true, Casing::Kebab | Casing::Snake) => {
+                    renamed.push_str(&extension.to_lowercase())
+                }
+                (true, casing) => renamed.push_str(&casing.apply(extension)),
             }
+        }
+        self.with_file_name(renamed)
+    }
+
+    fn breadcrumbs(&self) -> Breadcrumbs {
+        Breadcrumbs::new(self)
+    }
+
+    fn parts(&self) -> Vec<OsString> {
+        if let Some(platform) = flavor::overridden() {
+            return flavor::parts_as(self, platform);
+        }
+        let (root, rest) = self.split_root();
+        let root = (!root.as_os_str().is_empty()).then(|| root.into_os_string());
+        root.into_iter()
+            .chain(
+                rest.components()
+                    .map(|component| component.as_os_str().to_os_string()),
+            )
+            .collect()
+    }
+
+    fn nth_ancestor(&self, n: usize) -> Option<PathBuf> {
+        let (_, rest) = self.split_root();
+        let depth = rest.components().count();
+        depth
+            .checked_sub(n)
+            .map(|depth| self.truncate_to_depth(depth))
+    }
+
+    fn parents(&self) -> Vec<PathBuf> {
+        let (root, rest) = self.split_root();
+        let names = rest.components().collect::<Vec<_>>();
+        (0..names.len())
+            .rev()
+            .map(|depth| {
+                let mut ancestor = root.clone();
+                ancestor.extend(&names[..depth]);
+                if matches!(
+                    ancestor.components().next_back(),
+                    None | Some(Component::Prefix(_))
+                ) {
+                    ancestor.push(".");
+                }
+                ancestor
+            })
+            .collect()
+    }
+
+    fn expand_user(&self) -> Result<PathBuf, ExpandUserError> {
+        home::expand_user_with(self, home::home_dir)
+    }
+
+    fn expand_user_with(
+        &self,
+        lookup: impl FnOnce(Option<&OsStr>) -> Option<PathBuf>,
+    ) -> Result<PathBuf, ExpandUserError> {
+        home::expand_user_with(self, lookup)
+    }
 
-            let mut to_start = i;
-            while to_start < target_components.len() {
-                ret.push(target_components[to_start]);
-                to_start += 1;
+    fn encode_components(&self) -> String {
+        encoding::encode_components(self)
+    }
+
+    fn clamp_to(&self, base: impl AsRef<Path>) -> PathBuf {
+        if let Some(platform) = flavor::overridden() {
+            return flavor::clamp_to_as(self, base.as_ref(), platform);
+        }
+        let mut names = vec![];
+        for component in self.components() {
+            match component {
+                Component::Normal(name) => names.push(name),
+                Component::ParentDir => {
+                    names.pop();
+                }
+                Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
             }
+        }
+        let mut path = base.as_ref().normalize();
+        for name in names {
+            path_buf::push_component(&mut path, Component::Normal(name));
+        }
+        path
+    }
+
+    fn concat_overlapping(&self, other: impl AsRef<Path>) -> PathBuf {
+        let other = other.as_ref();
+        if let Some(platform) = flavor::overridden() {
+            return flavor::concat_overlapping_as(self, other, platform);
+        }
+        if other.has_root() || matches!(other.components().next(), Some(Component::Prefix(_))) {
+            return self.join(other).normalize();
+        }
+        let case = CaseSensitivity::platform();
+        let path = normalize_to_component_vec(self);
+        let other = normalize_to_component_vec(other);
+        let overlap = (1..=path.len().min(other.len()))
+            .rev()
+            .find(|&len| {
+                other[..len]
+                    .iter()
+                    .zip(&path[path.len() - len..])
+                    .all(|(a, b)| matches!(a, Component::Normal(_)) && component_eq(a, b, case))
+            })
+            .unwrap_or(0);
+        let mut joined = path.iter().collect::<PathBuf>();
+        // Pushing an empty path would add a trailing separator.
+        if overlap < other.len() {
+            joined.push(other[overlap..].iter().collect::<PathBuf>());
+        }
+        joined.normalize()
+    }
+
+    fn escape_depth(&self) -> usize {
+        if let Some(platform) = flavor::overridden() {
+            return flavor::escape_depth_as(self, platform);
+        }
+        normalize_to_component_vec(self)
+            .iter()
+            .skip_while(|component| matches!(component, Component::Prefix(_)))
+            .take_while(|component| matches!(component, Component::ParentDir))
+            .count()
+    }
+
+    fn is_relative_to(&self, base: impl AsRef<Path>) -> bool {
+        if let Some(platform) = flavor::overridden() {
+            return flavor::is_relative_to_as(self, base.as_ref(), platform);
+        }
+        let case = CaseSensitivity::platform();
+        let path = normalize_to_component_vec(self);
+        let base = normalize_to_component_vec(base.as_ref());
+        if base.is_empty() {
+            // `.` contains every relative path that doesn't walk out of it.
+            return matches!(path.first(), None | Some(Component::Normal(_)));
+        }
+        base.len() <= path.len()
+            && base
+                .iter()
+                .zip(&path)
+                .all(|(base, component)| component_eq(base, component, case))
+    }
 
-            ret
+    fn match_pattern(&self, pattern: &str, case: CaseSensitivity) -> Result<bool, PatternError> {
+        if pattern.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        let anchored = matches!(
+            Path::new(pattern).components().next(),
+            Some(Component::Prefix(_) | Component::RootDir)
+        );
+        if anchored {
+            return self.full_match(pattern, case);
         }
+        let pattern = Pattern::new(&format!("**/{}", pattern))?.case_sensitivity(case);
+        // `**` doesn't match an anchor, so leave it out of a relative match.
+        let path = self.normalize();
+        let names = path
+            .components()
+            .skip_while(|component| matches!(component, Component::Prefix(_) | Component::RootDir))
+            .collect::<Vec<_>>();
+        Ok(pattern.matches_components(&names))
+    }
+
+    fn full_match(&self, pattern: &str, case: CaseSensitivity) -> Result<bool, PatternError> {
+        Ok(Pattern::new(pattern)?.case_sensitivity(case).matches(self))
+    }
+
+    fn is_explicitly_relative(&self) -> bool {
+        let rest = match self.as_os_str().as_encoded_bytes() {
+            [b'.', b'.', rest @ ..] | [b'.', rest @ ..] => rest,
+            _ => return false,
+        };
+        rest.first()
+            .is_some_and(|b| Platform::current().is_separator(*b))
+    }
+
+    fn is_dir_like(&self) -> bool {
+        // `Path::file_name` skips a trailing `.`, so look at the bytes for it.
+        let bytes = self.as_os_str().as_encoded_bytes();
+        let ends_with_cur_dir = match bytes {
+            [b'.'] => true,
+            [.., sep, b'.'] => Platform::current().is_separator(*sep),
+            _ => false,
+        };
+        has_trailing_separator(self) || ends_with_cur_dir || self.file_name().is_none()
+    }
+}
+
+pub(crate) fn has_trailing_separator(path: &Path) -> bool {
+    path.as_os_str()
+        .as_encoded_bytes()
+        .last()
+        .is_some_and(|b| Platform::current().is_separator(*b))
+}
+
+fn unc_server_and_share(path: &Path) -> Option<(&OsStr, &OsStr)> {
+    match path.components().next() {
+        Some(Component::Prefix(prefix)) => match prefix.kind() {
+            Prefix::UNC(server, share) | Prefix::VerbatimUNC(server, share) => {
+                Some((server, share))
+            }
+            _ => None,
+        },
+        _ => None,
     }
 }
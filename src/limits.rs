@@ -0,0 +1,73 @@
+use std::{ffi::OsString, fmt, path::Path};
+
+use crate::{os_str, Platform};
+
+/// Maximum length of a single component: `NAME_MAX` in bytes on POSIX, UTF-16 units on Windows.
+pub const NAME_MAX: usize = 255;
+/// Maximum length of a whole POSIX path in bytes, not counting the terminating NUL.
+pub const POSIX_PATH_MAX: usize = 4095;
+/// Maximum length of a whole Windows path in UTF-16 units (`MAX_PATH`), not counting the terminating NUL.
+pub const WINDOWS_MAX_PATH: usize = 259;
+
+/// A length limit exceeded by a path, as reported by [`SugarPath::check_limits`](crate::SugarPath::check_limits).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LimitViolation {
+    /// A single component is longer than [`NAME_MAX`].
+    ComponentTooLong {
+        component: OsString,
+        len: usize,
+        max: usize,
+    },
+    /// The whole path is longer than [`POSIX_PATH_MAX`] or [`WINDOWS_MAX_PATH`].
+    PathTooLong { len: usize, max: usize },
+}
+
+impl fmt::Display for LimitViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitViolation::ComponentTooLong {
+                component,
+                len,
+                max,
+            } => write!(
+                f,
+                "component {:?} is {} long, the limit is {}",
+                component, len, max
+            ),
+            LimitViolation::PathTooLong { len, max } => {
+                write!(f, "path is {} long, the limit is {}", len, max)
+            }
+        }
+    }
+}
+
+pub(crate) fn check_limits(path: &Path, platform: Platform) -> Vec<LimitViolation> {
+    let len = match platform {
+        Platform::Posix => |s: &std::ffi::OsStr| s.len(),
+        Platform::Windows => os_str::utf16_len,
+    };
+    let path_max = match platform {
+        Platform::Posix => POSIX_PATH_MAX,
+        Platform::Windows => WINDOWS_MAX_PATH,
+    };
+
+    let mut violations = vec![];
+    for component in os_str::split(path.as_os_str(), |b| platform.is_separator(b)) {
+        let component_len = len(component);
+        if component_len > NAME_MAX {
+            violations.push(LimitViolation::ComponentTooLong {
+                component: component.to_os_string(),
+                len: component_len,
+                max: NAME_MAX,
+            });
+        }
+    }
+    let path_len = len(path.as_os_str());
+    if path_len > path_max {
+        violations.push(LimitViolation::PathTooLong {
+            len: path_len,
+            max: path_max,
+        });
+    }
+    violations
+}
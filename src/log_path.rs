@@ -0,0 +1,97 @@
+use std::{fmt, path::Path};
+
+use crate::{home::home_dir, key::canonical_key, CaseSensitivity, Platform, SugarPath, CWD};
+
+/// Displays a path the same way on every platform, for logs.
+///
+/// The path is normalized and its components are joined with `/`, like [`SugarPath::canonical_key`] but keeping the case. Optionally, a path beneath the current working directory is shown relative to it, and a path beneath the home directory is shown as `~/...`. The working directory is tried first.
+///
+/// With the `tracing` feature, [`field`](LogPath::field) records it as a `tracing` field, and with the `log` feature it is a `log` key-value, see [`as_value`](LogPath::as_value).
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{LogPath, SugarPath};
+///
+/// assert_eq!(LogPath::new(Path::new("a/./b/../c")).to_string(), "a/c");
+///
+/// let path = Path::new("src/lib.rs").resolve();
+/// assert_eq!(LogPath::new(&path).contract_cwd(true).to_string(), "src/lib.rs");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LogPath<'a> {
+    path: &'a Path,
+    contract_home: bool,
+    contract_cwd: bool,
+}
+
+impl<'a> LogPath<'a> {
+    pub fn new(path: &'a Path) -> Self {
+        LogPath {
+            path,
+            contract_home: false,
+            contract_cwd: false,
+        }
+    }
+
+    /// Shows a path beneath the home directory as `~/...`. Defaults to `false`.
+    pub fn contract_home(mut self, contract_home: bool) -> Self {
+        self.contract_home = contract_home;
+        self
+    }
+
+    /// Shows a path beneath the current working directory relative to it. Defaults to `false`.
+    pub fn contract_cwd(mut self, contract_cwd: bool) -> Self {
+        self.contract_cwd = contract_cwd;
+        self
+    }
+
+    /// Wraps the path for recording as a `tracing` field, e.g. `tracing::info!(path = LogPath::new(path).field())`.
+    #[cfg(feature = "tracing")]
+    pub fn field(self) -> tracing::field::DisplayValue<Self> {
+        tracing::field::display(self)
+    }
+
+    /// Returns the path as a `log` key-value, e.g. `log::info!(path = LogPath::new(path).as_value(); "...")`.
+    #[cfg(feature = "log")]
+    pub fn as_value(&self) -> log::kv::Value<'_> {
+        log::kv::Value::from_display(self)
+    }
+}
+
+impl fmt::Display for LogPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self.path.normalize();
+        if self.contract_cwd && path.is_absolute() {
+            if let Ok(rest) = path.strip_prefix(&*CWD) {
+                return f.write_str(&canonical_key(
+                    rest,
+                    Platform::current(),
+                    CaseSensitivity::Sensitive,
+                ));
+            }
+        }
+        if self.contract_home {
+            if let Some(home) = home_dir(None) {
+                if let Ok(rest) = path.strip_prefix(home.normalize()) {
+                    if rest.as_os_str().is_empty() {
+                        return f.write_str("~");
+                    }
+                    let rest = canonical_key(rest, Platform::current(), CaseSensitivity::Sensitive);
+                    return write!(f, "~/{}", rest);
+                }
+            }
+        }
+        f.write_str(&canonical_key(
+            &path,
+            Platform::current(),
+            CaseSensitivity::Sensitive,
+        ))
+    }
+}
+
+#[cfg(feature = "log")]
+impl log::kv::ToValue for LogPath<'_> {
+    fn to_value(&self) -> log::kv::Value<'_> {
+        self.as_value()
+    }
+}
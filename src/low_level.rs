@@ -0,0 +1,154 @@
+//! The component folding [`normalize`](crate::SugarPath::normalize) is built on, for resolvers that need to bend its rules, like bundlers deciding what a `..` above the root means, without forking the crate.
+//!
+//! These items follow semantic versioning like the rest of the public API: [`normalize_components`] keeps returning exactly what `normalize` is made of.
+//!
+//! ```rust
+//! use std::path::Path;
+//! use sugar_path::low_level::{ComponentStack, Folded};
+//!
+//! // Tells paths that climb out of the directory they are relative to apart.
+//! let escapes = |path: &str| {
+//!     let mut stack = ComponentStack::new();
+//!     Path::new(path)
+//!         .components()
+//!         .any(|component| stack.push(component) == Folded::Escaped)
+//! };
+//! assert!(escapes("a/../../b"));
+//! assert!(!escapes("a/./../b"));
+//! ```
+
+use std::path::{Component, Path, PathBuf};
+
+/// What [`ComponentStack::push`] did with a component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Folded {
+    /// The component was added on top.
+    Pushed,
+    /// The component was a `..` and removed the name on top.
+    Popped,
+    /// The component was a `.` and left the stack as it was.
+    Skipped,
+    /// The component was a `..` with no name to remove, because the stack is empty or ends with a root, a prefix or another `..`. It wasn't added; see [`ComponentStack::push_escaped_parent`].
+    Escaped,
+    /// The component was a root or a prefix after what can come before it. It wasn't added.
+    Misplaced,
+}
+
+/// The components of a path being normalized, folded one at a time.
+///
+/// `.` is dropped and `..` removes the name before it. What happens to a `..` that has no name to remove is up to the caller: [`normalize_components`] keeps it at the start of a path without a root, and drops it after a root.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentStack<'a> {
+    components: Vec<Component<'a>>,
+}
+
+impl<'a> ComponentStack<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            components: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Folds `component` into the stack.
+    ///
+    /// A prefix is only accepted first, and a root only first or right after a prefix; anywhere else they are [`Folded::Misplaced`].
+    pub fn push(&mut self, component: Component<'a>) -> Folded {
+        match component {
+            Component::Prefix(_) if !self.components.is_empty() => Folded::Misplaced,
+            Component::RootDir
+                if !matches!(self.components.as_slice(), [] | [Component::Prefix(_)]) =>
+            {
+                Folded::Misplaced
+            }
+            Component::CurDir => Folded::Skipped,
+            Component::ParentDir => {
+                if matches!(self.components.last(), Some(Component::Normal(_))) {
+                    self.components.pop();
+                    Folded::Popped
+                } else {
+                    Folded::Escaped
+                }
+            }
+            Component::Prefix(_) | Component::RootDir | Component::Normal(_) => {
+                self.components.push(component);
+                Folded::Pushed
+            }
+        }
+    }
+
+    /// Adds a `..` on top, for a `..` that [`Escaped`](Folded::Escaped) and should be kept, as [`normalize_components`] does when there is no root.
+    pub fn push_escaped_parent(&mut self) {
+        self.components.push(Component::ParentDir);
+    }
+
+    /// Whether the stack has a root, so that nothing is above it.
+    pub fn has_root(&self) -> bool {
+        self.components
+            .iter()
+            .take(2)
+            .any(|component| *component == Component::RootDir)
+    }
+
+    pub fn as_slice(&self) -> &[Component<'a>] {
+        &self.components
+    }
+
+    pub fn into_vec(self) -> Vec<Component<'a>> {
+        self.components
+    }
+
+    /// Rebuilds the path, see [`components_to_path_buf`].
+    pub fn to_path_buf(&self) -> PathBuf {
+        components_to_path_buf(&self.components)
+    }
+}
+
+/// Folds the components of `path` the way [`normalize`](crate::SugarPath::normalize) does.
+///
+/// An empty result stands for the empty path, which `normalize` writes as `.`. Unlike `normalize`, this doesn't look at separators, so it doesn't keep a trailing one, and on Windows it expects `path` to already use `\`.
+///
+/// ```rust
+/// use std::path::{Component, Path};
+/// use sugar_path::low_level::normalize_components;
+///
+/// assert_eq!(
+///     normalize_components(Path::new("../a/./b/..")),
+///     [Component::ParentDir, Component::Normal("a".as_ref())]
+/// );
+/// assert_eq!(normalize_components(Path::new("a/..")), []);
+/// ```
+pub fn normalize_components(path: &Path) -> Vec<Component<'_>> {
+    let mut stack = ComponentStack::new();
+    for component in path.components() {
+        if stack.push(component) == Folded::Escaped && !stack.has_root() {
+            stack.push_escaped_parent();
+        }
+    }
+    stack.into_vec()
+}
+
+/// Joins `components` back into a path. No components make the empty path.
+///
+/// ```rust
+/// use std::path::{Component, Path};
+/// use sugar_path::low_level::components_to_path_buf;
+///
+/// let components = [Component::ParentDir, Component::Normal("a".as_ref())];
+/// assert_eq!(components_to_path_buf(&components), Path::new("../a"));
+/// assert_eq!(components_to_path_buf(&[]), Path::new(""));
+/// ```
+pub fn components_to_path_buf(components: &[Component]) -> PathBuf {
+    let len = components
+        .iter()
+        .map(|component| component.as_os_str().len() + 1)
+        .sum();
+    let mut path = PathBuf::with_capacity(len);
+    for component in components {
+        path.push(component.as_os_str());
+    }
+    path
+}
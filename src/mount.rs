@@ -0,0 +1,182 @@
+use std::path::{Component, Path, PathBuf, Prefix};
+
+use crate::SugarPath;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Target {
+    Dir(PathBuf),
+    Passthrough,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Mount {
+    root: Vec<String>,
+    target: Target,
+}
+
+/// Maps virtual roots, as seen in URLs, to physical directories, the way dev servers serve `/assets/logo.png` from `./public/logo.png`.
+///
+/// Virtual paths use `/` as the separator and are normalized first, with `..` never going above `/`, so a request can't climb out of a mount. The mount with the longest virtual root containing the path wins, comparing whole components, so `/assets` doesn't match `/assets2/x`. Mounting at `/` serves everything else.
+///
+/// [`passthrough`](MountTable::passthrough) mounts the whole filesystem, so `/@fs/home/me/x.js` is `/home/me/x.js`, or `/@fs/C:/x.js` on Windows.
+///
+/// Physical paths are normalized but not resolved, so use absolute directories to translate absolute paths back with [`to_virtual`](MountTable::to_virtual).
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::MountTable;
+///
+/// let mounts = MountTable::new()
+///     .mount("/", "dist")
+///     .mount("/assets", "./public")
+///     .passthrough("/@fs");
+/// assert_eq!(mounts.to_physical("/assets/img/../logo.png").unwrap(), Path::new("public/logo.png"));
+/// assert_eq!(mounts.to_physical("/index.html").unwrap(), Path::new("dist/index.html"));
+/// assert_eq!(mounts.to_virtual("public/logo.png").as_deref(), Some("/assets/logo.png"));
+/// #[cfg(target_family = "unix")]
+/// {
+///     assert_eq!(mounts.to_physical("/@fs/home/me/x.js").unwrap(), Path::new("/home/me/x.js"));
+///     assert_eq!(mounts.to_virtual("/home/me/x.js").as_deref(), Some("/@fs/home/me/x.js"));
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MountTable {
+    mounts: Vec<Mount>,
+}
+
+impl MountTable {
+    /// A table without any mount.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves the normalized `dir` at `virtual_root`, replacing what was mounted there.
+    pub fn mount(self, virtual_root: &str, dir: impl AsRef<Path>) -> Self {
+        let mut dir = dir.as_ref().normalize();
+        if dir == Path::new(".") {
+            // Lets `strip_prefix` match the relative paths beneath it.
+            dir.clear();
+        }
+        self.insert(virtual_root, Target::Dir(dir))
+    }
+
+    /// Serves the whole filesystem at `virtual_root`: the rest of a virtual path is the absolute physical path.
+    pub fn passthrough(self, virtual_root: &str) -> Self {
+        self.insert(virtual_root, Target::Passthrough)
+    }
+
+    fn insert(mut self, virtual_root: &str, target: Target) -> Self {
+        let root = virtual_names(virtual_root)
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        self.mounts.retain(|mount| mount.root != root);
+        self.mounts.push(Mount { root, target });
+        self
+    }
+
+    /// The physical path served at `virtual_path`, or `None` if no mount contains it, or if a name would be more than a single component on this platform, like `a\b` on Windows.
+    pub fn to_physical(&self, virtual_path: &str) -> Option<PathBuf> {
+        let names = virtual_names(virtual_path);
+        let mount = self
+            .mounts
+            .iter()
+            .filter(|mount| {
+                mount.root.len() <= names.len()
+                    && mount.root.iter().zip(&names).all(|(a, b)| a == b)
+            })
+            .max_by_key(|mount| mount.root.len())?;
+        let rest = &names[mount.root.len()..];
+        match &mount.target {
+            Target::Dir(dir) => {
+                let mut path = dir.clone();
+                for name in rest {
+                    let mut components = Path::new(name).components();
+                    match (components.next(), components.next()) {
+                        (Some(Component::Normal(normal)), None) if normal == *name => {
+                            path.push(name)
+                        }
+                        _ => return None,
+                    }
+                }
+                Some(path)
+            }
+            Target::Passthrough => {
+                let path = if cfg!(target_family = "windows") {
+                    PathBuf::from(rest.join("/"))
+                } else {
+                    PathBuf::from(format!("/{}", rest.join("/")))
+                };
+                path.is_absolute().then(|| path.normalize())
+            }
+        }
+    }
+
+    /// The virtual path serving the normalized `path`, or `None` if no mount contains it or it isn't valid UTF-8.
+    ///
+    /// The mount with the longest physical directory wins, then the first one mounted. Passthrough mounts only serve absolute paths that no other mount contains.
+    pub fn to_virtual(&self, path: impl AsRef<Path>) -> Option<String> {
+        let path = path.as_ref().normalize();
+        let mounted = self
+            .mounts
+            .iter()
+            .filter_map(|mount| match &mount.target {
+                Target::Dir(dir) => Some((mount, dir, path.strip_prefix(dir).ok()?)),
+                Target::Passthrough => None,
+            })
+            .filter(|(_, _, rest)| {
+                rest.components()
+                    .all(|component| matches!(component, Component::Normal(_)))
+            })
+            // The first of the longest directories.
+            .rev()
+            .max_by_key(|(_, dir, _)| dir.components().count());
+        if let Some((mount, _, rest)) = mounted {
+            let mut names = mount.root.clone();
+            for component in rest.components() {
+                names.push(component.as_os_str().to_str()?.to_string());
+            }
+            return Some(virtual_path(&names));
+        }
+
+        let mount = self
+            .mounts
+            .iter()
+            .find(|mount| mount.target == Target::Passthrough)?;
+        if !path.is_absolute() {
+            return None;
+        }
+        let mut names = mount.root.clone();
+        for component in path.components() {
+            match component {
+                Component::Prefix(prefix) => match prefix.kind() {
+                    Prefix::Disk(_) => names.push(prefix.as_os_str().to_str()?.to_string()),
+                    // UNC and verbatim prefixes don't survive as `/` separated names.
+                    _ => return None,
+                },
+                Component::Normal(name) => names.push(name.to_str()?.to_string()),
+                _ => {}
+            }
+        }
+        Some(virtual_path(&names))
+    }
+}
+
+/// The names of the normalized virtual path, where `..` doesn't go above the root.
+fn virtual_names(path: &str) -> Vec<&str> {
+    let mut names = vec![];
+    for name in path.split('/') {
+        match name {
+            "" | "." => {}
+            ".." => {
+                names.pop();
+            }
+            name => names.push(name),
+        }
+    }
+    names
+}
+
+fn virtual_path(names: &[String]) -> String {
+    format!("/{}", names.join("/"))
+}
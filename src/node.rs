@@ -0,0 +1,656 @@
+use std::{borrow::Cow, env};
+
+use crate::{default_platform, Platform, CWD};
+
+const RESERVED_NAMES: [&str; 28] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9", "COM¹", "COM²",
+    "COM³", "LPT¹", "LPT²", "LPT³",
+];
+
+/// The `normalize`, `resolve` and `relative` of Node.js's `path` module, giving the very same strings as Node.js v20, quirks included, for tools that replace Node.js-based pipelines and need exact parity.
+///
+/// [`SugarPath`](crate::SugarPath) follows Node.js too, but works on [`Path`](std::path::Path)s the way Rust does: `resolve` keeps a trailing separator, `relative` returns `.` rather than `""` for equal paths, and Windows paths follow the rules of Windows rather than Node.js's reading of them. These methods work on strings instead, like Node.js does. [`NodePath::POSIX`] is `path.posix` and [`NodePath::WIN32`] is `path.win32`, whatever the host is.
+///
+/// Relative paths are resolved against the current directory as [`SugarPath::resolve`](crate::SugarPath::resolve) sees it. On Windows, a drive-relative path like `C:foo` is resolved against the current directory of its drive, read from the `=C:` environment variable as Node.js does.
+///
+/// ```rust
+/// use sugar_path::NodePath;
+///
+/// let posix = NodePath::POSIX;
+/// assert_eq!(posix.normalize(""), ".");
+/// assert_eq!(posix.normalize("a/../"), "./");
+/// assert_eq!(posix.resolve(["/app", "src/", "", "../lib/"]), "/app/lib");
+/// assert_eq!(posix.relative("/app/src", "/app/src"), "");
+/// assert_eq!(posix.relative("/app/src", "/app/lib/main.js"), "../lib/main.js");
+///
+/// let win32 = NodePath::WIN32;
+/// assert_eq!(win32.normalize("C:/app/./src/.."), "C:\\app");
+/// assert_eq!(win32.relative("C:\\APP\\src", "c:\\app\\lib"), "..\\lib");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodePath {
+    platform: Platform,
+}
+
+impl NodePath {
+    /// Node.js's `path.posix`.
+    pub const POSIX: NodePath = NodePath {
+        platform: Platform::Posix,
+    };
+    /// Node.js's `path.win32`.
+    pub const WIN32: NodePath = NodePath {
+        platform: Platform::Windows,
+    };
+
+    /// The `path` module of Node.js running on `platform`.
+    pub fn new(platform: Platform) -> Self {
+        NodePath { platform }
+    }
+
+    /// The platform whose `path` module this is.
+    pub fn platform(&self) -> Platform {
+        self.platform
+    }
+
+    /// Like `path.normalize(path)`.
+    pub fn normalize(&self, path: &str) -> String {
+        match self.platform {
+            Platform::Posix => posix_normalize(path),
+            Platform::Windows => win32_normalize(path),
+        }
+    }
+
+    /// Like `path.resolve(...paths)`, resolving from the last path back to the first one that is absolute, then against the current directory. Empty paths are skipped.
+    pub fn resolve<I>(&self, paths: I) -> String
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let paths = paths.into_iter().collect::<Vec<_>>();
+        let paths = paths.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        match self.platform {
+            Platform::Posix => posix_resolve(&paths),
+            Platform::Windows => win32_resolve(&paths),
+        }
+    }
+
+    /// Like `path.relative(from, to)`: the path that leads from `from` to `to` after resolving both, which is `""` if they are the same.
+    pub fn relative(&self, from: &str, to: &str) -> String {
+        match self.platform {
+            Platform::Posix => posix_relative(from, to),
+            Platform::Windows => win32_relative(from, to),
+        }
+    }
+}
+
+/// The `path` module of the [default platform](default_platform).
+impl Default for NodePath {
+    fn default() -> Self {
+        NodePath::new(default_platform())
+    }
+}
+
+fn is_posix_separator(b: u8) -> bool {
+    b == b'/'
+}
+
+fn is_separator(b: u8) -> bool {
+    b == b'/' || b == b'\\'
+}
+
+fn cwd() -> String {
+    CWD.to_string_lossy().into_owned()
+}
+
+/// Node.js's `normalizeString`: resolves the `.` and `..` in `path`, dropping its root and any trailing separator.
+///
+/// Node.js counts UTF-16 units where this counts bytes, which gives the same result as the counts are only compared against those of ASCII.
+fn normalize_string(
+    path: &str,
+    allow_above_root: bool,
+    separator: char,
+    is_separator: fn(u8) -> bool,
+) -> String {
+    let bytes = path.as_bytes();
+    let mut res = String::new();
+    let mut last_segment_len = 0;
+    // Right after the last separator.
+    let mut start = 0;
+    // `None` once the segment has anything but dots.
+    let mut dots = Some(0);
+    let mut code = 0;
+    for i in 0..=bytes.len() {
+        if i < bytes.len() {
+            code = bytes[i];
+        } else if is_separator(code) {
+            break;
+        } else {
+            code = b'/';
+        }
+        if !is_separator(code) {
+            dots = match dots {
+                Some(dots) if code == b'.' => Some(dots + 1),
+                _ => None,
+            };
+            continue;
+        }
+        if start == i || dots == Some(1) {
+            // Nothing to add.
+        } else if dots == Some(2) {
+            if res.len() < 2 || last_segment_len != 2 || !res.ends_with("..") {
+                if res.len() > 2 {
+                    match res.rfind(separator) {
+                        Some(index) => {
+                            res.truncate(index);
+                            last_segment_len =
+                                res.len() - res.rfind(separator).map_or(0, |i| i + 1);
+                        }
+                        None => {
+                            res.clear();
+                            last_segment_len = 0;
+                        }
+                    }
+                    start = i + 1;
+                    dots = Some(0);
+                    continue;
+                } else if !res.is_empty() {
+                    res.clear();
+                    last_segment_len = 0;
+                    start = i + 1;
+                    dots = Some(0);
+                    continue;
+                }
+            }
+            if allow_above_root {
+                if !res.is_empty() {
+                    res.push(separator);
+                }
+                res.push_str("..");
+                last_segment_len = 2;
+            }
+        } else {
+            if !res.is_empty() {
+                res.push(separator);
+            }
+            res.push_str(&path[start..i]);
+            last_segment_len = i - start;
+        }
+        start = i + 1;
+        dots = Some(0);
+    }
+    res
+}
+
+fn posix_normalize(path: &str) -> String {
+    if path.is_empty() {
+        return ".".to_string();
+    }
+    let is_absolute = path.starts_with('/');
+    let trailing_separator = path.ends_with('/');
+    let mut path = normalize_string(path, !is_absolute, '/', is_posix_separator);
+    if path.is_empty() {
+        return match (is_absolute, trailing_separator) {
+            (true, _) => "/",
+            (false, true) => "./",
+            (false, false) => ".",
+        }
+        .to_string();
+    }
+    if trailing_separator {
+        path.push('/');
+    }
+    if is_absolute {
+        path.insert(0, '/');
+    }
+    path
+}
+
+fn posix_cwd() -> String {
+    let cwd = cwd();
+    if cfg!(target_family = "windows") {
+        // Without the drive, like Node.js does.
+        let cwd = cwd.replace('\\', "/");
+        return match cwd.find('/') {
+            Some(index) => cwd[index..].to_string(),
+            // `slice(-1)`.
+            None => cwd
+                .chars()
+                .next_back()
+                .map(String::from)
+                .unwrap_or_default(),
+        };
+    }
+    cwd
+}
+
+fn posix_resolve(paths: &[&str]) -> String {
+    let mut resolved = String::new();
+    let mut is_absolute = false;
+    for path in paths.iter().rev() {
+        if is_absolute {
+            break;
+        }
+        if path.is_empty() {
+            continue;
+        }
+        resolved = format!("{}/{}", path, resolved);
+        is_absolute = path.starts_with('/');
+    }
+    if !is_absolute {
+        let cwd = posix_cwd();
+        resolved = format!("{}/{}", cwd, resolved);
+        is_absolute = cwd.starts_with('/');
+    }
+    let resolved = normalize_string(&resolved, !is_absolute, '/', is_posix_separator);
+    if is_absolute {
+        format!("/{}", resolved)
+    } else if resolved.is_empty() {
+        ".".to_string()
+    } else {
+        resolved
+    }
+}
+
+fn posix_relative(from: &str, to: &str) -> String {
+    if from == to {
+        return String::new();
+    }
+    let (from, to) = (posix_resolve(&[from]), posix_resolve(&[to]));
+    if from == to {
+        return String::new();
+    }
+    // Both start with `/`, which is skipped, and the indices are from there.
+    let (from_bytes, to_bytes) = (&from.as_bytes()[1..], &to.as_bytes()[1..]);
+    let length = from_bytes.len().min(to_bytes.len());
+    let mut last_common_sep = None;
+    let mut i = 0;
+    while i < length && from_bytes[i] == to_bytes[i] {
+        if from_bytes[i] == b'/' {
+            last_common_sep = Some(i);
+        }
+        i += 1;
+    }
+    if i == length {
+        if to_bytes.len() > length {
+            if to_bytes[i] == b'/' {
+                // `from` is a parent of `to`.
+                return to[1 + i + 1..].to_string();
+            }
+            if i == 0 {
+                // `from` is the root.
+                return to[1..].to_string();
+            }
+        } else if from_bytes.len() > length {
+            if from_bytes[i] == b'/' {
+                // `to` is a parent of `from`.
+                last_common_sep = Some(i);
+            } else if i == 0 {
+                // `to` is the root.
+                last_common_sep = Some(0);
+            }
+        }
+    }
+    let mut out = ups(
+        from_bytes,
+        last_common_sep.map_or(0, |sep| sep + 1),
+        b'/',
+        "/",
+    );
+    // The rest of `to` after what they have in common, starting with a separator.
+    out.push_str(&to[last_common_sep.map_or(0, |sep| sep + 1)..]);
+    out
+}
+
+/// A `..` for every name of `from` from `start` on, where names end with `separator`, joined with `joiner`.
+fn ups<T: PartialEq>(from: &[T], start: usize, separator: T, joiner: &str) -> String {
+    if start > from.len() {
+        return String::new();
+    }
+    let names = 1 + from[start..]
+        .iter()
+        .filter(|unit| **unit == separator)
+        .count();
+    vec![".."; names].join(joiner)
+}
+
+/// Like `path.slice(0, colon_index).toUpperCase()` being one of the reserved device names, where a missing colon is `-1` and cuts off the last UTF-16 unit.
+fn is_reserved_name(path: &str, colon_index: Option<usize>) -> bool {
+    let device = match colon_index {
+        Some(index) => &path[..index],
+        None => match path.chars().next_back() {
+            Some(last) if last.len_utf16() == 1 => &path[..path.len() - last.len_utf8()],
+            // Half of a surrogate pair or nothing is left, neither of which is a name.
+            _ => return false,
+        },
+    };
+    RESERVED_NAMES.contains(&device.to_uppercase().as_str())
+}
+
+/// How a Windows path starts, as Node.js reads it.
+struct Root {
+    device: Option<String>,
+    /// Where the root ends.
+    end: usize,
+    is_absolute: bool,
+    /// The path is nothing but a UNC share like `\\server\share`.
+    is_unc_only: bool,
+}
+
+/// Reads a UNC or device root like `\\server\share` or `\\.\COM1`, or just a separator, at the start of `path`.
+fn separator_root(path: &str) -> Root {
+    let bytes = path.as_bytes();
+    let len = bytes.len();
+    let mut root = Root {
+        device: None,
+        end: 0,
+        is_absolute: true,
+        is_unc_only: false,
+    };
+    if !bytes.get(1).copied().is_some_and(is_separator) {
+        root.end = 1;
+        return root;
+    }
+    let mut j = 2;
+    let mut last = j;
+    while j < len && !is_separator(bytes[j]) {
+        j += 1;
+    }
+    if j == len || j == last {
+        return root;
+    }
+    let first_part = &path[last..j];
+    last = j;
+    while j < len && is_separator(bytes[j]) {
+        j += 1;
+    }
+    if j == len || j == last {
+        return root;
+    }
+    last = j;
+    while j < len && !is_separator(bytes[j]) {
+        j += 1;
+    }
+    if first_part == "." || first_part == "?" {
+        root.device = Some(format!("\\\\{}", first_part));
+        root.end = 4;
+    } else {
+        root.device = Some(format!("\\\\{}\\{}", first_part, &path[last..j]));
+        root.end = j;
+        root.is_unc_only = j == len;
+    }
+    root
+}
+
+fn drive_root(path: &str) -> Root {
+    let bytes = path.as_bytes();
+    let is_absolute = bytes.len() > 2 && is_separator(bytes[2]);
+    Root {
+        device: Some(path[..2].to_string()),
+        end: if is_absolute { 3 } else { 2 },
+        is_absolute,
+        is_unc_only: false,
+    }
+}
+
+fn is_drive(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+fn win32_normalize(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let len = bytes.len();
+    if len == 0 {
+        return ".".to_string();
+    }
+    if len == 1 {
+        return if bytes[0] == b'/' { "\\" } else { path }.to_string();
+    }
+    let colon_index = path.find(':');
+    let root = if is_separator(bytes[0]) {
+        let root = separator_root(path);
+        if root.is_unc_only {
+            return format!("{}\\", root.device.unwrap_or_default());
+        }
+        root
+    } else if colon_index == Some(1) && is_drive(path) {
+        drive_root(path)
+    } else {
+        match colon_index {
+            Some(index) if index > 0 && is_reserved_name(path, colon_index) => Root {
+                device: Some(path[..index + 1].to_string()),
+                end: index + 1,
+                is_absolute: false,
+                is_unc_only: false,
+            },
+            _ => Root {
+                device: None,
+                end: 0,
+                is_absolute: false,
+                is_unc_only: false,
+            },
+        }
+    };
+    let mut tail = if root.end < len {
+        normalize_string(&path[root.end..], !root.is_absolute, '\\', is_separator)
+    } else {
+        String::new()
+    };
+    if tail.is_empty() && !root.is_absolute {
+        tail.push('.');
+    }
+    if !tail.is_empty() && is_separator(bytes[len - 1]) {
+        tail.push('\\');
+    }
+    if !root.is_absolute && root.device.is_none() && colon_index.is_some() {
+        // The tail mustn't read as a drive or a stream, like `C:` out of `a\..\C:`.
+        if is_drive(&tail) {
+            return format!(".\\{}", tail);
+        }
+        let ends_a_name = path
+            .match_indices(':')
+            .any(|(index, _)| bytes.get(index + 1).copied().is_none_or(is_separator));
+        if ends_a_name {
+            return format!(".\\{}", tail);
+        }
+    }
+    let device = root.device.unwrap_or_default();
+    if is_reserved_name(path, colon_index) {
+        return format!(".\\{}{}", device, tail);
+    }
+    if root.is_absolute {
+        format!("{}\\{}", device, tail)
+    } else {
+        format!("{}{}", device, tail)
+    }
+}
+
+fn win32_resolve(paths: &[&str]) -> String {
+    let mut resolved_device = String::new();
+    let mut resolved_tail = String::new();
+    let mut resolved_absolute = false;
+    // Down to `0` for the current directory.
+    for i in (0..=paths.len()).rev() {
+        let path: Cow<str> = if i > 0 {
+            match paths[i - 1] {
+                "" => continue,
+                path => Cow::Borrowed(path),
+            }
+        } else if resolved_device.is_empty() {
+            Cow::Owned(cwd())
+        } else {
+            // The current directory of the drive, if there is one.
+            let path = env::var(format!("={}", resolved_device))
+                .ok()
+                .filter(|path| !path.is_empty() && cfg!(target_family = "windows"))
+                .unwrap_or_else(cwd);
+            let same_drive = path
+                .as_bytes()
+                .get(..2)
+                .is_some_and(|drive| drive.eq_ignore_ascii_case(resolved_device.as_bytes()));
+            if !same_drive && path.as_bytes().get(2) == Some(&b'\\') {
+                Cow::Owned(format!("{}\\", resolved_device))
+            } else {
+                Cow::Owned(path)
+            }
+        };
+        let root = match path.as_bytes() {
+            [first] if is_separator(*first) => Root {
+                device: None,
+                end: 1,
+                is_absolute: true,
+                is_unc_only: false,
+            },
+            [first, _, ..] if is_separator(*first) => separator_root(&path),
+            _ if is_drive(&path) => drive_root(&path),
+            _ => Root {
+                device: None,
+                end: 0,
+                is_absolute: false,
+                is_unc_only: false,
+            },
+        };
+        if let Some(device) = root.device {
+            if resolved_device.is_empty() {
+                resolved_device = device;
+            } else if device.to_lowercase() != resolved_device.to_lowercase() {
+                // Another device, which doesn't apply.
+                continue;
+            }
+        }
+        if resolved_absolute {
+            if !resolved_device.is_empty() {
+                break;
+            }
+        } else {
+            resolved_tail = format!("{}\\{}", &path[root.end..], resolved_tail);
+            resolved_absolute = root.is_absolute;
+            if resolved_absolute && !resolved_device.is_empty() {
+                break;
+            }
+        }
+    }
+    let tail = normalize_string(&resolved_tail, !resolved_absolute, '\\', is_separator);
+    if resolved_absolute {
+        format!("{}\\{}", resolved_device, tail)
+    } else if resolved_device.is_empty() && tail.is_empty() {
+        ".".to_string()
+    } else {
+        format!("{}{}", resolved_device, tail)
+    }
+}
+
+fn win32_relative(from: &str, to: &str) -> String {
+    if from == to {
+        return String::new();
+    }
+    let (from_orig, to_orig) = (win32_resolve(&[from]), win32_resolve(&[to]));
+    if from_orig == to_orig {
+        return String::new();
+    }
+    let (from, to) = (from_orig.to_lowercase(), to_orig.to_lowercase());
+    if from == to {
+        return String::new();
+    }
+    let utf16_len = |s: &str| s.encode_utf16().count();
+    if utf16_len(&from_orig) != utf16_len(&from) || utf16_len(&to_orig) != utf16_len(&to) {
+        // Lowercasing changed the length, so the names are compared instead of the units.
+        fn split(path: &str) -> Vec<&str> {
+            let mut names = path.split('\\').collect::<Vec<_>>();
+            if names.last() == Some(&"") {
+                names.pop();
+            }
+            names
+        }
+        let (from_names, to_names) = (split(&from_orig), split(&to_orig));
+        let length = from_names.len().min(to_names.len());
+        let i = (0..length)
+            .find(|i| from_names[*i].to_lowercase() != to_names[*i].to_lowercase())
+            .unwrap_or(length);
+        if i == 0 {
+            return to_orig;
+        }
+        if i == length {
+            if to_names.len() > length {
+                return to_names[i..].join("\\");
+            }
+            if from_names.len() > length {
+                return "..\\".repeat(from_names.len() - 1 - i) + "..";
+            }
+            return String::new();
+        }
+        return "..\\".repeat(from_names.len() - i) + &to_names[i..].join("\\");
+    }
+
+    // Node.js indexes UTF-16 units, which the lowercase paths share with the original ones.
+    const BACKSLASH: u16 = b'\\' as u16;
+    let from = from.encode_utf16().collect::<Vec<_>>();
+    let to = to.encode_utf16().collect::<Vec<_>>();
+    let to_units = to_orig.encode_utf16().collect::<Vec<_>>();
+    let slice = |start: usize, end: usize| {
+        String::from_utf16_lossy(to_units.get(start..end.min(to_units.len())).unwrap_or(&[]))
+    };
+    let trim = |path: &[u16]| {
+        let start = path.iter().take_while(|unit| **unit == BACKSLASH).count();
+        let mut end = path.len();
+        // Only a UNC root ends with a backslash.
+        while end > start + 1 && path[end - 1] == BACKSLASH {
+            end -= 1;
+        }
+        (start, end)
+    };
+    let ((from_start, from_end), (to_start, to_end)) = (trim(&from), trim(&to));
+    let (from_len, to_len) = (from_end - from_start, to_end - to_start);
+    let length = from_len.min(to_len);
+    let mut last_common_sep = None;
+    let mut i = 0;
+    while i < length && from[from_start + i] == to[to_start + i] {
+        if from[from_start + i] == BACKSLASH {
+            last_common_sep = Some(i);
+        }
+        i += 1;
+    }
+    let last_common_sep = if i != length {
+        match last_common_sep {
+            Some(sep) => sep,
+            // Not even the root is the same.
+            None => return to_orig,
+        }
+    } else {
+        if to_len > length {
+            if to[to_start + i] == BACKSLASH {
+                // `from` is a parent of `to`.
+                return slice(to_start + i + 1, to_units.len());
+            }
+            if i == 2 {
+                // `from` is the root of a drive.
+                return slice(to_start + i, to_units.len());
+            }
+        }
+        if from_len > length {
+            if from[from_start + i] == BACKSLASH {
+                // `to` is a parent of `from`.
+                last_common_sep = Some(i);
+            } else if i == 2 {
+                // `to` is the root of a drive.
+                last_common_sep = Some(3);
+            }
+        }
+        last_common_sep.unwrap_or(0)
+    };
+    let out = ups(
+        &from[..from_end],
+        from_start + last_common_sep + 1,
+        BACKSLASH,
+        "\\",
+    );
+    let mut to_start = to_start + last_common_sep;
+    if !out.is_empty() {
+        return out + &slice(to_start, to_end);
+    }
+    if to_units.get(to_start) == Some(&BACKSLASH) {
+        to_start += 1;
+    }
+    slice(to_start, to_end)
+}
@@ -0,0 +1,94 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::{has_trailing_separator, os_str, path_buf::push_component, Platform};
+
+/// Normalizes a path that arrives one segment at a time, e.g. from an archive reader or a network protocol.
+///
+/// Feeding segments and calling [`finish`](Normalizer::finish) gives the same result as joining the segments with a separator and calling [`normalize`](crate::SugarPath::normalize), without materializing the joined path.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::Normalizer;
+///
+/// let mut normalizer = Normalizer::new();
+/// assert!(normalizer.feed("a"));
+/// assert!(normalizer.feed("b/../c"));
+/// assert!(!normalizer.feed("../../.."));
+/// assert!(normalizer.has_escaped());
+/// assert_eq!(normalizer.finish(), Path::new(".."));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Normalizer {
+    path: PathBuf,
+    started: bool,
+    escaped: bool,
+    trailing_separator: bool,
+}
+
+impl Normalizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts from `path` cleared, reusing its allocation.
+    pub(crate) fn with_buffer(mut path: PathBuf) -> Self {
+        path.clear();
+        Normalizer {
+            path,
+            ..Self::default()
+        }
+    }
+
+    /// Appends a segment, which may itself contain separators.
+    ///
+    /// A root or prefix is only honored at the start of the first segment. Anywhere else a root is just a separator, and a prefix is read as the normal components it is made of.
+    ///
+    /// Returns `false` if a `..` in the segment escaped the accumulated path: either it went above the root of an absolute path (and was dropped), or above the start of a relative one (and was kept, as `normalize` does).
+    pub fn feed(&mut self, segment: impl AsRef<Path>) -> bool {
+        let segment = segment.as_ref();
+        if !segment.as_os_str().is_empty() {
+            self.trailing_separator = has_trailing_separator(segment);
+        }
+        let mut contained = true;
+        for component in segment.components() {
+            match component {
+                Component::RootDir if self.started => {}
+                Component::Prefix(prefix) if self.started => {
+                    let names =
+                        os_str::split(prefix.as_os_str(), |b| Platform::Windows.is_separator(b));
+                    for name in names {
+                        push_component(&mut self.path, Component::Normal(name));
+                    }
+                }
+                _ => contained &= push_component(&mut self.path, component),
+            }
+            self.started = true;
+        }
+        self.escaped |= !contained;
+        contained
+    }
+
+    /// Whether any fed segment escaped the accumulated path.
+    pub fn has_escaped(&self) -> bool {
+        self.escaped
+    }
+
+    /// The path accumulated so far. It is empty until a segment is fed.
+    pub fn as_path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the normalized path. Like `normalize`, an empty path becomes `.`, and a trailing separator on the last segment is kept.
+    pub fn finish(mut self) -> PathBuf {
+        if matches!(
+            self.path.components().next_back(),
+            None | Some(Component::Prefix(_))
+        ) {
+            self.path.push(".");
+        }
+        if self.trailing_separator {
+            self.path.push("");
+        }
+        self.path
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    borrow::Cow,
+    ffi::OsString,
+    path::{Component, Path, PathBuf, Prefix},
+};
+
+use crate::SugarPath;
+
+/// Tweaks to [`normalize`](SugarPath::normalize) and [`resolve`](SugarPath::resolve) for the cases where the defaults, which follow Node.js, don't fit.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::NormalizeOptions;
+///
+/// let options = NormalizeOptions::new().preserve_double_root(true);
+/// #[cfg(target_family = "unix")]
+/// assert_eq!(options.normalize("//net/a/../b").as_os_str(), "//net/b");
+/// assert_eq!(options.normalize("a/./b"), Path::new("a/b"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NormalizeOptions {
+    preserve_double_root: bool,
+    drive_letter_case: DriveLetterCase,
+    backslash_as_separator: bool,
+}
+
+/// How [`NormalizeOptions`] writes the letter of a Windows drive, like `C:` or `\\?\C:`.
+///
+/// Drive letters don't depend on case, and comparisons like [`SugarPath::relative`] and [`SugarPath::path_eq`] always ignore it. This only decides how they are written, so paths built from different sources print and hash the same.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DriveLetterCase {
+    /// Keeps the letter as it was written.
+    #[default]
+    Preserve,
+    /// Uppercases the letter, like `GetFullPathNameW` and the Windows shell do.
+    Upper,
+    /// Lowercases the letter, like VS Code does in file URIs.
+    Lower,
+}
+
+impl NormalizeOptions {
+    /// The default options, under which `normalize` and `resolve` behave like the methods of [`SugarPath`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps a leading `//` on POSIX instead of collapsing it to `/`.
+    ///
+    /// POSIX leaves the meaning of a path that starts with exactly two slashes up to the implementation, and some (Cygwin, for one) use it for network roots. Three or more slashes still collapse to one. On Windows a leading `//` is a UNC prefix and is always kept, so this has no effect there.
+    pub fn preserve_double_root(mut self, preserve: bool) -> Self {
+        self.preserve_double_root = preserve;
+        self
+    }
+
+    /// Rewrites the drive letter of a Windows path following `case`. Defaults to [`DriveLetterCase::Preserve`].
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::{DriveLetterCase, NormalizeOptions};
+    ///
+    /// let options = NormalizeOptions::new().drive_letter_case(DriveLetterCase::Upper);
+    /// #[cfg(target_family = "windows")]
+    /// assert_eq!(options.normalize("c:\\a").as_os_str(), "C:\\a");
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(options.normalize("c:/a").as_os_str(), "c:/a");
+    /// ```
+    pub fn drive_letter_case(mut self, case: DriveLetterCase) -> Self {
+        self.drive_letter_case = case;
+        self
+    }
+
+    /// Reads `\` as a separator on POSIX too, instead of as part of a name. Defaults to `false`.
+    ///
+    /// This is for paths written on Windows, like `src\lib.rs` in a config file, that are read on POSIX. A backslash can't be used in a name then. On Windows `\` is always a separator, so this has no effect there.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use sugar_path::NormalizeOptions;
+    ///
+    /// let options = NormalizeOptions::new().backslash_as_separator(true);
+    /// assert_eq!(options.normalize("src\\bin\\..\\lib.rs"), Path::new("src/lib.rs"));
+    /// ```
+    pub fn backslash_as_separator(mut self, enabled: bool) -> Self {
+        self.backslash_as_separator = enabled;
+        self
+    }
+
+    /// Normalizes `path` like [`SugarPath::normalize`], following these options.
+    pub fn normalize(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path = self.replace_backslashes(path.as_ref());
+        self.apply(&path, path.normalize())
+    }
+
+    /// Resolves `path` like [`SugarPath::resolve`], following these options.
+    pub fn resolve(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path = self.replace_backslashes(path.as_ref());
+        self.apply(&path, path.resolve())
+    }
+
+    fn replace_backslashes<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let bytes = path.as_os_str().as_encoded_bytes();
+        if !self.backslash_as_separator
+            || cfg!(target_family = "windows")
+            || !bytes.contains(&b'\\')
+        {
+            return Cow::Borrowed(path);
+        }
+        let bytes = bytes
+            .iter()
+            .map(|b| if *b == b'\\' { b'/' } else { *b })
+            .collect::<Vec<_>>();
+        // SAFETY: only ASCII bytes were replaced with ASCII bytes, which keeps the bytes valid.
+        Cow::Owned(PathBuf::from(unsafe {
+            OsString::from_encoded_bytes_unchecked(bytes)
+        }))
+    }
+
+    fn apply(&self, original: &Path, normalized: PathBuf) -> PathBuf {
+        let normalized = self.keep_double_root(original, normalized);
+        self.recase_drive_letter(normalized)
+    }
+
+    fn recase_drive_letter(&self, path: PathBuf) -> PathBuf {
+        let index = match path.components().next() {
+            Some(Component::Prefix(prefix)) => match prefix.kind() {
+                Prefix::Disk(_) => 0,
+                // `\\?\C:`
+                Prefix::VerbatimDisk(_) => 4,
+                _ => return path,
+            },
+            _ => return path,
+        };
+        let mut bytes = path.into_os_string().into_encoded_bytes();
+        match self.drive_letter_case {
+            DriveLetterCase::Preserve => {}
+            DriveLetterCase::Upper => bytes[index].make_ascii_uppercase(),
+            DriveLetterCase::Lower => bytes[index].make_ascii_lowercase(),
+        }
+        // SAFETY: only the case of an ASCII letter changed, which keeps the bytes valid.
+        PathBuf::from(unsafe { OsString::from_encoded_bytes_unchecked(bytes) })
+    }
+
+    fn keep_double_root(&self, original: &Path, normalized: PathBuf) -> PathBuf {
+        if !self.preserve_double_root || cfg!(target_family = "windows") {
+            return normalized;
+        }
+        match original.as_os_str().as_encoded_bytes() {
+            [b'/', b'/', rest @ ..] if rest.first() != Some(&b'/') => {
+                let mut ret = OsString::from("/");
+                ret.push(normalized);
+                PathBuf::from(ret)
+            }
+            _ => normalized,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::ffi::{OsStr, OsString};
+
+/// Splits `s` on every byte matched by `is_separator`, skipping empty pieces.
+///
+/// Only ASCII bytes are ever treated as separators, which keeps every piece a valid `OsStr`.
+pub(crate) fn split(s: &OsStr, is_separator: impl Fn(u8) -> bool) -> impl Iterator<Item = &OsStr> {
+    s.as_encoded_bytes()
+        .split(move |b| b.is_ascii() && is_separator(*b))
+        .filter(|piece| !piece.is_empty())
+        // SAFETY: the pieces were split on ASCII bytes, which is allowed by `from_encoded_bytes_unchecked`.
+        .map(|piece| unsafe { OsStr::from_encoded_bytes_unchecked(piece) })
+}
+
+/// Turns a slice of the encoded bytes of an `OsStr` back into an `OsStr`.
+///
+/// Callers only cut right before or after an ASCII byte or a whole UTF-8 character, which `from_encoded_bytes_unchecked` allows.
+pub(crate) fn from_encoded_bytes(bytes: &[u8]) -> &OsStr {
+    // SAFETY: see above, every caller cuts on one of those boundaries.
+    unsafe { OsStr::from_encoded_bytes_unchecked(bytes) }
+}
+
+/// The length of `s` in UTF-16 code units, as Windows counts it.
+pub(crate) fn utf16_len(s: &OsStr) -> usize {
+    #[cfg(target_family = "windows")]
+    {
+        use std::os::windows::ffi::OsStrExt;
+        s.encode_wide().count()
+    }
+    #[cfg(not(target_family = "windows"))]
+    {
+        s.to_string_lossy().encode_utf16().count()
+    }
+}
+
+/// Decodes the `%XX` escapes of `s`. On failure, returns the byte offset of the malformed escape.
+pub(crate) fn percent_decode(s: &str) -> Result<Vec<u8>, usize> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        if b == b'%' {
+            let offset = s.len() - rest.len();
+            let value = tail
+                .get(..2)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or(offset)?;
+            bytes.push(value);
+            rest = &tail[2..];
+        } else {
+            bytes.push(b);
+            rest = tail;
+        }
+    }
+    Ok(bytes)
+}
+
+/// Turns raw bytes into an `OsString`. Only Unix can hold bytes that aren't valid UTF-8.
+pub(crate) fn from_bytes(bytes: Vec<u8>) -> Option<OsString> {
+    #[cfg(target_family = "unix")]
+    {
+        use std::os::unix::ffi::OsStringExt;
+        Some(OsString::from_vec(bytes))
+    }
+    #[cfg(not(target_family = "unix"))]
+    {
+        String::from_utf8(bytes).ok().map(OsString::from)
+    }
+}
@@ -0,0 +1,53 @@
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::{default_platform, with_default_platform, Pattern, SugarPath};
+
+/// Normalizes every path on the rayon thread pool, see [`SugarPath::normalize`]. The results are in the order of `paths`.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::par_normalize_many;
+///
+/// assert_eq!(
+///     par_normalize_many(&["a/./b", "a/../c"]),
+///     [Path::new("a/b"), Path::new("c")]
+/// );
+/// ```
+pub fn par_normalize_many<P: AsRef<Path> + Sync>(paths: &[P]) -> Vec<PathBuf> {
+    // Worker threads don't see the default platform of this one.
+    let platform = default_platform();
+    paths
+        .par_iter()
+        .map(|path| with_default_platform(platform, || path.as_ref().normalize()))
+        .collect()
+}
+
+/// Resolves every path against the current working directory on the rayon thread pool, see [`SugarPath::resolve`]. The results are in the order of `paths`.
+pub fn par_resolve_many<P: AsRef<Path> + Sync>(paths: &[P]) -> Vec<PathBuf> {
+    let platform = default_platform();
+    paths
+        .par_iter()
+        .map(|path| with_default_platform(platform, || path.as_ref().resolve()))
+        .collect()
+}
+
+/// Keeps the paths whose normalized form matches `pattern`, checking them on the rayon thread pool, see [`Pattern::matches`]. The kept paths are in the order of `paths`.
+///
+/// ```rust
+/// use sugar_path::{par_filter_matches, Pattern};
+///
+/// let pattern = Pattern::new("src/**/*.rs").unwrap();
+/// let paths = ["src/lib.rs", "README.md", "./src/a/b.rs"];
+/// assert_eq!(par_filter_matches(&paths, &pattern), [&"src/lib.rs", &"./src/a/b.rs"]);
+/// ```
+pub fn par_filter_matches<'a, P: AsRef<Path> + Sync>(
+    paths: &'a [P],
+    pattern: &Pattern,
+) -> Vec<&'a P> {
+    paths
+        .par_iter()
+        .filter(|path| pattern.matches(path))
+        .collect()
+}
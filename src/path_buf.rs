@@ -0,0 +1,176 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::{flavor, has_trailing_separator, SugarPath};
+
+/// Sugar methods for building a [`PathBuf`] in place.
+pub trait SugarPathBuf {
+    /// Removes the last component of the normalized path, i.e. moves to its parent directory.
+    ///
+    /// Unlike [`PathBuf::pop`], `..` and `.` are resolved first, so popping `a/b/..` yields `.` rather than `a/b`. Walking up from a relative path that has no components left appends a `..`. The path is left normalized.
+    ///
+    /// Returns `false` and leaves the path unchanged if it is a root.
+    ///
+    /// ```rust
+    /// use std::path::{Path, PathBuf};
+    /// use sugar_path::SugarPathBuf;
+    ///
+    /// let mut path = PathBuf::from("a/b/../c");
+    /// assert!(path.pop_normalized());
+    /// assert_eq!(path, Path::new("a"));
+    /// assert!(path.pop_normalized());
+    /// assert_eq!(path, Path::new("."));
+    /// assert!(path.pop_normalized());
+    /// assert_eq!(path, Path::new(".."));
+    ///
+    /// #[cfg(target_family = "unix")]
+    /// assert!(!PathBuf::from("/").pop_normalized());
+    /// ```
+    fn pop_normalized(&mut self) -> bool;
+
+    /// Appends `path`, resolving its `.` and `..` segments and duplicate separators along the way.
+    ///
+    /// `self` must already be normalized, e.g. built with [`SugarPath::normalize`] or with this method. Only the appended segment is processed, so the cost doesn't grow with the length of `self`. An empty `self` is treated as `.`.
+    ///
+    /// Unlike [`PathBuf::push`], an absolute `path` doesn't replace `self`: its root and prefix are ignored and the rest is appended. Use [`push_normalized_allow_absolute`](SugarPathBuf::push_normalized_allow_absolute) to opt into replacing.
+    ///
+    /// ```rust
+    /// use std::path::{Path, PathBuf};
+    /// use sugar_path::SugarPathBuf;
+    ///
+    /// let mut path = PathBuf::from("a/b");
+    /// path.push_normalized("../c//./d");
+    /// assert_eq!(path, Path::new("a/c/d"));
+    /// path.push_normalized("/etc");
+    /// assert_eq!(path, Path::new("a/c/d/etc"));
+    /// ```
+    fn push_normalized(&mut self, path: impl AsRef<Path>);
+
+    /// Like [`push_normalized`](SugarPathBuf::push_normalized), but an absolute `path` replaces `self` the same way it does with [`PathBuf::push`].
+    ///
+    /// ```rust
+    /// use std::path::{Path, PathBuf};
+    /// use sugar_path::SugarPathBuf;
+    ///
+    /// let mut path = PathBuf::from("a/b");
+    /// path.push_normalized_allow_absolute("/etc/../var");
+    /// #[cfg(target_family = "unix")]
+    /// assert_eq!(path, Path::new("/var"));
+    /// ```
+    fn push_normalized_allow_absolute(&mut self, path: impl AsRef<Path>);
+}
+
+impl SugarPathBuf for PathBuf {
+    fn pop_normalized(&mut self) -> bool {
+        if let Some(platform) = flavor::overridden() {
+            return match flavor::pop_normalized_as(self, platform) {
+                Some(popped) => {
+                    *self = popped;
+                    true
+                }
+                None => false,
+            };
+        }
+        let mut normalized = self.normalize();
+        if matches!(
+            normalized.components().next_back(),
+            Some(Component::RootDir)
+        ) {
+            return false;
+        }
+        push_component(&mut normalized, Component::ParentDir);
+        *self = normalized;
+        true
+    }
+
+    fn push_normalized(&mut self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        if let Some(platform) = flavor::overridden() {
+            *self = flavor::push_normalized_as(self, path, false, platform);
+            return;
+        }
+        for component in path.components() {
+            if !matches!(component, Component::Prefix(_) | Component::RootDir) {
+                push_component(self, component);
+            }
+        }
+        carry_trailing_separator(self, path);
+    }
+
+    fn push_normalized_allow_absolute(&mut self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        if let Some(platform) = flavor::overridden() {
+            *self = flavor::push_normalized_as(self, path, true, platform);
+            return;
+        }
+        let mut components = path.components().peekable();
+        if let Some(Component::Prefix(_)) = components.peek() {
+            *self = path.normalize();
+            return;
+        }
+        if path.has_root() {
+            // Like `PathBuf::push`, a root without a prefix keeps the prefix of `self`.
+            let prefix = self
+                .components()
+                .next()
+                .filter(|component| matches!(component, Component::Prefix(_)))
+                .map(|prefix| prefix.as_os_str().to_os_string());
+            self.clear();
+            if let Some(prefix) = prefix {
+                self.push(prefix);
+            }
+        }
+        for component in components {
+            push_component(self, component);
+        }
+        carry_trailing_separator(self, path);
+    }
+}
+
+/// Gives `buf` a trailing separator if `pushed` has one, or is empty like `PathBuf::push("")`, and removes it otherwise.
+fn carry_trailing_separator(buf: &mut PathBuf, pushed: &Path) {
+    if pushed.as_os_str().is_empty() || has_trailing_separator(pushed) {
+        buf.push("");
+    } else if has_trailing_separator(buf)
+        && !matches!(buf.components().next_back(), Some(Component::RootDir))
+    {
+        *buf = buf.components().collect();
+    }
+}
+
+/// Appends a single component to `buf`, which must be normalized, keeping it normalized.
+///
+/// Returns `false` if the component is a `..` that couldn't cancel out a normal component, either because it was absorbed by a root or because it went above the start of a relative path.
+pub(crate) fn push_component(buf: &mut PathBuf, component: Component) -> bool {
+    match component {
+        Component::CurDir => true,
+        Component::Normal(_) | Component::RootDir | Component::Prefix(_) => {
+            if matches!(buf.components().next_back(), Some(Component::CurDir)) {
+                buf.pop();
+            }
+            buf.push(component);
+            true
+        }
+        Component::ParentDir => match buf.components().next_back() {
+            Some(Component::Normal(_)) => {
+                buf.pop();
+                if matches!(
+                    buf.components().next_back(),
+                    None | Some(Component::Prefix(_))
+                ) {
+                    buf.push(".");
+                }
+                true
+            }
+            Some(Component::RootDir) => false,
+            Some(Component::CurDir) => {
+                buf.pop();
+                buf.push("..");
+                false
+            }
+            Some(Component::ParentDir | Component::Prefix(_)) | None => {
+                buf.push("..");
+                false
+            }
+        },
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use crate::{FileSystem, StdFileSystem, SugarPath};
+
+/// The markers [`ProjectRoots`] looks for by default.
+pub const DEFAULT_PROJECT_MARKERS: &[&str] =
+    &[".git", "Cargo.toml", "package.json", "pyproject.toml"];
+
+/// Finds the root of the project a path belongs to: its nearest ancestor directory, itself included, that has one of a set of marker entries, like `.git` or `Cargo.toml`.
+///
+/// Results are cached per directory, so looking up many files of the same tree only queries the filesystem once per directory. Use [`clear_cache`](ProjectRoots::clear_cache) after the tree changes.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{ProjectRoots, VirtualTree};
+///
+/// let tree: VirtualTree = ["/repo/.git/HEAD", "/repo/crates/a/Cargo.toml", "/repo/crates/a/src/lib.rs"]
+///     .into_iter()
+///     .collect();
+/// let mut roots = ProjectRoots::with_file_system(&tree);
+/// #[cfg(target_family = "unix")]
+/// {
+///     assert_eq!(roots.find("/repo/crates/a/src/lib.rs").unwrap(), Path::new("/repo/crates/a"));
+///     assert_eq!(roots.project_relative("/repo/crates/a/src/lib.rs").unwrap(), Path::new("src/lib.rs"));
+///
+///     let mut roots = ProjectRoots::with_file_system(&tree).markers([".git"]);
+///     assert_eq!(roots.find("/repo/crates/a/src/lib.rs").unwrap(), Path::new("/repo"));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProjectRoots<F = StdFileSystem> {
+    fs: F,
+    markers: Vec<OsString>,
+    cache: HashMap<PathBuf, Option<PathBuf>>,
+}
+
+impl ProjectRoots {
+    /// Looks for [`DEFAULT_PROJECT_MARKERS`] on the real filesystem.
+    pub fn new() -> Self {
+        Self::with_file_system(StdFileSystem)
+    }
+}
+
+impl Default for ProjectRoots {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: FileSystem> ProjectRoots<F> {
+    /// Looks for [`DEFAULT_PROJECT_MARKERS`] on `fs`.
+    pub fn with_file_system(fs: F) -> Self {
+        Self {
+            fs,
+            markers: DEFAULT_PROJECT_MARKERS.iter().map(OsString::from).collect(),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Replaces the markers. An entry of any kind with one of these names marks a project root.
+    pub fn markers<I>(mut self, markers: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<OsString>,
+    {
+        self.markers = markers.into_iter().map(Into::into).collect();
+        self.cache.clear();
+        self
+    }
+
+    /// The root of the project `path` belongs to, resolved against the current working directory, or `None` if no ancestor has a marker.
+    pub fn find(&mut self, path: impl AsRef<Path>) -> Option<PathBuf> {
+        let path = path.as_ref().resolve();
+        let mut visited = vec![];
+        let mut root = None;
+        for dir in path.ancestors() {
+            if let Some(cached) = self.cache.get(dir) {
+                root = cached.clone();
+                break;
+            }
+            visited.push(dir);
+            if self
+                .markers
+                .iter()
+                .any(|marker| self.fs.exists(&dir.join(marker)))
+            {
+                root = Some(dir.to_path_buf());
+                break;
+            }
+        }
+        for dir in visited {
+            self.cache.insert(dir.to_path_buf(), root.clone());
+        }
+        root
+    }
+
+    /// The normalized path of `path` relative to the root of its project, see [`find`](ProjectRoots::find).
+    pub fn project_relative(&mut self, path: impl AsRef<Path>) -> Option<PathBuf> {
+        let path = path.as_ref().resolve();
+        let root = self.find(&path)?;
+        Some(path.relative(root))
+    }
+
+    /// Forgets the cached roots.
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/// The root of the project `path` belongs to on the real filesystem, using [`DEFAULT_PROJECT_MARKERS`]. See [`ProjectRoots`] to pick the markers, cache lookups or use another [`FileSystem`].
+pub fn find_project_root(path: impl AsRef<Path>) -> Option<PathBuf> {
+    ProjectRoots::new().find(path)
+}
@@ -0,0 +1,252 @@
+use std::{
+    error::Error,
+    ffi::OsString,
+    fmt,
+    path::{Component, Path},
+    str::FromStr,
+};
+
+use crate::{CaseSensitivity, SugarPath};
+
+/// Why a [`Query`] couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    /// The clause isn't one of those described on [`Query`].
+    InvalidClause(String),
+    /// The query has no clause at all.
+    Empty,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::InvalidClause(clause) => write!(f, "invalid clause {:?}", clause),
+            QueryError::Empty => f.write_str("empty query"),
+        }
+    }
+}
+
+impl Error for QueryError {}
+
+/// A predicate over the components of normalized paths, for lint configs and build rules that would otherwise run regexes on strings.
+///
+/// Every condition has to hold. Components are counted after the root and any Windows prefix, so `/src/lib.rs` and `src/lib.rs` both have a depth of 2 and `src` at index 0. Conditions are folded together as they are added, so matching costs a single pass over the path whatever the number of conditions.
+///
+/// A query can also be parsed from clauses joined by `&&`, which is also how it is displayed:
+///
+/// - `depth >= 2`, `depth <= 4` or `depth = 3`,
+/// - `component[0] = src`,
+/// - `extension in rs|toml`, or `extension = rs`.
+///
+/// ```rust
+/// use sugar_path::Query;
+///
+/// let query = Query::new().depth_at_least(2).component_eq(0, "src").extension_in(["rs"]);
+/// assert!(query.matches("src/a/../lib.rs"));
+/// assert!(!query.matches("src/lib.toml"));
+/// assert!(!query.matches("tests/lib.rs"));
+///
+/// let parsed = "depth >= 2 && component[0] = src && extension in rs".parse::<Query>().unwrap();
+/// assert_eq!(parsed, query);
+/// assert_eq!(parsed.to_string(), "depth >= 2 && component[0] = src && extension in rs");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    min_depth: usize,
+    max_depth: Option<usize>,
+    components: Vec<(usize, OsString)>,
+    extensions: Option<Vec<String>>,
+    case: CaseSensitivity,
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Query {
+    /// A query that matches every path.
+    pub fn new() -> Self {
+        Self {
+            min_depth: 0,
+            max_depth: None,
+            components: vec![],
+            extensions: None,
+            case: CaseSensitivity::Sensitive,
+        }
+    }
+
+    /// Requires at least `depth` components.
+    pub fn depth_at_least(mut self, depth: usize) -> Self {
+        self.min_depth = self.min_depth.max(depth);
+        self
+    }
+
+    /// Requires at most `depth` components.
+    pub fn depth_at_most(mut self, depth: usize) -> Self {
+        self.max_depth = Some(self.max_depth.map_or(depth, |max| max.min(depth)));
+        self
+    }
+
+    /// Requires the component at `index`, counting from `0`, to be `name`.
+    pub fn component_eq(mut self, index: usize, name: impl Into<OsString>) -> Self {
+        self.components.push((index, name.into()));
+        self
+    }
+
+    /// Requires the file name to end with one of `extensions`, like [`SugarPath::has_extension_in`]. Calling it again keeps the extensions allowed by both calls.
+    pub fn extension_in<I>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let extensions = extensions
+            .into_iter()
+            .map(|extension| {
+                let extension = extension.into();
+                match extension.strip_prefix('.') {
+                    Some(stripped) => stripped.to_string(),
+                    None => extension,
+                }
+            })
+            .collect::<Vec<_>>();
+        self.extensions = Some(match self.extensions {
+            Some(allowed) => allowed
+                .into_iter()
+                .filter(|allowed| {
+                    extensions
+                        .iter()
+                        .any(|extension| extension.eq_ignore_ascii_case(allowed))
+                })
+                .collect(),
+            None => extensions,
+        });
+        self
+    }
+
+    /// Sets how [`component_eq`](Query::component_eq) compares names. Queries are case-sensitive by default; extensions always ignore ASCII case.
+    pub fn case_sensitivity(mut self, case: CaseSensitivity) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Whether the normalized `path` meets every condition.
+    pub fn matches(&self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref().normalize();
+        if let Some(extensions) = &self.extensions {
+            if !extensions
+                .iter()
+                .any(|extension| path.ends_with_extension(extension))
+            {
+                return false;
+            }
+        }
+        let mut depth = 0;
+        for component in path.components() {
+            let name = match component {
+                Component::Prefix(_) | Component::RootDir | Component::CurDir => continue,
+                Component::ParentDir | Component::Normal(_) => component.as_os_str(),
+            };
+            if self
+                .components
+                .iter()
+                .any(|(index, expected)| *index == depth && !self.case.eq_os_str(expected, name))
+            {
+                return false;
+            }
+            depth += 1;
+            if self.max_depth.is_some_and(|max| depth > max) {
+                return false;
+            }
+        }
+        depth >= self.min_depth && self.components.iter().all(|(index, _)| *index < depth)
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut clauses = vec![];
+        match (self.min_depth, self.max_depth) {
+            (min, Some(max)) if min == max => clauses.push(format!("depth = {}", min)),
+            (min, max) => {
+                if min > 0 {
+                    clauses.push(format!("depth >= {}", min));
+                }
+                if let Some(max) = max {
+                    clauses.push(format!("depth <= {}", max));
+                }
+            }
+        }
+        for (index, name) in &self.components {
+            clauses.push(format!("component[{}] = {}", index, name.to_string_lossy()));
+        }
+        if let Some(extensions) = &self.extensions {
+            clauses.push(format!("extension in {}", extensions.join("|")));
+        }
+        if clauses.is_empty() {
+            // The query that matches everything.
+            return f.write_str("depth >= 0");
+        }
+        f.write_str(&clauses.join(" && "))
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(QueryError::Empty);
+        }
+        s.split("&&").try_fold(Query::new(), |query, clause| {
+            parse_clause(query, clause.trim())
+        })
+    }
+}
+
+fn parse_clause(query: Query, clause: &str) -> Result<Query, QueryError> {
+    let invalid = || QueryError::InvalidClause(clause.to_string());
+    let number = |value: &str| value.trim().parse::<usize>().map_err(|_| invalid());
+
+    if let Some(rest) = clause.strip_prefix("depth") {
+        let rest = rest.trim_start();
+        return if let Some(depth) = rest.strip_prefix(">=") {
+            Ok(query.depth_at_least(number(depth)?))
+        } else if let Some(depth) = rest.strip_prefix("<=") {
+            Ok(query.depth_at_most(number(depth)?))
+        } else if let Some(depth) = rest.strip_prefix("==").or_else(|| rest.strip_prefix('=')) {
+            let depth = number(depth)?;
+            Ok(query.depth_at_least(depth).depth_at_most(depth))
+        } else {
+            Err(invalid())
+        };
+    }
+    if let Some(rest) = clause.strip_prefix("component[") {
+        let (index, rest) = rest.split_once(']').ok_or_else(invalid)?;
+        let rest = rest.trim_start();
+        let name = rest
+            .strip_prefix("==")
+            .or_else(|| rest.strip_prefix('='))
+            .and_then(non_empty)
+            .ok_or_else(invalid)?;
+        return Ok(query.component_eq(number(index)?, name));
+    }
+    if let Some(rest) = clause.strip_prefix("extension") {
+        let rest = rest.trim_start();
+        let extensions = if let Some(list) = rest.strip_prefix("in ") {
+            list.split('|').map(non_empty).collect::<Option<Vec<_>>>()
+        } else {
+            let extension = rest.strip_prefix("==").or_else(|| rest.strip_prefix('='));
+            extension
+                .and_then(non_empty)
+                .map(|extension| vec![extension])
+        };
+        return Ok(query.extension_in(extensions.ok_or_else(invalid)?));
+    }
+    Err(invalid())
+}
+
+fn non_empty(value: &str) -> Option<&str> {
+    Some(value.trim()).filter(|value| !value.is_empty())
+}
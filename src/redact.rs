@@ -0,0 +1,142 @@
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{home::home_dir, SugarPath};
+
+/// Rewrites paths so they can go into logs, crash reports and telemetry without leaking who the user is.
+///
+/// The path is normalized and written with `/` separators. Then:
+///
+/// - a path beneath the home directory starts with `~` instead,
+/// - a component equal to one of the usernames, ignoring ASCII case, is replaced with `<user>`,
+/// - optionally, every other name is replaced with a hash of it, see [`hash_names`](Redactor::hash_names).
+///
+/// [`Redactor::new`] takes the home directory and the username from the environment, and counts the last component of the home directory as a username too, so `/home/alice` is still redacted when seen from another account.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::Redactor;
+///
+/// let redactor = Redactor::empty().home("/home/alice").username("alice");
+/// assert_eq!(redactor.redact("/home/alice/src/app/main.rs"), "~/src/app/main.rs");
+/// assert_eq!(redactor.redact("/mnt/backup/alice/notes.txt"), "/mnt/backup/<user>/notes.txt");
+///
+/// let redactor = redactor.hash_names(true);
+/// assert_eq!(redactor.redact("/home/alice/a/../b.txt"), "~/01d9faa5");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Redactor {
+    home: Option<PathBuf>,
+    usernames: Vec<OsString>,
+    hash_names: bool,
+}
+
+impl Redactor {
+    /// Redacts the home directory and username of the current user.
+    pub fn new() -> Self {
+        let mut redactor = Self::empty();
+        if let Some(home) = home_dir(None) {
+            redactor = redactor.home(home);
+        }
+        let user = if cfg!(target_family = "windows") {
+            "USERNAME"
+        } else {
+            "USER"
+        };
+        match env::var_os(user) {
+            Some(user) if !user.is_empty() => redactor.username(user),
+            _ => redactor,
+        }
+    }
+
+    /// Redacts nothing until configured.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Sets the home directory shown as `~`. Its last component is added to the usernames.
+    pub fn home(mut self, home: impl AsRef<Path>) -> Self {
+        let home = home.as_ref().normalize();
+        if let Some(Component::Normal(name)) = home.components().next_back() {
+            self = self.username(name);
+        }
+        self.home = Some(home);
+        self
+    }
+
+    /// Adds a username whose components are replaced with `<user>`.
+    pub fn username(mut self, name: impl AsRef<OsStr>) -> Self {
+        let name = name.as_ref();
+        if !name.is_empty() && !self.usernames.iter().any(|user| user == name) {
+            self.usernames.push(name.to_os_string());
+        }
+        self
+    }
+
+    /// Replaces every name that isn't otherwise redacted with the first 8 hex digits of its 64-bit FNV-1a hash. Defaults to `false`.
+    ///
+    /// The hash doesn't depend on the platform or the version of the crate, so the same name always gets the same placeholder and reports stay correlatable. `.`, `..`, roots and Windows prefixes are kept.
+    pub fn hash_names(mut self, hash: bool) -> Self {
+        self.hash_names = hash;
+        self
+    }
+
+    /// Redacts `path`.
+    pub fn redact(&self, path: impl AsRef<Path>) -> String {
+        let path = path.as_ref().normalize();
+        let mut redacted = String::with_capacity(path.as_os_str().len());
+        let mut needs_separator = false;
+        let mut rest = path.as_path();
+        if let Some(home) = &self.home {
+            if let Ok(beneath_home) = path.strip_prefix(home) {
+                redacted.push('~');
+                needs_separator = true;
+                rest = beneath_home;
+            }
+        }
+        for component in rest.components() {
+            match component {
+                Component::Prefix(prefix) => {
+                    redacted.push_str(&prefix.as_os_str().to_string_lossy().replace('\\', "/"));
+                }
+                Component::RootDir => redacted.push('/'),
+                Component::CurDir | Component::ParentDir | Component::Normal(_) => {
+                    if needs_separator {
+                        redacted.push('/');
+                    }
+                    self.push_name(&mut redacted, component);
+                    needs_separator = true;
+                }
+            }
+        }
+        redacted
+    }
+
+    fn push_name(&self, redacted: &mut String, component: Component) {
+        let name = component.as_os_str();
+        let is_user = || {
+            self.usernames.iter().any(|user| {
+                user.as_encoded_bytes()
+                    .eq_ignore_ascii_case(name.as_encoded_bytes())
+            })
+        };
+        match component {
+            Component::Normal(_) if is_user() => redacted.push_str("<user>"),
+            Component::Normal(_) if self.hash_names => {
+                let hash = fnv1a(name.as_encoded_bytes());
+                redacted.push_str(&format!("{:08x}", hash >> 32));
+            }
+            _ => redacted.push_str(&name.to_string_lossy()),
+        }
+    }
+}
+
+/// The 64-bit FNV-1a hash of `bytes`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    })
+}
@@ -0,0 +1,207 @@
+use std::{
+    error::Error,
+    fmt,
+    iter::FusedIterator,
+    path::{Component, Components, Path, PathBuf},
+};
+
+use crate::{component_eq, CaseSensitivity, SugarPath};
+
+/// Why no relative path leads from one path to another, returned by [`SugarPath::try_relative`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryRelativeError {
+    /// The resolved paths don't share a root, e.g. they are on different Windows drives.
+    DifferentRoots,
+    /// The resolved base still has a `..` below the part it shares with the path, and the name of the directory it leads out of is unknown.
+    UnresolvedParent,
+}
+
+impl fmt::Display for TryRelativeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRelativeError::DifferentRoots => f.write_str("the paths don't share a root"),
+            TryRelativeError::UnresolvedParent => {
+                f.write_str("the base has a `..` that can't be walked back")
+            }
+        }
+    }
+}
+
+impl Error for TryRelativeError {}
+
+/// How [`SugarPath::relative_with_report`] got from one path to the other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelativeReport {
+    /// The deepest directory both resolved paths are in, spelled like the path, or `None` if there is no relative path and the resolved path was returned instead.
+    pub common_ancestor: Option<PathBuf>,
+    /// The number of `..` the relative path starts with, that is how far the path is outside of the base.
+    pub ascended: usize,
+    /// The number of names that follow them.
+    pub descended: usize,
+    /// How components were compared.
+    pub case: CaseSensitivity,
+    /// Whether a component of the common ancestor is spelled differently in the two paths, so it only matched ignoring case.
+    pub case_folded: bool,
+}
+
+pub(crate) fn relative_with_report(
+    path: &Path,
+    to: &Path,
+    case: CaseSensitivity,
+) -> (PathBuf, RelativeReport) {
+    let mut report = RelativeReport {
+        common_ancestor: None,
+        ascended: 0,
+        descended: 0,
+        case,
+        case_folded: false,
+    };
+    let mut relative = PathBuf::new();
+    if try_relative_into(path, to, case, &mut relative).is_err() {
+        return (path.resolve(), report);
+    }
+    let mut common_ancestor = PathBuf::new();
+    for (base, target) in to.resolve().components().zip(path.resolve().components()) {
+        if !component_eq(&base, &target, case) {
+            break;
+        }
+        report.case_folded |= base.as_os_str() != target.as_os_str();
+        common_ancestor.push(target);
+    }
+    report.common_ancestor = Some(common_ancestor);
+    report.ascended = relative
+        .components()
+        .take_while(|component| *component == Component::ParentDir)
+        .count();
+    report.descended = relative.components().count() - report.ascended;
+    (relative, report)
+}
+
+/// Writes `path.relative(to)` into `buf`, falling back to the resolved `path` when there is no relative path.
+pub(crate) fn relative_into(path: &Path, to: &Path, case: CaseSensitivity, buf: &mut PathBuf) {
+    if try_relative_into(path, to, case, buf).is_err() {
+        buf.clear();
+        buf.push(path.resolve());
+    }
+}
+
+pub(crate) fn try_relative_into(
+    path: &Path,
+    to: &Path,
+    case: CaseSensitivity,
+    buf: &mut PathBuf,
+) -> Result<(), TryRelativeError> {
+    buf.clear();
+    let base = to.resolve();
+    let target = path.resolve();
+    if base == target {
+        return Ok(());
+    }
+    let mut base_components = base.components();
+    let mut target_components = target.components();
+    let mut shared = 0;
+    loop {
+        let mut base_rest = base_components.clone();
+        let mut target_rest = target_components.clone();
+        match (base_rest.next(), target_rest.next()) {
+            (Some(from), Some(to)) if component_eq(&from, &to, case) => {
+                base_components = base_rest;
+                target_components = target_rest;
+                shared += 1;
+            }
+            _ => break,
+        }
+    }
+    if shared == 0 {
+        return Err(TryRelativeError::DifferentRoots);
+    }
+    // Going back down a `..` of the base would need the name of the directory it left.
+    let mut parents = 0;
+    for component in base_components {
+        match component {
+            Component::ParentDir => return Err(TryRelativeError::UnresolvedParent),
+            _ => parents += 1,
+        }
+    }
+    let rest = target_components.as_path().as_os_str();
+    buf.reserve(parents * 3 + rest.len());
+    for _ in 0..parents {
+        buf.push("..");
+    }
+    if !rest.is_empty() {
+        buf.push(rest);
+    }
+    Ok(())
+}
+
+/// The components of the relative path between two paths, returned by [`SugarPath::components_between`].
+///
+/// Yields a [`Component::ParentDir`] for each level to go up, then the components to go down.
+#[derive(Debug, Clone)]
+pub struct ComponentsBetween<'a> {
+    parents: usize,
+    rest: Components<'a>,
+}
+
+impl<'a> Iterator for ComponentsBetween<'a> {
+    type Item = Component<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parents > 0 {
+            self.parents -= 1;
+            return Some(Component::ParentDir);
+        }
+        self.rest.find(|component| *component != Component::CurDir)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.parents, None)
+    }
+}
+
+impl FusedIterator for ComponentsBetween<'_> {}
+
+pub(crate) fn components_between<'a>(
+    path: &'a Path,
+    base: &'a Path,
+    case: CaseSensitivity,
+) -> Result<ComponentsBetween<'a>, TryRelativeError> {
+    let named = |components: &mut Components<'a>| {
+        components.find(|component| *component != Component::CurDir)
+    };
+    let mut base_components = base.components();
+    let mut target_components = path.components();
+    loop {
+        let mut base_rest = base_components.clone();
+        let mut target_rest = target_components.clone();
+        match (named(&mut base_rest), named(&mut target_rest)) {
+            (Some(from), Some(to)) if component_eq(&from, &to, case) => {
+                base_components = base_rest;
+                target_components = target_rest;
+            }
+            (from, to) => {
+                // An absolute path and a relative one, or different roots.
+                if [from, to]
+                    .iter()
+                    .flatten()
+                    .any(|component| matches!(component, Component::Prefix(_) | Component::RootDir))
+                {
+                    return Err(TryRelativeError::DifferentRoots);
+                }
+                break;
+            }
+        }
+    }
+    let mut parents = 0;
+    for component in base_components {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => return Err(TryRelativeError::UnresolvedParent),
+            _ => parents += 1,
+        }
+    }
+    Ok(ComponentsBetween {
+        parents,
+        rest: target_components,
+    })
+}
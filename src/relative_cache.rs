@@ -0,0 +1,189 @@
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{CaseSensitivity, SugarPath};
+
+/// Caches [`SugarPath::relative`] between pairs of directories, for computing many relative paths between files of the same directories, like when rewriting imports.
+///
+/// Only the relative path between the directory of the file and the base directory is cached; the file name is appended to it. At most `capacity` pairs are kept, dropping the least recently used one.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{RelativeCache, SugarPath};
+///
+/// let mut cache = RelativeCache::new(100);
+/// let base = Path::new("/app/src/pages");
+/// assert_eq!(cache.relative("/app/src/lib/a.js", base), Path::new("../lib/a.js"));
+/// assert_eq!(cache.relative("/app/src/lib/b.js", base), Path::new("../lib/b.js"));
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RelativeCache {
+    capacity: usize,
+    /// From the directory of the file, to the base directory, to the slot of the pair.
+    index: HashMap<PathBuf, HashMap<PathBuf, usize>>,
+    slots: Vec<Slot>,
+    /// The most recently used slot.
+    head: Option<usize>,
+    /// The least recently used slot.
+    tail: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Slot {
+    dir: PathBuf,
+    base: PathBuf,
+    relative: PathBuf,
+    /// When the base is beneath the directory, the name of the component of the base right below it.
+    base_child: Option<OsString>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl RelativeCache {
+    /// Creates a cache that keeps at most `capacity` pairs of directories. A capacity of 0 disables caching.
+    pub fn new(capacity: usize) -> Self {
+        RelativeCache {
+            capacity,
+            index: HashMap::new(),
+            slots: Vec::new(),
+            head: None,
+            tail: None,
+        }
+    }
+
+    /// The number of cached pairs of directories.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn clear(&mut self) {
+        self.index.clear();
+        self.slots.clear();
+        self.head = None;
+        self.tail = None;
+    }
+
+    /// Returns the same as `path.relative(to)`.
+    ///
+    /// A `path` that doesn't end with a file name, like `a/..`, isn't cached.
+    pub fn relative(&mut self, path: impl AsRef<Path>, to: impl AsRef<Path>) -> PathBuf {
+        let path = path.as_ref();
+        let to = to.as_ref();
+        let (dir, name) = match (path.parent(), path.file_name()) {
+            (Some(dir), Some(name)) => (dir, name),
+            _ => return path.relative(to),
+        };
+        let slot = match self.index.get(dir).and_then(|bases| bases.get(to)) {
+            Some(&slot) => {
+                self.unlink(slot);
+                slot
+            }
+            None => match self.insert(dir, to) {
+                Some(slot) => slot,
+                None => return path.relative(to),
+            },
+        };
+        self.push_front(slot);
+        let slot = &self.slots[slot];
+        match &slot.base_child {
+            // The base is beneath `path` itself, so the name cancels out with a `..`.
+            Some(child) if CaseSensitivity::platform().eq_os_str(child, name) => path.relative(to),
+            _ => slot.relative.join(name),
+        }
+    }
+
+    fn insert(&mut self, dir: &Path, base: &Path) -> Option<usize> {
+        if self.capacity == 0 {
+            return None;
+        }
+        let relative = dir.relative(base);
+        let base_child = base_child(&relative, base);
+        let slot = if self.slots.len() < self.capacity {
+            self.slots.push(Slot {
+                dir: dir.to_path_buf(),
+                base: base.to_path_buf(),
+                relative,
+                base_child,
+                prev: None,
+                next: None,
+            });
+            self.slots.len() - 1
+        } else {
+            let slot = self
+                .tail
+                .expect("a full cache has a least recently used slot");
+            self.unlink(slot);
+            let evicted = &mut self.slots[slot];
+            if let Some(bases) = self.index.get_mut(&evicted.dir) {
+                bases.remove(&evicted.base);
+                if bases.is_empty() {
+                    self.index.remove(&evicted.dir);
+                }
+            }
+            evicted.dir = dir.to_path_buf();
+            evicted.base = base.to_path_buf();
+            evicted.relative = relative;
+            evicted.base_child = base_child;
+            slot
+        };
+        self.index
+            .entry(dir.to_path_buf())
+            .or_default()
+            .insert(base.to_path_buf(), slot);
+        Some(slot)
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let Slot { prev, next, .. } = self.slots[slot];
+        match prev {
+            Some(prev) => self.slots[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.slots[next].prev = prev,
+            None => self.tail = prev,
+        }
+        self.slots[slot].prev = None;
+        self.slots[slot].next = None;
+    }
+
+    fn push_front(&mut self, slot: usize) {
+        self.slots[slot].next = self.head;
+        if let Some(head) = self.head {
+            self.slots[head].prev = Some(slot);
+        }
+        self.head = Some(slot);
+        if self.tail.is_none() {
+            self.tail = Some(slot);
+        }
+    }
+}
+
+/// Finds the component of `base` right below the directory that `relative` leads to from `base`, if `relative` only walks up.
+fn base_child(relative: &Path, base: &Path) -> Option<OsString> {
+    let parents = relative.components().count();
+    if parents == 0
+        || !relative
+            .components()
+            .all(|component| component == Component::ParentDir)
+    {
+        return None;
+    }
+    let base = base.resolve();
+    let depth = base.components().count();
+    base.components()
+        .nth(depth - parents)
+        .map(|component| component.as_os_str().to_os_string())
+}
@@ -0,0 +1,98 @@
+use std::path::{Component, Path, PathBuf};
+
+use relative_path::{FromPathError, FromPathErrorKind, RelativePath};
+
+use crate::{NormalizedPathBuf, RelativePathBuf, SugarPath, SugarPathBuf, TypedPathError};
+
+/// Sugar methods for [`relative_path::RelativePath`].
+pub trait SugarRelativePath {
+    /// Joins the path onto the normalized `base` and normalizes the result.
+    ///
+    /// Components are only split on `/`, following `relative_path`. Unlike [`RelativePath::to_path`], a component that Windows reads as a prefix, like `C:`, is dropped rather than replacing `base`, and `..` can't walk above the root of `base`.
+    ///
+    /// ```rust
+    /// use relative_path::RelativePath;
+    /// use std::path::Path;
+    /// use sugar_path::SugarRelativePath;
+    ///
+    /// let path = RelativePath::new("../lib/./mod.rs");
+    /// assert_eq!(path.resolve_on("src/bin"), Path::new("src/lib/mod.rs"));
+    /// ```
+    fn resolve_on(&self, base: impl AsRef<Path>) -> PathBuf;
+}
+
+impl SugarRelativePath for RelativePath {
+    fn resolve_on(&self, base: impl AsRef<Path>) -> PathBuf {
+        let mut path = base.as_ref().normalize();
+        for component in self.as_str().split('/') {
+            path.push_normalized(component);
+        }
+        path
+    }
+}
+
+impl TryFrom<&RelativePath> for RelativePathBuf {
+    type Error = TypedPathError;
+
+    /// Fails on Windows if the first component is read as a prefix, like `C:`.
+    fn try_from(path: &RelativePath) -> Result<Self, Self::Error> {
+        RelativePathBuf::new(path.to_path(""))
+    }
+}
+
+impl TryFrom<relative_path::RelativePathBuf> for RelativePathBuf {
+    type Error = TypedPathError;
+
+    fn try_from(path: relative_path::RelativePathBuf) -> Result<Self, Self::Error> {
+        RelativePathBuf::try_from(path.as_relative_path())
+    }
+}
+
+impl From<&RelativePath> for NormalizedPathBuf {
+    fn from(path: &RelativePath) -> Self {
+        NormalizedPathBuf::new(path.to_path(""))
+    }
+}
+
+impl From<relative_path::RelativePathBuf> for NormalizedPathBuf {
+    fn from(path: relative_path::RelativePathBuf) -> Self {
+        NormalizedPathBuf::from(path.as_relative_path())
+    }
+}
+
+impl TryFrom<&RelativePathBuf> for relative_path::RelativePathBuf {
+    type Error = FromPathError;
+
+    /// Fails if a component isn't valid UTF-8.
+    fn try_from(path: &RelativePathBuf) -> Result<Self, Self::Error> {
+        to_relative_path(path)
+    }
+}
+
+impl TryFrom<&NormalizedPathBuf> for relative_path::RelativePathBuf {
+    type Error = FromPathError;
+
+    /// Fails if the path has a root or a Windows prefix, or a component isn't valid UTF-8.
+    fn try_from(path: &NormalizedPathBuf) -> Result<Self, Self::Error> {
+        to_relative_path(path)
+    }
+}
+
+/// Joins the components of `path` with `/`, which is the only separator of `relative_path`.
+fn to_relative_path(path: &Path) -> Result<relative_path::RelativePathBuf, FromPathError> {
+    let mut ret = String::with_capacity(path.as_os_str().len());
+    for component in path.components() {
+        if matches!(component, Component::Prefix(_) | Component::RootDir) {
+            return Err(FromPathErrorKind::NonRelative.into());
+        }
+        let component = component
+            .as_os_str()
+            .to_str()
+            .ok_or(FromPathErrorKind::NonUtf8)?;
+        if !ret.is_empty() {
+            ret.push('/');
+        }
+        ret.push_str(component);
+    }
+    Ok(relative_path::RelativePathBuf::from(ret))
+}
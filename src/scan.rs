@@ -0,0 +1,45 @@
+/// Finds the positions of `separator` in `bytes`.
+#[cfg(feature = "perf")]
+fn separator_positions(bytes: &[u8], separator: u8) -> impl Iterator<Item = usize> + '_ {
+    memchr::memchr_iter(separator, bytes)
+}
+
+/// Finds the positions of `separator` in `bytes`.
+#[cfg(not(feature = "perf"))]
+fn separator_positions(bytes: &[u8], separator: u8) -> impl Iterator<Item = usize> + '_ {
+    bytes
+        .iter()
+        .enumerate()
+        .filter(move |(_, b)| **b == separator)
+        .map(|(index, _)| index)
+}
+
+/// Whether a Unix path is already what `normalize` would return, so it can be copied as is.
+///
+/// This is conservative: it may return `false` for some normalized paths, like `./`, but never returns `true` for one that isn't.
+pub(crate) fn is_normalized_unix(bytes: &[u8]) -> bool {
+    if bytes.is_empty() {
+        return false;
+    }
+    let is_absolute = bytes[0] == b'/';
+    // `..` can only start a relative path, before any normal component.
+    let mut only_parents = !is_absolute;
+    let mut start = 0;
+    for end in separator_positions(bytes, b'/') {
+        match &bytes[start..end] {
+            b"" if end == 0 => {}
+            b"" | b"." => return false,
+            b".." if !only_parents => return false,
+            b".." => {}
+            _ => only_parents = false,
+        }
+        start = end + 1;
+    }
+    match &bytes[start..] {
+        // A trailing separator is kept, but only a single one, and `/` is just the root.
+        b"" => start == 1 || bytes[start - 2] != b'/',
+        b"." => bytes.len() == 1,
+        b".." => only_parents,
+        _ => true,
+    }
+}
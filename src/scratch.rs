@@ -0,0 +1,91 @@
+use std::{
+    cell::RefCell,
+    mem,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{flavor, Normalizer, SugarPath, CWD};
+
+/// A reusable buffer for normalizing and resolving many paths in a loop without allocating for each one.
+///
+/// Results borrow the buffer and are overwritten by the next call. Each thread has one that can be borrowed with [`with_scratch`].
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::Scratch;
+///
+/// let mut scratch = Scratch::new();
+/// let mut depths = Vec::new();
+/// for path in ["a/./b", "a/../c", "d//e/f"] {
+///     depths.push(scratch.normalize(path).components().count());
+/// }
+/// assert_eq!(depths, [2, 1, 3]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Scratch {
+    buf: PathBuf,
+}
+
+impl Scratch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Normalizes `path` into the buffer, giving the same result as [`SugarPath::normalize`].
+    pub fn normalize(&mut self, path: impl AsRef<Path>) -> &Path {
+        path.as_ref().normalize_into(&mut self.buf);
+        &self.buf
+    }
+
+    /// Resolves `path` against the current working directory into the buffer, giving the same result as [`SugarPath::resolve`].
+    pub fn resolve(&mut self, path: impl AsRef<Path>) -> &Path {
+        let path = path.as_ref();
+        if flavor::overridden().is_some() {
+            self.buf = path.resolve();
+            return &self.buf;
+        }
+        if path.is_absolute() {
+            return self.normalize(path);
+        }
+        if matches!(
+            path.components().next(),
+            Some(Component::Prefix(_) | Component::RootDir)
+        ) {
+            // A root without a prefix, or a prefix without a root, only happens on Windows.
+            self.buf = path.resolve();
+            return &self.buf;
+        }
+        let mut normalizer = Normalizer::with_buffer(mem::take(&mut self.buf));
+        normalizer.feed(&*CWD);
+        normalizer.feed(path);
+        self.buf = normalizer.finish();
+        &self.buf
+    }
+}
+
+thread_local! {
+    static SCRATCH: RefCell<Scratch> = RefCell::new(Scratch::new());
+}
+
+/// Calls `f` with the [`Scratch`] of the current thread.
+///
+/// If the scratch of the thread is already borrowed, i.e. `with_scratch` is called from inside `f`, the inner call gets a fresh one.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::with_scratch;
+///
+/// let total = with_scratch(|scratch| {
+///     ["a/b/..", "c/./d"]
+///         .iter()
+///         .map(|path| scratch.normalize(path).as_os_str().len())
+///         .sum::<usize>()
+/// });
+/// assert_eq!(total, 4);
+/// ```
+pub fn with_scratch<R>(f: impl FnOnce(&mut Scratch) -> R) -> R {
+    SCRATCH.with(|scratch| match scratch.try_borrow_mut() {
+        Ok(mut scratch) => f(&mut scratch),
+        Err(_) => f(&mut Scratch::new()),
+    })
+}
@@ -0,0 +1,132 @@
+use std::{
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::Error as _, Deserializer, Serializer};
+use serde_with::{DeserializeAs, Same, SerializeAs};
+
+use crate::{RelativePathBuf, SugarPath, CWD};
+
+/// A `serde_with` adapter that normalizes a path after deserializing it with `T`. Serializing is left to `T`.
+///
+/// Like the other adapters of this crate, it wraps another adapter, so policies can be stacked, e.g. `NormalizeOnDeserialize<RequireRelative>`.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_with::serde_as;
+/// use std::path::{Path, PathBuf};
+/// use sugar_path::NormalizeOnDeserialize;
+///
+/// #[serde_as]
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde_as(as = "NormalizeOnDeserialize")]
+///     out_dir: PathBuf,
+///     #[serde_as(as = "Vec<NormalizeOnDeserialize>")]
+///     include: Vec<PathBuf>,
+/// }
+///
+/// let config: Config =
+///     serde_json::from_str(r#"{ "out_dir": "./dist/", "include": ["src/../lib"] }"#).unwrap();
+/// assert_eq!(config.out_dir, Path::new("dist/"));
+/// assert_eq!(config.include, [Path::new("lib")]);
+/// ```
+pub struct NormalizeOnDeserialize<T = Same>(PhantomData<T>);
+
+impl<'de, T: DeserializeAs<'de, PathBuf>> DeserializeAs<'de, PathBuf>
+    for NormalizeOnDeserialize<T>
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<PathBuf, D::Error> {
+        Ok(T::deserialize_as(deserializer)?.normalize())
+    }
+}
+
+impl<T: SerializeAs<PathBuf>> SerializeAs<PathBuf> for NormalizeOnDeserialize<T> {
+    fn serialize_as<S: Serializer>(source: &PathBuf, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize_as(source, serializer)
+    }
+}
+
+/// A `serde_with` adapter that rejects a path with a root or a Windows prefix after deserializing it with `T`, see [`RelativePathBuf::new`].
+///
+/// The path itself is passed through unchanged.
+pub struct RequireRelative<T = Same>(PhantomData<T>);
+
+impl<'de, T: DeserializeAs<'de, PathBuf>> DeserializeAs<'de, PathBuf> for RequireRelative<T> {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<PathBuf, D::Error> {
+        let path = T::deserialize_as(deserializer)?;
+        RelativePathBuf::new(&path).map_err(D::Error::custom)?;
+        Ok(path)
+    }
+}
+
+impl<T: SerializeAs<PathBuf>> SerializeAs<PathBuf> for RequireRelative<T> {
+    fn serialize_as<S: Serializer>(source: &PathBuf, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize_as(source, serializer)
+    }
+}
+
+/// The directory a [`ResolveAgainst`] adapter resolves paths against.
+///
+/// The base is usually only known at runtime, e.g. the directory of the config file being read, so an implementation can read it from a static or thread local set before deserializing.
+pub trait BaseDir {
+    fn base_dir() -> PathBuf;
+}
+
+/// A [`BaseDir`] that is the current working directory.
+pub struct CurrentDir;
+
+impl BaseDir for CurrentDir {
+    fn base_dir() -> PathBuf {
+        CWD.clone()
+    }
+}
+
+/// A `serde_with` adapter that joins a path deserialized with `T` onto `B::base_dir()` and normalizes it. An absolute path is kept as is, apart from being normalized.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_with::serde_as;
+/// use std::path::{Path, PathBuf};
+/// use sugar_path::{BaseDir, RequireRelative, ResolveAgainst};
+///
+/// struct ConfigDir;
+///
+/// impl BaseDir for ConfigDir {
+///     fn base_dir() -> PathBuf {
+///         PathBuf::from("/etc/app")
+///     }
+/// }
+///
+/// #[serde_as]
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde_as(as = "ResolveAgainst<ConfigDir, RequireRelative>")]
+///     plugins: PathBuf,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{ "plugins": "./plugins/../lib" }"#).unwrap();
+/// assert_eq!(config.plugins, Path::new("/etc/app/lib"));
+/// assert!(serde_json::from_str::<Config>(r#"{ "plugins": "/lib" }"#).is_err());
+/// ```
+pub struct ResolveAgainst<B, T = Same>(PhantomData<(B, T)>);
+
+impl<'de, B: BaseDir, T: DeserializeAs<'de, PathBuf>> DeserializeAs<'de, PathBuf>
+    for ResolveAgainst<B, T>
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<PathBuf, D::Error> {
+        let path = T::deserialize_as(deserializer)?;
+        Ok(resolve_against(&B::base_dir(), &path))
+    }
+}
+
+impl<B, T: SerializeAs<PathBuf>> SerializeAs<PathBuf> for ResolveAgainst<B, T> {
+    fn serialize_as<S: Serializer>(source: &PathBuf, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize_as(source, serializer)
+    }
+}
+
+fn resolve_against(base: &Path, path: &Path) -> PathBuf {
+    base.join(path).normalize()
+}
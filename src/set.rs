@@ -0,0 +1,188 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{component_eq, CaseSensitivity, SugarPath};
+
+/// Resolves a set of files and splits them into their deepest common directory and the path of each file relative to it.
+///
+/// This is the shape needed by archive writers, source map `sources` arrays and upload manifests. Files are returned in the same order as they were given. Components are compared following the conventions of the host, see [`CaseSensitivity::platform`].
+///
+/// ```rust
+/// use std::path::{Path, PathBuf};
+/// use sugar_path::{relativize_set, SugarPath};
+///
+/// let (base, files) = relativize_set(["/app/src/a.js", "/app/src/lib/b.js", "/app/test/c.js"]);
+/// #[cfg(target_family = "unix")]
+/// assert_eq!(base, Path::new("/app"));
+/// assert_eq!(
+///     files,
+///     [Path::new("src/a.js"), Path::new("src/lib/b.js"), Path::new("test/c.js")]
+/// );
+/// ```
+pub fn relativize_set<I>(files: I) -> (PathBuf, Vec<PathBuf>)
+where
+    I: IntoIterator,
+    I::Item: AsRef<Path>,
+{
+    let case = CaseSensitivity::platform();
+    let files = files
+        .into_iter()
+        .map(|file| file.as_ref().resolve())
+        .collect::<Vec<_>>();
+    let Some(first) = files.first() else {
+        return (PathBuf::new(), vec![]);
+    };
+
+    let base = first
+        .parent()
+        .unwrap_or(first)
+        .components()
+        .collect::<Vec<_>>();
+    let mut common_len = base.len();
+    for file in &files[1..] {
+        let dir = file.parent().unwrap_or(file);
+        common_len = dir
+            .components()
+            .zip(&base[..common_len])
+            .take_while(|(a, b)| component_eq(a, b, case))
+            .count();
+    }
+
+    let relative_files = files
+        .iter()
+        .map(|file| file.components().skip(common_len).collect())
+        .collect();
+    let base = base[..common_len].iter().collect();
+    (base, relative_files)
+}
+
+/// A directory whose files all disappeared from one place and showed up under another, found by [`diff_path_sets`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MovedSubtree {
+    /// The directory before the move.
+    pub from: PathBuf,
+    /// The directory after the move.
+    pub to: PathBuf,
+    /// The moved paths, relative to both directories, in sorted order.
+    pub paths: Vec<PathBuf>,
+}
+
+/// The changes between two lists of paths, returned by [`diff_path_sets`]. Every list is in sorted order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathSetDiff {
+    /// Paths only in the second list, and not part of a move.
+    pub added: Vec<PathBuf>,
+    /// Paths only in the first list, and not part of a move.
+    pub removed: Vec<PathBuf>,
+    /// Whole directories that moved.
+    pub moved: Vec<MovedSubtree>,
+}
+
+/// Normalizes two lists of paths and reports what changed between them, grouping files that moved together.
+///
+/// A directory counts as moved when every path beneath it is gone, and another directory, where nothing was before, now holds exactly the same relative paths. The outermost such directories are reported, so a moved tree is one [`MovedSubtree`] rather than one per subdirectory; what's left is added or removed. Paths are compared as normalized, byte for byte, and duplicates count once.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::diff_path_sets;
+///
+/// let diff = diff_path_sets(
+///     ["src/util/a.rs", "src/util/b/c.rs", "src/main.rs", "old.txt"],
+///     ["src/helpers/a.rs", "src/helpers/b/c.rs", "./src/main.rs", "new.txt"],
+/// );
+/// assert_eq!(diff.added, [Path::new("new.txt")]);
+/// assert_eq!(diff.removed, [Path::new("old.txt")]);
+/// assert_eq!(diff.moved.len(), 1);
+/// assert_eq!(diff.moved[0].from, Path::new("src/util"));
+/// assert_eq!(diff.moved[0].to, Path::new("src/helpers"));
+/// assert_eq!(diff.moved[0].paths, [Path::new("a.rs"), Path::new("b/c.rs")]);
+/// ```
+pub fn diff_path_sets<A, B>(before: A, after: B) -> PathSetDiff
+where
+    A: IntoIterator,
+    A::Item: AsRef<Path>,
+    B: IntoIterator,
+    B::Item: AsRef<Path>,
+{
+    let normalize = |path: &dyn AsRef<Path>| path.as_ref().normalize();
+    let before = before
+        .into_iter()
+        .map(|p| normalize(&p))
+        .collect::<BTreeSet<_>>();
+    let after = after
+        .into_iter()
+        .map(|p| normalize(&p))
+        .collect::<BTreeSet<_>>();
+    let mut removed = before.difference(&after).cloned().collect::<BTreeSet<_>>();
+    let mut added = after.difference(&before).cloned().collect::<BTreeSet<_>>();
+
+    // Directories whose whole subtree changed, with the paths beneath them.
+    let removed_dirs = changed_dirs(&before, &removed);
+    let added_dirs = changed_dirs(&after, &added);
+    let mut targets: HashMap<&[PathBuf], Vec<&PathBuf>> = HashMap::new();
+    for (dir, paths) in &added_dirs {
+        targets.entry(paths.as_slice()).or_default().push(dir);
+    }
+
+    let mut sources = removed_dirs.iter().collect::<Vec<_>>();
+    // The outermost directories first, so they claim their subdirectories.
+    sources.sort_by_key(|(dir, _)| dir.components().count());
+    let mut moved = vec![];
+    let mut claimed = HashSet::new();
+    for (from, paths) in sources {
+        let unclaimed = |dir: &Path| paths.iter().all(|path| !claimed.contains(&dir.join(path)));
+        if !unclaimed(from) {
+            continue;
+        }
+        let Some(to) = targets
+            .get(paths.as_slice())
+            .and_then(|dirs| dirs.iter().find(|to| unclaimed(to)))
+        else {
+            continue;
+        };
+        for path in paths {
+            claimed.insert(from.join(path));
+            claimed.insert(to.join(path));
+        }
+        moved.push(MovedSubtree {
+            from: from.clone(),
+            to: (*to).clone(),
+            paths: paths.clone(),
+        });
+    }
+    moved.sort_by(|a, b| a.from.cmp(&b.from));
+    removed.retain(|path| !claimed.contains(path));
+    added.retain(|path| !claimed.contains(path));
+    PathSetDiff {
+        added: added.into_iter().collect(),
+        removed: removed.into_iter().collect(),
+        moved,
+    }
+}
+
+/// The directories among the ancestors of `changed` whose paths in `all` all changed, with those paths relative to them.
+fn changed_dirs(
+    all: &BTreeSet<PathBuf>,
+    changed: &BTreeSet<PathBuf>,
+) -> BTreeMap<PathBuf, Vec<PathBuf>> {
+    let beneath = |paths: &BTreeSet<PathBuf>| {
+        let mut dirs: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+        for path in paths {
+            for dir in path.ancestors().skip(1) {
+                // Roots and prefixes can't move.
+                if !matches!(dir.components().next_back(), Some(Component::Normal(_))) {
+                    break;
+                }
+                let rest = path.strip_prefix(dir).unwrap().to_path_buf();
+                dirs.entry(dir.to_path_buf()).or_default().push(rest);
+            }
+        }
+        dirs
+    };
+    let totals = beneath(all);
+    let mut dirs = beneath(changed);
+    dirs.retain(|dir, paths| totals[dir].len() == paths.len());
+    dirs
+}
@@ -0,0 +1,147 @@
+use std::{
+    error::Error,
+    ffi::OsString,
+    fmt,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::SugarPath;
+
+/// Why [`ShardedLayout::path_for`] rejected a digest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DigestError {
+    /// The digest has fewer characters than the shards take, `width * depth`, plus one.
+    TooShort,
+    /// The digest has a character other than an ASCII letter or digit, which could be a separator or `..`.
+    InvalidChar(char),
+}
+
+impl fmt::Display for DigestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DigestError::TooShort => f.write_str("digest is too short for the shards"),
+            DigestError::InvalidChar(c) => write!(f, "invalid character {:?} in digest", c),
+        }
+    }
+}
+
+impl Error for DigestError {}
+
+/// The directory layout of a content-addressed cache, where every object is stored under directories named after the first characters of its digest, like `objects/ab/cd/abcdef…`.
+///
+/// Sharding keeps the number of entries per directory low. Each of the `depth` shard directories is named after the next `width` characters of the digest, and the file is named after the whole digest plus an optional extension. [`digest_of`](ShardedLayout::digest_of) parses such a path back.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::ShardedLayout;
+///
+/// let layout = ShardedLayout::new("cache/objects").depth(2).extension("bin");
+/// let path = layout.path_for("abcdef0123").unwrap();
+/// assert_eq!(path, Path::new("cache/objects/ab/cd/abcdef0123.bin"));
+/// assert_eq!(layout.digest_of(&path).as_deref(), Some("abcdef0123"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShardedLayout {
+    root: PathBuf,
+    width: usize,
+    depth: usize,
+    extension: Option<OsString>,
+}
+
+impl ShardedLayout {
+    /// A layout under the normalized `root`, with a single level of two-character shards and no extension.
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        let mut root = root.as_ref().normalize();
+        if root == Path::new(".") {
+            // Keeps objects from being written as `./ab/…`, which isn't normalized.
+            root.clear();
+        }
+        Self {
+            root,
+            width: 2,
+            depth: 1,
+            extension: None,
+        }
+    }
+
+    /// The number of digest characters that name each shard directory. Defaults to `2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is `0`.
+    pub fn width(mut self, width: usize) -> Self {
+        assert!(width > 0, "shards need at least one character");
+        self.width = width;
+        self
+    }
+
+    /// The number of nested shard directories. Defaults to `1`; `0` stores every object right under the root.
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// The extension of the object files, without its leading `.`. Defaults to none.
+    pub fn extension(mut self, extension: impl Into<OsString>) -> Self {
+        self.extension = Some(extension.into());
+        self
+    }
+
+    /// The normalized root of the layout, empty for the current directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The path of the object with the given digest.
+    ///
+    /// The digest is used as is, so callers should pick one case for hex digests. It must only have ASCII letters and digits, and be longer than the shards.
+    pub fn path_for(&self, digest: &str) -> Result<PathBuf, DigestError> {
+        if let Some(c) = digest.chars().find(|c| !c.is_ascii_alphanumeric()) {
+            return Err(DigestError::InvalidChar(c));
+        }
+        if digest.len() <= self.width * self.depth {
+            return Err(DigestError::TooShort);
+        }
+        let mut path = self.root.clone();
+        for shard in 0..self.depth {
+            path.push(&digest[shard * self.width..(shard + 1) * self.width]);
+        }
+        match &self.extension {
+            Some(extension) => {
+                let mut name = OsString::from(digest);
+                name.push(".");
+                name.push(extension);
+                path.push(name);
+            }
+            None => path.push(digest),
+        }
+        Ok(path)
+    }
+
+    /// The digest of the object stored at `path`, the inverse of [`path_for`](ShardedLayout::path_for).
+    ///
+    /// Both paths are compared once normalized. Returns `None` if `path` isn't an object of this layout: it is outside the root, nested at another depth, has another extension, or sits in a shard that doesn't match its digest.
+    pub fn digest_of(&self, path: impl AsRef<Path>) -> Option<String> {
+        let path = path.as_ref().normalize();
+        let rest = path.strip_prefix(&self.root).ok()?;
+        let names = rest
+            .components()
+            .map(|component| match component {
+                Component::Normal(name) => name.to_str(),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let (file_name, shards) = names.split_last()?;
+        if shards.len() != self.depth {
+            return None;
+        }
+        let digest = match &self.extension {
+            Some(extension) => file_name
+                .strip_suffix(extension.to_str()?)?
+                .strip_suffix('.')?,
+            None => file_name,
+        };
+        let path_for = self.path_for(digest).ok()?;
+        (path_for.as_os_str() == path.as_os_str()).then(|| digest.to_string())
+    }
+}
@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+use url::Url;
+
+use crate::{has_trailing_separator, path_from_file_url, FileUrlError, HostPolicy, SugarPath};
+
+/// Sugar methods for `file:` URLs of the [`url`] crate, following the same normalization as paths, for tools like language servers that receive URIs but reason about paths.
+///
+/// A URL ending with `/` stands for a directory. Paths are resolved against and made relative to the directory of the other URLs, like [`Url::join`] does, so a document URL resolves `./util.rs` next to itself.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{url_from_path, SugarUrl};
+/// use url::Url;
+///
+/// let document = Url::parse("file:///project/src/./lib.rs").unwrap();
+/// assert_eq!(document.to_normalized_path().unwrap(), Path::new("/project/src/lib.rs"));
+/// assert_eq!(document.resolve_path("../README.md").unwrap().as_str(), "file:///project/README.md");
+///
+/// let workspace = Url::parse("file:///project/").unwrap();
+/// assert_eq!(document.relative(&workspace).unwrap(), Path::new("src/lib.rs"));
+/// #[cfg(target_family = "unix")]
+/// assert_eq!(url_from_path("/project/a b/../c").unwrap().as_str(), "file:///project/c");
+/// ```
+pub trait SugarUrl {
+    /// The normalized path of the URL, like [`path_from_file_url`] with [`HostPolicy::LocalhostOnly`].
+    fn to_normalized_path(&self) -> Result<PathBuf, FileUrlError>;
+
+    /// The URL of `path` resolved against the directory of this URL. An absolute `path` replaces it.
+    fn resolve_path(&self, path: impl AsRef<Path>) -> Result<Url, FileUrlError>;
+
+    /// The path that leads from the directory of `to` to this URL, like [`SugarPath::relative`].
+    fn relative(&self, to: &Url) -> Result<PathBuf, FileUrlError>;
+}
+
+impl SugarUrl for Url {
+    fn to_normalized_path(&self) -> Result<PathBuf, FileUrlError> {
+        path_from_file_url(self.as_str(), HostPolicy::LocalhostOnly)
+    }
+
+    fn resolve_path(&self, path: impl AsRef<Path>) -> Result<Url, FileUrlError> {
+        let resolved = dir_of(self)?.join(path).normalize();
+        url_from_path(resolved).ok_or(FileUrlError::NotFileUrl)
+    }
+
+    fn relative(&self, to: &Url) -> Result<PathBuf, FileUrlError> {
+        Ok(self.to_normalized_path()?.relative(dir_of(to)?))
+    }
+}
+
+/// The `file:` URL of the resolved `path`, ending with `/` if `path` ends with a separator, or `None` if the URL can't represent it, like a verbatim path on Windows.
+pub fn url_from_path(path: impl AsRef<Path>) -> Option<Url> {
+    let path = path.as_ref();
+    let resolved = path.resolve();
+    if has_trailing_separator(path) {
+        Url::from_directory_path(resolved).ok()
+    } else {
+        Url::from_file_path(resolved).ok()
+    }
+}
+
+fn dir_of(url: &Url) -> Result<PathBuf, FileUrlError> {
+    let path = url.to_normalized_path()?;
+    if url.path().ends_with('/') {
+        return Ok(path);
+    }
+    Ok(path.parent().map_or(path.clone(), Path::to_path_buf))
+}
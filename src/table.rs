@@ -0,0 +1,217 @@
+use std::{
+    collections::HashMap,
+    ffi::{OsStr, OsString},
+    mem,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::SugarPath;
+
+/// The id of a path stored in a [`PathTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PathId(u32);
+
+const NO_PARENT: u32 = u32::MAX;
+
+#[derive(Debug, Clone)]
+struct Node {
+    parent: u32,
+    name: u32,
+    inserted: bool,
+}
+
+/// A compact store for large sets of normalized paths, such as the file lists of a build graph.
+///
+/// Paths are kept as a tree of components where every node points at its parent, so shared prefixes like `/repo/src/` are stored once and each component name is stored once however often it appears. Every inserted path gets a [`PathId`] that stays valid as long as the table lives.
+///
+/// Paths are normalized on insertion and trailing separators aren't kept.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::PathTable;
+///
+/// let mut table = PathTable::new();
+/// let lib = table.insert("src/lib.rs");
+/// let main = table.insert("src/bin/../main.rs");
+/// assert_eq!(table.insert("./src/lib.rs"), lib);
+/// assert_eq!(table.get("src/main.rs"), Some(main));
+/// assert_eq!(table.path(main), Path::new("src/main.rs"));
+/// assert_eq!(table.get("src"), None);
+/// assert_eq!(
+///     table.iter().map(|(_, path)| path).collect::<Vec<_>>(),
+///     [Path::new("src/lib.rs"), Path::new("src/main.rs")]
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PathTable {
+    nodes: Vec<Node>,
+    children: HashMap<(u32, u32), u32>,
+    names: Vec<OsString>,
+    name_ids: HashMap<OsString, u32>,
+    len: usize,
+}
+
+impl PathTable {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the normalized `path` and returns its id. Inserting the same path again returns the same id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table would hold more than `u32::MAX - 1` components.
+    pub fn insert(&mut self, path: impl AsRef<Path>) -> PathId {
+        let path = path.as_ref().normalize();
+        let mut node = NO_PARENT;
+        for component in path.components() {
+            let name = self.intern(component.as_os_str());
+            node = match self.children.get(&(node, name)) {
+                Some(&child) => child,
+                None => {
+                    let child = u32::try_from(self.nodes.len())
+                        .ok()
+                        .filter(|&child| child != NO_PARENT)
+                        .expect("too many components in the path table");
+                    self.nodes.push(Node {
+                        parent: node,
+                        name,
+                        inserted: false,
+                    });
+                    self.children.insert((node, name), child);
+                    child
+                }
+            };
+        }
+        let inserted = &mut self.nodes[node as usize].inserted;
+        if !*inserted {
+            *inserted = true;
+            self.len += 1;
+        }
+        PathId(node)
+    }
+
+    /// The id of the normalized `path`, if it was inserted.
+    ///
+    /// Directories that only appear as ancestors of inserted paths have no id.
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<PathId> {
+        let path = path.as_ref().normalize();
+        let mut node = NO_PARENT;
+        for component in path.components() {
+            let name = *self.name_ids.get(component.as_os_str())?;
+            node = *self.children.get(&(node, name))?;
+        }
+        self.nodes[node as usize].inserted.then_some(PathId(node))
+    }
+
+    /// Whether the normalized `path` was inserted.
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        self.get(path).is_some()
+    }
+
+    /// The path with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` comes from another table.
+    pub fn path(&self, id: PathId) -> PathBuf {
+        let mut names = vec![];
+        let mut node = id.0;
+        while node != NO_PARENT {
+            let Node { parent, name, .. } = self.nodes[node as usize];
+            names.push(self.names[name as usize].as_os_str());
+            node = parent;
+        }
+        names.into_iter().rev().collect()
+    }
+
+    /// The number of paths in the table.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the table has no paths.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// All paths with their ids, in the same order as [`Path`]'s `Ord`.
+    pub fn iter(&self) -> impl Iterator<Item = (PathId, PathBuf)> + '_ {
+        let mut children = vec![vec![]; self.nodes.len()];
+        let mut roots = vec![];
+        for (id, node) in self.nodes.iter().enumerate() {
+            match node.parent {
+                NO_PARENT => roots.push(id as u32),
+                parent => children[parent as usize].push(id as u32),
+            }
+        }
+
+        // Visits children in reverse order, since they are popped off the stack.
+        let sort = |nodes: &mut Vec<u32>| {
+            nodes.sort_unstable_by(|a, b| self.component(*b).cmp(&self.component(*a)))
+        };
+        sort(&mut roots);
+        let mut stack = roots;
+        let mut order = vec![];
+        while let Some(node) = stack.pop() {
+            if self.nodes[node as usize].inserted {
+                order.push(node);
+            }
+            let mut node_children = mem::take(&mut children[node as usize]);
+            sort(&mut node_children);
+            stack.extend(node_children);
+        }
+        order
+            .into_iter()
+            .map(|node| (PathId(node), self.path(PathId(node))))
+    }
+
+    /// An estimate of the heap memory used by the table, in bytes.
+    ///
+    /// Hash maps are counted by their capacity, ignoring their control bytes.
+    pub fn memory_usage(&self) -> usize {
+        let names = self.names.iter().map(|name| name.capacity()).sum::<usize>();
+        self.nodes.capacity() * mem::size_of::<Node>()
+            + self.children.capacity() * mem::size_of::<((u32, u32), u32)>()
+            + self.names.capacity() * mem::size_of::<OsString>()
+            + self.name_ids.capacity() * mem::size_of::<(OsString, u32)>()
+            // Names are stored twice, once in `names` and once as keys of `name_ids`.
+            + names * 2
+    }
+
+    fn intern(&mut self, name: &OsStr) -> u32 {
+        if let Some(&id) = self.name_ids.get(name) {
+            return id;
+        }
+        let id = self.names.len() as u32;
+        self.names.push(name.to_os_string());
+        self.name_ids.insert(name.to_os_string(), id);
+        id
+    }
+
+    /// The component a node stands for, which orders nodes the same way paths are ordered.
+    fn component(&self, node: u32) -> Component<'_> {
+        let name = &self.names[self.nodes[node as usize].name as usize];
+        Path::new(name)
+            .components()
+            .next()
+            .expect("stored names are single components")
+    }
+}
+
+impl<P: AsRef<Path>> FromIterator<P> for PathTable {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        let mut table = PathTable::new();
+        table.extend(iter);
+        table
+    }
+}
+
+impl<P: AsRef<Path>> Extend<P> for PathTable {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        for path in iter {
+            self.insert(path);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    error::Error,
+    ffi::{OsStr, OsString},
+    fmt,
+    hash::{BuildHasher, Hash},
+    path::PathBuf,
+};
+
+use crate::SugarPath;
+
+/// Why a template couldn't be expanded by [`interpolate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpolateError {
+    /// The template refers to a variable that isn't in the map.
+    MissingVariable(String),
+    /// The `{` at this byte offset is never closed.
+    UnclosedBrace(usize),
+    /// The `}` at this byte offset doesn't close anything. Use `}}` for a literal `}`.
+    UnmatchedBrace(usize),
+}
+
+impl fmt::Display for InterpolateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpolateError::MissingVariable(name) => write!(f, "missing variable {:?}", name),
+            InterpolateError::UnclosedBrace(offset) => write!(f, "unclosed `{{` at {}", offset),
+            InterpolateError::UnmatchedBrace(offset) => write!(f, "unmatched `}}` at {}", offset),
+        }
+    }
+}
+
+impl Error for InterpolateError {}
+
+/// Expands the `{name}` placeholders of `template` with `vars` and normalizes the result.
+///
+/// `{{` and `}}` stand for literal braces. Values may contain separators, which are normalized along with the rest of the template.
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::path::Path;
+/// use sugar_path::interpolate;
+///
+/// let vars = HashMap::from([("out_dir", "dist/./assets"), ("name", "main"), ("hash", "1a2b")]);
+/// assert_eq!(
+///     interpolate("{out_dir}/{name}.{hash}.js", &vars).unwrap(),
+///     Path::new("dist/assets/main.1a2b.js")
+/// );
+/// ```
+pub fn interpolate<K, V, S>(
+    template: &str,
+    vars: &HashMap<K, V, S>,
+) -> Result<PathBuf, InterpolateError>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: AsRef<OsStr>,
+    S: BuildHasher,
+{
+    let mut ret = OsString::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(index) = rest.find(['{', '}']) {
+        let offset = template.len() - rest.len() + index;
+        ret.push(&rest[..index]);
+        let brace = &rest[index..index + 1];
+        let after = &rest[index + 1..];
+        if after.starts_with(brace) {
+            ret.push(brace);
+            rest = &after[1..];
+        } else if brace == "}" {
+            return Err(InterpolateError::UnmatchedBrace(offset));
+        } else {
+            let end = after
+                .find(['{', '}'])
+                .filter(|end| after[*end..].starts_with('}'))
+                .ok_or(InterpolateError::UnclosedBrace(offset))?;
+            let name = &after[..end];
+            let value = vars
+                .get(name)
+                .ok_or_else(|| InterpolateError::MissingVariable(name.to_string()))?;
+            ret.push(value.as_ref());
+            rest = &after[end + 1..];
+        }
+    }
+    ret.push(rest);
+    Ok(PathBuf::from(ret).normalize())
+}
@@ -0,0 +1,120 @@
+use std::{error::Error, fmt, fmt::Write, path::Path, path::PathBuf};
+
+use chrono::{
+    format::{parse, Item, ParseError, Parsed, StrftimeItems},
+    NaiveDateTime,
+};
+
+use crate::SugarPath;
+
+/// Why a time template couldn't be expanded or matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeTemplateError {
+    /// The template contains a `%` specifier chrono doesn't understand, or one that needs a time zone.
+    InvalidTemplate,
+    /// The path isn't valid Unicode, so it can't be matched against the template.
+    NotUnicode,
+    /// The path doesn't match the template.
+    Parse(ParseError),
+}
+
+impl fmt::Display for TimeTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeTemplateError::InvalidTemplate => f.write_str("invalid time template"),
+            TimeTemplateError::NotUnicode => f.write_str("path is not valid unicode"),
+            TimeTemplateError::Parse(err) => write!(f, "path doesn't match the template: {}", err),
+        }
+    }
+}
+
+impl Error for TimeTemplateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TimeTemplateError::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Expands a strftime-style template such as `logs/%Y/%m/%d/app.log` for `time` and normalizes the result.
+///
+/// ```rust
+/// use std::path::Path;
+/// use chrono::NaiveDate;
+/// use sugar_path::expand_time_template;
+///
+/// let time = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap().and_hms_opt(17, 5, 0).unwrap();
+/// assert_eq!(
+///     expand_time_template("logs/%Y/%m/%d/%H.log", &time).unwrap(),
+///     Path::new("logs/2024/03/09/17.log")
+/// );
+/// ```
+pub fn expand_time_template(
+    template: &str,
+    time: &NaiveDateTime,
+) -> Result<PathBuf, TimeTemplateError> {
+    let items = StrftimeItems::new(template);
+    if items.clone().any(|item| item == Item::Error) {
+        return Err(TimeTemplateError::InvalidTemplate);
+    }
+    let mut ret = String::with_capacity(template.len());
+    write!(ret, "{}", time.format_with_items(items))
+        .map_err(|_| TimeTemplateError::InvalidTemplate)?;
+    Ok(PathBuf::from(ret).normalize())
+}
+
+/// Recovers the timestamp a path was expanded from by [`expand_time_template`].
+///
+/// Both the template and the path are normalized before matching. Fields the template doesn't mention
+/// default to the start of the coarsest unit it does, so `%Y/%m` yields midnight on the first of the month.
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use sugar_path::parse_time_template;
+///
+/// let time = parse_time_template("logs/%Y/%m/%d/%H.log", "./logs/2024/03/09/17.log").unwrap();
+/// assert_eq!(time, NaiveDate::from_ymd_opt(2024, 3, 9).unwrap().and_hms_opt(17, 0, 0).unwrap());
+/// ```
+pub fn parse_time_template(
+    template: &str,
+    path: impl AsRef<Path>,
+) -> Result<NaiveDateTime, TimeTemplateError> {
+    let template = Path::new(template).normalize();
+    let template = template.to_str().ok_or(TimeTemplateError::NotUnicode)?;
+    let path = path.as_ref().normalize();
+    let path = path.to_str().ok_or(TimeTemplateError::NotUnicode)?;
+
+    let items = StrftimeItems::new(template);
+    if items.clone().any(|item| item == Item::Error) {
+        return Err(TimeTemplateError::InvalidTemplate);
+    }
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, path, items).map_err(TimeTemplateError::Parse)?;
+    fill_missing_fields(&mut parsed).map_err(TimeTemplateError::Parse)?;
+    parsed
+        .to_naive_datetime_with_offset(0)
+        .map_err(TimeTemplateError::Parse)
+}
+
+fn fill_missing_fields(parsed: &mut Parsed) -> Result<(), ParseError> {
+    if parsed.timestamp().is_some() {
+        return Ok(());
+    }
+    let has_week = parsed.isoweek().is_some()
+        || parsed.week_from_sun().is_some()
+        || parsed.week_from_mon().is_some();
+    if parsed.month().is_none() && parsed.ordinal().is_none() && !has_week {
+        parsed.set_month(1)?;
+    }
+    if parsed.month().is_some() && parsed.day().is_none() {
+        parsed.set_day(1)?;
+    }
+    if parsed.hour_div_12().is_none() && parsed.hour_mod_12().is_none() {
+        parsed.set_hour(0)?;
+    }
+    if parsed.minute().is_none() {
+        parsed.set_minute(0)?;
+    }
+    Ok(())
+}
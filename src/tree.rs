@@ -0,0 +1,168 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use crate::{Pattern, SugarPath};
+
+/// An in-memory set of normalized paths that can be queried with glob [`Pattern`]s without touching the filesystem.
+///
+/// Paths are kept sorted, so every query returns them in the same order.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{Pattern, VirtualTree};
+///
+/// let tree: VirtualTree = ["src/main.rs", "src/util/./mod.rs", "README.md"].into_iter().collect();
+/// let pattern = Pattern::new("src/**/*.rs").unwrap();
+/// assert_eq!(
+///     tree.glob(&pattern).collect::<Vec<_>>(),
+///     [Path::new("src/main.rs"), Path::new("src/util/mod.rs")]
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VirtualTree {
+    paths: BTreeSet<PathBuf>,
+}
+
+impl VirtualTree {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the normalized `path`, returning whether it wasn't in the tree yet.
+    pub fn insert(&mut self, path: impl AsRef<Path>) -> bool {
+        self.paths.insert(path.as_ref().normalize())
+    }
+
+    /// Removes the normalized `path`, returning whether it was in the tree.
+    pub fn remove(&mut self, path: impl AsRef<Path>) -> bool {
+        self.paths.remove(&path.as_ref().normalize())
+    }
+
+    /// Whether the normalized `path` was inserted.
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        self.paths.contains(&path.as_ref().normalize())
+    }
+
+    /// The number of paths in the tree.
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Whether the tree has no paths.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// All paths in sorted order.
+    pub fn iter(&self) -> impl Iterator<Item = &Path> {
+        self.paths.iter().map(PathBuf::as_path)
+    }
+
+    /// The paths that match `pattern`, in sorted order.
+    pub fn glob<'a>(&'a self, pattern: &'a Pattern) -> impl Iterator<Item = &'a Path> + 'a {
+        self.iter().filter(move |path| {
+            let components = path.components().collect::<Vec<_>>();
+            pattern.matches_components(&components)
+        })
+    }
+}
+
+impl<P: AsRef<Path>> FromIterator<P> for VirtualTree {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        let mut tree = VirtualTree::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<P: AsRef<Path>> Extend<P> for VirtualTree {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        for path in iter {
+            self.insert(path);
+        }
+    }
+}
+
+#[cfg(feature = "fs")]
+mod fs {
+    use std::{
+        io,
+        ops::Bound,
+        path::{Component, Path, PathBuf},
+    };
+
+    use super::VirtualTree;
+    use crate::{fs::FileKind, FileSystem, Metadata, SugarPath};
+
+    impl VirtualTree {
+        /// The inserted paths strictly below the normalized `dir`.
+        fn descendants<'a>(&'a self, dir: &'a Path) -> impl Iterator<Item = &'a Path> + 'a {
+            // Everything relative sorts after absolute paths, and `.` is the parent of plain names only.
+            let is_cwd = dir == Path::new(".");
+            let start = if is_cwd {
+                Bound::Unbounded
+            } else {
+                Bound::Excluded(dir)
+            };
+            self.paths
+                .range::<Path, _>((start, Bound::Unbounded))
+                .map(PathBuf::as_path)
+                .take_while(move |path| is_cwd || path.starts_with(dir))
+                .filter(move |path| {
+                    !is_cwd || matches!(path.components().next(), Some(Component::Normal(_)))
+                })
+        }
+    }
+
+    fn not_found() -> io::Error {
+        io::Error::new(io::ErrorKind::NotFound, "no such path in the tree")
+    }
+
+    impl FileSystem for VirtualTree {
+        fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+            let path = path.normalize();
+            let kind = if self.paths.contains(&path) {
+                FileKind::File
+            } else if path == Path::new(".") || self.descendants(&path).next().is_some() {
+                FileKind::Dir
+            } else {
+                return Err(not_found());
+            };
+            Ok(Metadata { kind, len: 0 })
+        }
+
+        fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+            self.metadata(path)?;
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the tree has no symbolic links",
+            ))
+        }
+
+        fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+            let dir = path.normalize();
+            if !self.metadata(&dir)?.is_dir() {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotADirectory,
+                    "not a directory",
+                ));
+            }
+            let depth = if dir == Path::new(".") {
+                0
+            } else {
+                dir.components().count()
+            };
+            let mut entries = Vec::<PathBuf>::new();
+            for descendant in self.descendants(&dir) {
+                let child = descendant.components().take(depth + 1).collect::<PathBuf>();
+                if entries.last() != Some(&child) {
+                    entries.push(child);
+                }
+            }
+            Ok(entries)
+        }
+    }
+}
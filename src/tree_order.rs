@@ -0,0 +1,159 @@
+use std::{
+    cmp::Ordering,
+    ffi::OsStr,
+    iter::Peekable,
+    path::{Component, Path, PathBuf},
+    str::Chars,
+};
+
+/// Compares two names the way people expect in file listings: runs of digits are compared by their numeric value, so `file2` comes before `file10`, and the rest ignores ASCII case.
+///
+/// Names that are still equal are ordered by their bytes, so the order is total and only equal names compare as equal.
+///
+/// ```rust
+/// use std::{cmp::Ordering, ffi::OsStr};
+/// use sugar_path::natural_cmp;
+///
+/// let mut names = ["file10.txt", "File2.txt", "file1.txt", "file02.txt"].map(OsStr::new);
+/// names.sort_by(|a, b| natural_cmp(a, b));
+/// assert_eq!(names, ["file1.txt", "File2.txt", "file02.txt", "file10.txt"]);
+/// ```
+pub fn natural_cmp(a: &OsStr, b: &OsStr) -> Ordering {
+    let (a_lossy, b_lossy) = (a.to_string_lossy(), b.to_string_lossy());
+    let (mut a_chars, mut b_chars) = (a_lossy.chars().peekable(), b_lossy.chars().peekable());
+    // Leading zeros, as in `1` and `01`, only decide the order when nothing else does.
+    let mut leading_zeros = Ordering::Equal;
+    loop {
+        let ordering = match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => break,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(a), Some(b)) if a.is_ascii_digit() && b.is_ascii_digit() => {
+                let (a, b) = (digits(&mut a_chars), digits(&mut b_chars));
+                let (a_value, b_value) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+                leading_zeros = leading_zeros.then(a.len().cmp(&b.len()));
+                a_value
+                    .len()
+                    .cmp(&b_value.len())
+                    .then_with(|| a_value.cmp(b_value))
+            }
+            (Some(a), Some(b)) => {
+                a_chars.next();
+                b_chars.next();
+                a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+            }
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+    leading_zeros.then_with(|| a.as_encoded_bytes().cmp(b.as_encoded_bytes()))
+}
+
+fn digits(chars: &mut Peekable<Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        digits.push(c);
+    }
+    digits
+}
+
+/// Where [`TreeOrder`] puts directories among their siblings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DirPlacement {
+    /// Directories before files, like most file managers.
+    #[default]
+    First,
+    /// Directories after files.
+    Last,
+    /// Directories and files interleaved, only ordered by name.
+    Mixed,
+}
+
+/// Orders paths for rendering a file tree: every path comes right after its parent, siblings are grouped into directories and files, and each group is sorted with [`natural_cmp`].
+///
+/// Whether a path is a directory is up to the `is_dir` callback, so the order can be built from a listing, an index or the filesystem, see [`with_file_system`](TreeOrder::with_file_system). A component that has other paths beneath it in the comparison is always a directory. Paths are compared component by component, as given; normalize them first if needed.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::TreeOrder;
+///
+/// let order = TreeOrder::new(|path: &Path| path.extension().is_none());
+/// let mut paths = ["src/main.rs", "README.md", "src/bin", "src/lib10.rs", "src/lib9.rs", "Cargo.toml"]
+///     .map(Path::new);
+/// paths.sort_by(|a, b| order.compare(a, b));
+/// assert_eq!(
+///     paths,
+///     ["src/bin", "src/lib9.rs", "src/lib10.rs", "src/main.rs", "Cargo.toml", "README.md"].map(Path::new)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TreeOrder<F> {
+    is_dir: F,
+    dirs: DirPlacement,
+}
+
+impl<F: Fn(&Path) -> bool> TreeOrder<F> {
+    /// Puts directories first, as told by `is_dir`.
+    pub fn new(is_dir: F) -> Self {
+        Self {
+            is_dir,
+            dirs: DirPlacement::First,
+        }
+    }
+
+    /// Where directories go among their siblings. Defaults to [`DirPlacement::First`].
+    pub fn dirs(mut self, dirs: DirPlacement) -> Self {
+        self.dirs = dirs;
+        self
+    }
+
+    /// Compares two paths, see [`TreeOrder`].
+    pub fn compare(&self, a: &Path, b: &Path) -> Ordering {
+        let mut a_components = a.components().peekable();
+        let mut b_components = b.components().peekable();
+        let mut parent = PathBuf::new();
+        loop {
+            let (a_component, b_component) = match (a_components.next(), b_components.next()) {
+                (None, None) => return Ordering::Equal,
+                // An ancestor comes before its descendants.
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(a), Some(b)) => (a, b),
+            };
+            if a_component == b_component {
+                parent.push(a_component);
+                continue;
+            }
+            if !matches!(
+                (a_component, b_component),
+                (Component::Normal(_), Component::Normal(_))
+            ) {
+                // Roots, prefixes, `.` and `..` keep the order of `Path`.
+                return a_component.cmp(&b_component);
+            }
+
+            let a_is_dir =
+                a_components.peek().is_some() || (self.is_dir)(&parent.join(a_component));
+            let b_is_dir =
+                b_components.peek().is_some() || (self.is_dir)(&parent.join(b_component));
+            let groups = match self.dirs {
+                DirPlacement::First => b_is_dir.cmp(&a_is_dir),
+                DirPlacement::Last => a_is_dir.cmp(&b_is_dir),
+                DirPlacement::Mixed => Ordering::Equal,
+            };
+            return groups
+                .then_with(|| natural_cmp(a_component.as_os_str(), b_component.as_os_str()));
+        }
+    }
+}
+
+#[cfg(feature = "fs")]
+impl<'a> TreeOrder<Box<dyn Fn(&Path) -> bool + 'a>> {
+    /// Asks `fs` whether a path is a directory, following symbolic links. Paths that can't be found are files.
+    pub fn with_file_system(fs: &'a impl crate::FileSystem) -> Self {
+        Self::new(Box::new(move |path: &Path| {
+            fs.metadata(path).is_ok_and(|metadata| metadata.is_dir())
+        }))
+    }
+}
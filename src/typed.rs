@@ -0,0 +1,234 @@
+use std::{
+    borrow::Borrow,
+    error::Error,
+    ffi::{CString, NulError, OsStr},
+    fmt,
+    ops::Deref,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{SugarPath, SugarPathBuf};
+
+/// Why a path couldn't be turned into an [`AbsolutePathBuf`] or a [`RelativePathBuf`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypedPathError {
+    /// The path isn't absolute.
+    NotAbsolute(PathBuf),
+    /// The path has a root or a Windows prefix.
+    NotRelative(PathBuf),
+}
+
+impl fmt::Display for TypedPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypedPathError::NotAbsolute(path) => write!(f, "{:?} is not an absolute path", path),
+            TypedPathError::NotRelative(path) => write!(f, "{:?} is not a relative path", path),
+        }
+    }
+}
+
+impl Error for TypedPathError {}
+
+macro_rules! impl_path_wrapper {
+    ($name:ident, $from_bytes:ty) => {
+        impl $name {
+            pub fn as_path(&self) -> &Path {
+                &self.0
+            }
+
+            pub fn into_path_buf(self) -> PathBuf {
+                self.0
+            }
+
+            /// Converts the path to a C string for passing to system calls, failing if it contains a NUL.
+            ///
+            /// The bytes are the platform encoding of the path, see [`OsStr::as_encoded_bytes`].
+            pub fn to_cstring(&self) -> Result<CString, NulError> {
+                CString::new(self.0.as_os_str().as_encoded_bytes())
+            }
+
+            /// Returns the bytes of the path, without copying.
+            #[cfg(target_family = "unix")]
+            pub fn as_bytes(&self) -> &[u8] {
+                std::os::unix::ffi::OsStrExt::as_bytes(self.0.as_os_str())
+            }
+
+            /// Builds the path from its bytes, the same way `new` does from a [`Path`].
+            #[cfg(target_family = "unix")]
+            pub fn from_bytes(bytes: &[u8]) -> $from_bytes {
+                Self::new(<OsStr as std::os::unix::ffi::OsStrExt>::from_bytes(bytes))
+            }
+
+            /// Encodes the path as NUL-terminated UTF-16 for wide-char Win32 APIs, or returns `None` if it contains a NUL, see [`windows::to_wide`](crate::windows::to_wide).
+            #[cfg(target_family = "windows")]
+            pub fn to_wide(&self) -> Option<Vec<u16>> {
+                crate::windows::to_wide(self.0.as_os_str())
+            }
+
+            /// Builds the path from UTF-16 up to the first NUL, the same way `new` does from a [`Path`], keeping unpaired surrogates.
+            #[cfg(target_family = "windows")]
+            pub fn from_wide(wide: &[u16]) -> $from_bytes {
+                let len = wide
+                    .iter()
+                    .position(|unit| *unit == 0)
+                    .unwrap_or(wide.len());
+                Self::new(
+                    <std::ffi::OsString as std::os::windows::ffi::OsStringExt>::from_wide(
+                        &wide[..len],
+                    ),
+                )
+            }
+        }
+
+        impl Deref for $name {
+            type Target = Path;
+
+            fn deref(&self) -> &Path {
+                &self.0
+            }
+        }
+
+        impl AsRef<Path> for $name {
+            fn as_ref(&self) -> &Path {
+                &self.0
+            }
+        }
+
+        /// Paths compare and hash by their components, like [`Path`] does, so maps keyed by the wrapper can be queried with a `&Path`, see [`SugarPath::normalize_key`].
+        impl Borrow<Path> for $name {
+            fn borrow(&self) -> &Path {
+                &self.0
+            }
+        }
+
+        impl PartialEq<Path> for $name {
+            fn eq(&self, other: &Path) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&Path> for $name {
+            fn eq(&self, other: &&Path) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl From<$name> for PathBuf {
+            fn from(path: $name) -> Self {
+                path.0
+            }
+        }
+    };
+}
+
+/// A [`PathBuf`] that is known to be normalized, see [`SugarPath::normalize`].
+///
+/// It can also be collected from [`Component`]s, which normalizes them one at a time as they come, like joining them and calling `normalize` would, without building the unnormalized path first. A root or prefix in the middle replaces what came before, as it does with [`PathBuf::push`].
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{CollectNormalized, NormalizedPathBuf};
+///
+/// assert_eq!(NormalizedPathBuf::new("a/./b/../c").as_path(), Path::new("a/c"));
+///
+/// let path = Path::new("a/b/c/d")
+///     .components()
+///     .filter(|component| component.as_os_str() != "b")
+///     .chain(Path::new("../e").components())
+///     .collect_normalized();
+/// assert_eq!(path.as_path(), Path::new("a/c/e"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NormalizedPathBuf(PathBuf);
+
+impl NormalizedPathBuf {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        NormalizedPathBuf(path.as_ref().normalize())
+    }
+}
+
+impl_path_wrapper!(NormalizedPathBuf, Self);
+
+impl<'a> FromIterator<Component<'a>> for NormalizedPathBuf {
+    fn from_iter<I: IntoIterator<Item = Component<'a>>>(iter: I) -> Self {
+        let mut path = PathBuf::new();
+        for component in iter {
+            path.push_normalized_allow_absolute(component);
+        }
+        if matches!(
+            path.components().next_back(),
+            None | Some(Component::Prefix(_))
+        ) {
+            path.push(".");
+        }
+        NormalizedPathBuf(path)
+    }
+}
+
+/// Adds [`collect_normalized`](CollectNormalized::collect_normalized) to iterators of [`Component`]s.
+pub trait CollectNormalized<'a>: Iterator<Item = Component<'a>> + Sized {
+    /// Collects the components into a [`NormalizedPathBuf`].
+    fn collect_normalized(self) -> NormalizedPathBuf {
+        self.collect()
+    }
+}
+
+impl<'a, I: Iterator<Item = Component<'a>>> CollectNormalized<'a> for I {}
+
+/// A normalized absolute path.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{AbsolutePathBuf, SugarPath};
+///
+/// assert!(AbsolutePathBuf::new("src").is_err());
+/// assert_eq!(AbsolutePathBuf::resolve("src").as_path(), Path::new("src").resolve());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AbsolutePathBuf(PathBuf);
+
+impl AbsolutePathBuf {
+    /// Normalizes `path`, failing if it isn't absolute.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, TypedPathError> {
+        let path = path.as_ref();
+        if path.is_absolute() {
+            Ok(AbsolutePathBuf(path.normalize()))
+        } else {
+            Err(TypedPathError::NotAbsolute(path.to_path_buf()))
+        }
+    }
+
+    /// Resolves `path` against the current working directory, see [`SugarPath::resolve`].
+    pub fn resolve(path: impl AsRef<Path>) -> Self {
+        AbsolutePathBuf(path.as_ref().resolve())
+    }
+}
+
+impl_path_wrapper!(AbsolutePathBuf, Result<Self, TypedPathError>);
+
+/// A normalized path without a root or a Windows prefix. It may start with `..`.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::RelativePathBuf;
+///
+/// assert_eq!(RelativePathBuf::new("a/../../b").unwrap().as_path(), Path::new("../b"));
+/// #[cfg(target_family = "unix")]
+/// assert!(RelativePathBuf::new("/a").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RelativePathBuf(PathBuf);
+
+impl RelativePathBuf {
+    /// Normalizes `path`, failing if it has a root or a Windows prefix, like `/a`, `\a` or `C:a`.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, TypedPathError> {
+        let path = path.as_ref();
+        if path.has_root() || path.drive().is_some() {
+            Err(TypedPathError::NotRelative(path.to_path_buf()))
+        } else {
+            Ok(RelativePathBuf(path.normalize()))
+        }
+    }
+}
+
+impl_path_wrapper!(RelativePathBuf, Result<Self, TypedPathError>);
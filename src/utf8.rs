@@ -0,0 +1,39 @@
+use std::{
+    error::Error,
+    ffi::OsStr,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// A path, or a part of it, isn't valid UTF-8, returned by [`SugarPath::to_str_checked`](crate::SugarPath::to_str_checked) and the like.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonUtf8PathError {
+    /// The whole path.
+    pub path: PathBuf,
+    /// The offset in the path, in the bytes of [`OsStr::as_encoded_bytes`], of the first byte that isn't part of valid UTF-8. Everything before it is valid.
+    pub valid_up_to: usize,
+}
+
+impl fmt::Display for NonUtf8PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} is not valid UTF-8 from byte {}",
+            self.path, self.valid_up_to
+        )
+    }
+}
+
+impl Error for NonUtf8PathError {}
+
+/// `part` as a `str`, where `part` is borrowed from `path`.
+pub(crate) fn to_str<'a>(path: &Path, part: &'a OsStr) -> Result<&'a str, NonUtf8PathError> {
+    std::str::from_utf8(part.as_encoded_bytes()).map_err(|err| {
+        let start = part.as_encoded_bytes().as_ptr() as usize
+            - path.as_os_str().as_encoded_bytes().as_ptr() as usize;
+        NonUtf8PathError {
+            path: path.to_path_buf(),
+            valid_up_to: start + err.valid_up_to(),
+        }
+    })
+}
@@ -0,0 +1,95 @@
+use std::{
+    io,
+    path::{Component, Path, PathBuf},
+};
+
+use vfs::{error::VfsErrorKind, VfsError, VfsFileType, VfsPath};
+
+use crate::{FileKind, FileSystem, Metadata, SugarPath};
+
+/// A [`VfsPath`] is a filesystem rooted at itself: relative paths are looked up from it and absolute
+/// paths from the root of its [`vfs`] filesystem.
+///
+/// `VfsPath` has inherent methods of the same names, so call these through the trait when using them directly.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::FileSystem;
+/// use vfs::{MemoryFS, VfsPath};
+///
+/// let root = VfsPath::new(MemoryFS::new());
+/// root.join("src/util").unwrap().create_dir_all().unwrap();
+/// root.join("src/main.rs").unwrap().create_file().unwrap();
+///
+/// assert!(FileSystem::metadata(&root, Path::new("./src/util")).unwrap().is_dir());
+/// assert_eq!(
+///     FileSystem::read_dir(&root, Path::new("src")).unwrap(),
+///     [Path::new("src/main.rs"), Path::new("src/util")]
+/// );
+/// ```
+impl FileSystem for VfsPath {
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        let metadata = to_vfs_path(self, path)?.metadata().map_err(to_io_error)?;
+        let kind = match metadata.file_type {
+            VfsFileType::File => FileKind::File,
+            VfsFileType::Directory => FileKind::Dir,
+        };
+        Ok(Metadata {
+            kind,
+            len: metadata.len,
+        })
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        FileSystem::metadata(self, path)?;
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "vfs has no symbolic links",
+        ))
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut entries = to_vfs_path(self, path)?
+            .read_dir()
+            .map_err(to_io_error)?
+            .map(|entry| path.join(entry.filename()))
+            .collect::<Vec<_>>();
+        entries.sort();
+        Ok(entries)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        to_vfs_path(self, path).is_ok_and(|path| path.exists().unwrap_or(false))
+    }
+}
+
+fn to_vfs_path(base: &VfsPath, path: &Path) -> io::Result<VfsPath> {
+    let path = path.normalize();
+    let mut ret = base.clone();
+    let mut names = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir => ret = base.root(),
+            Component::CurDir => {}
+            component => names.push(component.as_os_str().to_str().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "path is not valid unicode")
+            })?),
+        }
+    }
+    if names.is_empty() {
+        return Ok(ret);
+    }
+    ret.join(names.join("/")).map_err(to_io_error)
+}
+
+fn to_io_error(err: VfsError) -> io::Error {
+    let kind = match err.kind() {
+        VfsErrorKind::IoError(err) => err.kind(),
+        VfsErrorKind::FileNotFound => io::ErrorKind::NotFound,
+        VfsErrorKind::InvalidPath => io::ErrorKind::InvalidInput,
+        VfsErrorKind::NotSupported => io::ErrorKind::Unsupported,
+        VfsErrorKind::DirectoryExists | VfsErrorKind::FileExists => io::ErrorKind::AlreadyExists,
+        _ => io::ErrorKind::Other,
+    };
+    io::Error::new(kind, err)
+}
@@ -0,0 +1,143 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{FileSystem, Pattern, SugarPath};
+
+/// How [`glob`](WalkOptions::glob) and [`rglob`](WalkOptions::rglob) walk a directory tree.
+///
+/// ```rust
+/// use std::path::Path;
+/// use sugar_path::{Pattern, VirtualTree, WalkOptions};
+///
+/// let tree: VirtualTree = ["src/main.rs", "src/a/b.rs", "src/a/c/d.rs"].into_iter().collect();
+/// let pattern = Pattern::new("*.rs").unwrap();
+/// assert_eq!(
+///     WalkOptions::new().max_depth(2).rglob(&tree, "src", &pattern).unwrap(),
+///     [Path::new("src/a/b.rs"), Path::new("src/main.rs")]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct WalkOptions {
+    max_depth: Option<usize>,
+    follow_symlinks: bool,
+}
+
+impl WalkOptions {
+    /// Walks the whole tree without following symbolic links.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only looks at entries at most `depth` levels below the root, where the entries of the root itself are at depth 1.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Whether to walk into symbolic links to directories. A link back to one of its own ancestors is never walked into.
+    pub fn follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
+
+    /// Returns the normalized paths below `root` whose path relative to `root` matches `pattern`, in sorted depth-first order.
+    ///
+    /// Directories that can't lead to a match aren't read at all.
+    pub fn glob(
+        &self,
+        fs: &impl FileSystem,
+        root: impl AsRef<Path>,
+        pattern: &Pattern,
+    ) -> io::Result<Vec<PathBuf>> {
+        let root = root.as_ref().normalize();
+        let mut ret = Vec::new();
+        let mut walk = Walk {
+            fs,
+            options: self,
+            pattern,
+            ret: &mut ret,
+            // Link targets are resolved against it, so a relative root has to be as well.
+            ancestors: vec![root.resolve()],
+        };
+        walk.dir(&root, &mut PathBuf::new(), 0)?;
+        Ok(ret)
+    }
+
+    /// Like [`glob`](WalkOptions::glob), with the pattern matching at any depth, as if it started with `**/`.
+    pub fn rglob(
+        &self,
+        fs: &impl FileSystem,
+        root: impl AsRef<Path>,
+        pattern: &Pattern,
+    ) -> io::Result<Vec<PathBuf>> {
+        self.glob(fs, root, &pattern.recursive())
+    }
+}
+
+struct Walk<'a, F> {
+    fs: &'a F,
+    options: &'a WalkOptions,
+    pattern: &'a Pattern,
+    ret: &'a mut Vec<PathBuf>,
+    /// The directories being walked, resolved, to catch symbolic link loops.
+    ancestors: Vec<PathBuf>,
+}
+
+impl<F: FileSystem> Walk<'_, F> {
+    fn dir(&mut self, dir: &Path, relative: &mut PathBuf, depth: usize) -> io::Result<()> {
+        if self.options.max_depth.is_some_and(|max| depth >= max) {
+            return Ok(());
+        }
+        let entries = match self.fs.read_dir(dir) {
+            Ok(entries) => entries,
+            // Like `find`, skip directories below the root that can't be read.
+            Err(_) if depth > 0 => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let Some(name) = entry.file_name() else {
+                continue;
+            };
+            relative.push(name);
+            let components = relative.components().collect::<Vec<_>>();
+            if self.pattern.matches_components(&components) {
+                self.ret.push(entry.normalize());
+            }
+            if self.pattern.matches_prefix(&components) {
+                if let Some(target) = self.walkable_dir(&entry) {
+                    self.ancestors.push(target);
+                    self.dir(&entry, relative, depth + 1)?;
+                    self.ancestors.pop();
+                }
+            }
+            relative.pop();
+        }
+        Ok(())
+    }
+
+    /// The resolved directory `entry` leads to, if the walk should go into it.
+    fn walkable_dir(&self, entry: &Path) -> Option<PathBuf> {
+        let parent = self.ancestors.last().expect("the root is always there");
+        let metadata = self.fs.symlink_metadata(entry).ok()?;
+        if metadata.is_dir() {
+            return Some(parent.join(entry.file_name()?));
+        }
+        if !metadata.is_symlink()
+            || !self.options.follow_symlinks
+            || !self.fs.metadata(entry).ok()?.is_dir()
+        {
+            return None;
+        }
+        let target = parent.join(self.fs.read_link(entry).ok()?).normalize();
+        if self
+            .ancestors
+            .iter()
+            .any(|ancestor| ancestor.starts_with(&target))
+        {
+            return None;
+        }
+        Some(target)
+    }
+}
@@ -0,0 +1,601 @@
+//! Lexical helpers for Windows-flavored paths. They work the same on every host.
+
+use std::{
+    borrow::Cow,
+    ffi::{OsStr, OsString},
+    path::Path,
+};
+
+use crate::{os_str, Platform, SugarPath, WINDOWS_MAX_PATH};
+
+const RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "CONIN$", "CONOUT$", "COM1", "COM2", "COM3", "COM4", "COM5",
+    "COM6", "COM7", "COM8", "COM9", "COM¹", "COM²", "COM³", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5",
+    "LPT6", "LPT7", "LPT8", "LPT9", "LPT¹", "LPT²", "LPT³",
+];
+
+/// Whether `name` is a reserved device name on Windows, such as `CON`, `nul.txt` or `com1 .log`.
+///
+/// The comparison is case-insensitive and ignores anything after the first `.`, as well as trailing spaces, matching how Windows itself recognizes device names.
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::is_reserved_name;
+///
+/// assert!(is_reserved_name(OsStr::new("nul.txt")));
+/// assert!(is_reserved_name(OsStr::new("Com1")));
+/// assert!(!is_reserved_name(OsStr::new("console")));
+/// ```
+pub fn is_reserved_name(name: &OsStr) -> bool {
+    let bytes = name.as_encoded_bytes();
+    let stem = match bytes.iter().position(|b| *b == b'.') {
+        Some(index) => &bytes[..index],
+        None => bytes,
+    };
+    let end = stem
+        .iter()
+        .rposition(|b| *b != b' ')
+        .map_or(0, |index| index + 1);
+    let stem = &stem[..end];
+    RESERVED_NAMES
+        .iter()
+        .any(|reserved| reserved.as_bytes().eq_ignore_ascii_case(stem))
+}
+
+/// A Windows path prefix, as recognized by [`parse_prefix`].
+///
+/// This mirrors [`std::path::Prefix`], but can be produced on any host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrefixKind<'a> {
+    /// `\\?\foo`, a verbatim prefix not followed by a drive or UNC root.
+    Verbatim(&'a OsStr),
+    /// `\\?\UNC\server\share`.
+    VerbatimUNC(&'a OsStr, &'a OsStr),
+    /// `\\?\C:`, with the uppercase drive letter.
+    VerbatimDisk(u8),
+    /// `\\.\COM1`, a device namespace prefix.
+    DeviceNS(&'a OsStr),
+    /// `\\server\share`.
+    UNC(&'a OsStr, &'a OsStr),
+    /// `C:`, with the uppercase drive letter.
+    Disk(u8),
+}
+
+impl PrefixKind<'_> {
+    /// Whether the prefix is verbatim (`\\?\`), which disables normalization by Windows.
+    pub fn is_verbatim(&self) -> bool {
+        matches!(
+            self,
+            PrefixKind::Verbatim(_) | PrefixKind::VerbatimUNC(..) | PrefixKind::VerbatimDisk(_)
+        )
+    }
+}
+
+/// Parses the Windows prefix at the start of `path`, returning it along with the rest of the path.
+///
+/// This works the same on every host, so Windows paths can be inspected on other platforms too. Verbatim prefixes only accept `\` as a separator, the others accept `/` as well.
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::{parse_prefix, PrefixKind};
+///
+/// assert_eq!(
+///     parse_prefix(OsStr::new("C:\\foo")),
+///     Some((PrefixKind::Disk(b'C'), OsStr::new("\\foo")))
+/// );
+/// assert_eq!(
+///     parse_prefix(OsStr::new("//server/share/foo")),
+///     Some((
+///         PrefixKind::UNC(OsStr::new("server"), OsStr::new("share")),
+///         OsStr::new("/foo")
+///     ))
+/// );
+/// assert_eq!(parse_prefix(OsStr::new("/foo")), None);
+/// ```
+pub fn parse_prefix(path: &OsStr) -> Option<(PrefixKind<'_>, &OsStr)> {
+    let bytes = path.as_encoded_bytes();
+    let is_separator = |b: &u8| *b == b'\\' || *b == b'/';
+    let is_verbatim_separator = |b: &u8| *b == b'\\';
+
+    if let Some(rest) = bytes.strip_prefix(br"\\?\") {
+        if let Some(rest) = rest.strip_prefix(br"UNC\") {
+            let (server, share, rest) = parse_two_components(rest, is_verbatim_separator);
+            return Some((
+                PrefixKind::VerbatimUNC(
+                    os_str::from_encoded_bytes(server),
+                    os_str::from_encoded_bytes(share),
+                ),
+                os_str::from_encoded_bytes(rest),
+            ));
+        }
+        if let Some(drive) = parse_drive(rest) {
+            if rest.get(2).is_none_or(is_verbatim_separator) {
+                return Some((
+                    PrefixKind::VerbatimDisk(drive),
+                    os_str::from_encoded_bytes(&rest[2..]),
+                ));
+            }
+        }
+        let (name, rest) = split_at_separator(rest, is_verbatim_separator);
+        return Some((
+            PrefixKind::Verbatim(os_str::from_encoded_bytes(name)),
+            os_str::from_encoded_bytes(rest),
+        ));
+    }
+
+    if let [first, second, rest @ ..] = bytes {
+        if is_separator(first) && is_separator(second) {
+            if let [b'.', separator, rest @ ..] = rest {
+                if is_separator(separator) {
+                    let (name, rest) = split_at_separator(rest, is_separator);
+                    return Some((
+                        PrefixKind::DeviceNS(os_str::from_encoded_bytes(name)),
+                        os_str::from_encoded_bytes(rest),
+                    ));
+                }
+            }
+            let (server, share, rest) = parse_two_components(rest, is_separator);
+            if server.is_empty() {
+                return None;
+            }
+            return Some((
+                PrefixKind::UNC(
+                    os_str::from_encoded_bytes(server),
+                    os_str::from_encoded_bytes(share),
+                ),
+                os_str::from_encoded_bytes(rest),
+            ));
+        }
+    }
+
+    parse_drive(bytes).map(|drive| {
+        (
+            PrefixKind::Disk(drive),
+            os_str::from_encoded_bytes(&bytes[2..]),
+        )
+    })
+}
+
+/// How Windows reads a path, as told by [`classify_windows_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowsPathKind {
+    /// `C:\foo`, absolute on a drive.
+    DriveAbsolute,
+    /// `C:foo`, relative to the current directory of the drive.
+    DriveRelative,
+    /// `\foo`, relative to the root of the current drive.
+    Rooted,
+    /// `\\server\share\foo`.
+    Unc,
+    /// `\\?\C:\foo` or `\\?\UNC\server\share`, passed to the filesystem without normalization.
+    Verbatim,
+    /// `\\.\COM1`, in the device namespace.
+    Device,
+    /// `foo`, relative to the current directory.
+    Relative,
+}
+
+impl WindowsPathKind {
+    /// Whether a path of this kind means the same thing whatever the current directory and drive.
+    pub fn is_absolute(self) -> bool {
+        matches!(
+            self,
+            WindowsPathKind::DriveAbsolute
+                | WindowsPathKind::Unc
+                | WindowsPathKind::Verbatim
+                | WindowsPathKind::Device
+        )
+    }
+}
+
+/// Tells what kind of Windows path `path` is, which decides how it is joined and resolved, see [`WindowsPathKind`].
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::{classify_windows_kind, WindowsPathKind};
+///
+/// assert_eq!(classify_windows_kind(OsStr::new(r"C:\foo")), WindowsPathKind::DriveAbsolute);
+/// assert_eq!(classify_windows_kind(OsStr::new("C:foo")), WindowsPathKind::DriveRelative);
+/// assert_eq!(classify_windows_kind(OsStr::new("/foo")), WindowsPathKind::Rooted);
+/// assert_eq!(classify_windows_kind(OsStr::new(r"\\server\share")), WindowsPathKind::Unc);
+/// assert_eq!(classify_windows_kind(OsStr::new(r"\\?\C:\foo")), WindowsPathKind::Verbatim);
+/// assert_eq!(classify_windows_kind(OsStr::new(r"\\.\COM1")), WindowsPathKind::Device);
+/// assert_eq!(classify_windows_kind(OsStr::new("foo")), WindowsPathKind::Relative);
+/// ```
+pub fn classify_windows_kind(path: &OsStr) -> WindowsPathKind {
+    let starts_with_separator = |rest: &OsStr| {
+        rest.as_encoded_bytes()
+            .first()
+            .is_some_and(|b| matches!(b, b'\\' | b'/'))
+    };
+    match parse_prefix(path) {
+        Some((PrefixKind::Disk(_), rest)) if starts_with_separator(rest) => {
+            WindowsPathKind::DriveAbsolute
+        }
+        Some((PrefixKind::Disk(_), _)) => WindowsPathKind::DriveRelative,
+        Some((PrefixKind::UNC(..), _)) => WindowsPathKind::Unc,
+        Some((PrefixKind::DeviceNS(_), _)) => WindowsPathKind::Device,
+        Some((
+            PrefixKind::Verbatim(_) | PrefixKind::VerbatimDisk(_) | PrefixKind::VerbatimUNC(..),
+            _,
+        )) => WindowsPathKind::Verbatim,
+        None if starts_with_separator(path) => WindowsPathKind::Rooted,
+        None => WindowsPathKind::Relative,
+    }
+}
+
+/// Removes a `\\?\` or `\\?\UNC\` prefix when Windows would read the path the same way without it.
+///
+/// The verbatim prefix turns off the normalization Win32 applies to paths, so it can only go when there is nothing left to normalize: no `/`, no empty, `.` or `..` components, no names ending with a dot or a space, no reserved device names, and a total length within [`WINDOWS_MAX_PATH`]. Otherwise, and for paths that aren't verbatim disk or UNC paths, `path` is returned unchanged.
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::simplify_verbatim;
+///
+/// assert_eq!(simplify_verbatim(OsStr::new(r"\\?\C:\Users\me")), OsStr::new(r"C:\Users\me"));
+/// assert_eq!(
+///     simplify_verbatim(OsStr::new(r"\\?\UNC\server\share\x")),
+///     OsStr::new(r"\\server\share\x")
+/// );
+/// // `nul` is a device outside of the verbatim namespace.
+/// assert_eq!(simplify_verbatim(OsStr::new(r"\\?\C:\nul")), OsStr::new(r"\\?\C:\nul"));
+/// ```
+pub fn simplify_verbatim(path: &OsStr) -> Cow<'_, OsStr> {
+    let mut simplified = OsString::with_capacity(path.len());
+    let rest = match parse_prefix(path) {
+        Some((PrefixKind::VerbatimDisk(drive), rest)) => {
+            simplified.push(format!("{}:", drive as char));
+            rest
+        }
+        Some((PrefixKind::VerbatimUNC(server, share), rest))
+            if is_plain_name(server) && is_plain_name(share) =>
+        {
+            simplified.push(r"\\");
+            simplified.push(server);
+            simplified.push("\\");
+            simplified.push(share);
+            rest
+        }
+        _ => return Cow::Borrowed(path),
+    };
+    let rest_bytes = rest.as_encoded_bytes();
+    let names = match rest_bytes.strip_prefix(b"\\") {
+        Some(names) => names,
+        // A share is a root by itself.
+        None if rest_bytes.is_empty() && !simplified.as_encoded_bytes().ends_with(b":") => b"",
+        // `\\?\C:` without a root would become relative to the current directory of the drive.
+        None => return Cow::Borrowed(path),
+    };
+    let names = names.strip_suffix(b"\\").unwrap_or(names);
+    if !names.is_empty()
+        && !names
+            .split(|b| *b == b'\\')
+            .all(|name| is_plain_name(os_str::from_encoded_bytes(name)))
+    {
+        return Cow::Borrowed(path);
+    }
+    simplified.push(rest);
+    if os_str::utf16_len(&simplified) > WINDOWS_MAX_PATH {
+        return Cow::Borrowed(path);
+    }
+    Cow::Owned(simplified)
+}
+
+/// The NT object manager directories that hold the DOS device names, like `C:` and `UNC`.
+const NT_DOS_DEVICES: &[&[u8]] = &[br"\??\", br"\DosDevices\", br"\GLOBAL??\"];
+
+/// Converts an NT object path that goes through the DOS device names, like `\??\C:\x` or `\DosDevices\UNC\server\share`, to a Win32 path.
+///
+/// Such paths show up in the registry, minidumps and ETW traces. The NT path is read verbatim, so it becomes a `\\?\` path first, which [`simplify_verbatim`] then turns into a plain one when that means the same thing. Returns `None` for paths that aren't of this form, including `\Device\` paths, which have no Win32 equivalent; see [`is_nt_device_path`].
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::nt_to_win32;
+///
+/// assert_eq!(nt_to_win32(OsStr::new(r"\??\C:\Windows\notepad.exe")).unwrap(), r"C:\Windows\notepad.exe");
+/// assert_eq!(nt_to_win32(OsStr::new(r"\??\UNC\server\share\a")).unwrap(), r"\\server\share\a");
+/// assert_eq!(nt_to_win32(OsStr::new(r"\??\C:\a\..\b")).unwrap(), r"\\?\C:\a\..\b");
+/// assert_eq!(nt_to_win32(OsStr::new(r"C:\a")), None);
+/// ```
+pub fn nt_to_win32(path: &OsStr) -> Option<OsString> {
+    let bytes = path.as_encoded_bytes();
+    let rest = NT_DOS_DEVICES.iter().find_map(|directory| {
+        let head = bytes.get(..directory.len())?;
+        head.eq_ignore_ascii_case(directory)
+            .then(|| &bytes[directory.len()..])
+    })?;
+    if rest.is_empty() {
+        return None;
+    }
+    let mut verbatim = OsString::with_capacity(bytes.len() + 2);
+    verbatim.push(r"\\?\");
+    verbatim.push(os_str::from_encoded_bytes(rest));
+    Some(simplify_verbatim(&verbatim).into_owned())
+}
+
+/// Whether `path` is an NT device path like `\Device\HarddiskVolume1\x`, which has no Win32 equivalent.
+///
+/// Mapping the device to a drive letter needs the mount points of the machine the path came from, so these are only recognized, so they can be kept away from `normalize`.
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::is_nt_device_path;
+///
+/// assert!(is_nt_device_path(OsStr::new(r"\Device\HarddiskVolume3\Windows")));
+/// assert!(!is_nt_device_path(OsStr::new(r"\Devices\x")));
+/// ```
+pub fn is_nt_device_path(path: &OsStr) -> bool {
+    let prefix = br"\Device\";
+    let bytes = path.as_encoded_bytes();
+    bytes.len() > prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+/// A share on a remote machine, `\\server\share`, that exposes a local directory of that machine, like `D:\apps`.
+///
+/// Deployment tools use it to reach the files of a machine from another one: [`to_unc`](UncShare::to_unc) re-roots a local path onto the share and [`to_local`](UncShare::to_local) maps it back. Both normalize the path the way Win32 does, with `..` stopping at the root, and compare names ignoring ASCII case.
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::UncShare;
+///
+/// let share = UncShare::new("build01", "apps", OsStr::new(r"D:\apps")).unwrap();
+/// assert_eq!(share.to_unc(OsStr::new(r"d:\Apps\svc\.\config.json")).unwrap(), r"\\build01\apps\svc\config.json");
+/// assert_eq!(share.to_local(OsStr::new(r"\\BUILD01\apps\svc")).unwrap(), r"D:\apps\svc");
+/// assert_eq!(share.to_unc(OsStr::new(r"D:\data\x")), None);
+///
+/// let admin = UncShare::admin("build01", b'D');
+/// assert_eq!(admin.to_unc(OsStr::new(r"D:\apps\svc")).unwrap(), r"\\build01\d$\apps\svc");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UncShare {
+    server: OsString,
+    share: OsString,
+    drive: u8,
+    dir: Vec<OsString>,
+}
+
+impl UncShare {
+    /// The share `\\server\share` exposing `dir`, or `None` if `dir` isn't an absolute path on a drive, like `D:\apps`.
+    pub fn new(server: impl AsRef<OsStr>, share: impl AsRef<OsStr>, dir: &OsStr) -> Option<Self> {
+        let (drive, names) = parse_drive_path(dir)?;
+        Some(Self {
+            server: server.as_ref().to_os_string(),
+            share: share.as_ref().to_os_string(),
+            drive,
+            dir: names.into_iter().map(OsStr::to_os_string).collect(),
+        })
+    }
+
+    /// The administrative share of `drive` on `server`, like `\\server\d$` for `D:\`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `drive` isn't an ASCII letter.
+    pub fn admin(server: impl AsRef<OsStr>, drive: u8) -> Self {
+        assert!(drive.is_ascii_alphabetic(), "a drive is an ASCII letter");
+        Self {
+            server: server.as_ref().to_os_string(),
+            share: format!("{}$", drive.to_ascii_lowercase() as char).into(),
+            drive: drive.to_ascii_uppercase(),
+            dir: vec![],
+        }
+    }
+
+    /// The path of the local `path` on the share, or `None` if `path` isn't an absolute path on a drive beneath the shared directory.
+    pub fn to_unc(&self, path: &OsStr) -> Option<OsString> {
+        let (drive, names) = parse_drive_path(path)?;
+        let rest = names_beneath(&names, &self.dir).filter(|_| drive == self.drive)?;
+        let mut unc = OsString::with_capacity(path.len() + self.server.len() + self.share.len());
+        unc.push(r"\\");
+        unc.push(&self.server);
+        unc.push(r"\");
+        unc.push(&self.share);
+        for name in rest {
+            unc.push(r"\");
+            unc.push(name);
+        }
+        Some(unc)
+    }
+
+    /// The local path of `path` on the share, or `None` if `path` isn't on this share. Verbatim paths aren't recognized.
+    pub fn to_local(&self, path: &OsStr) -> Option<OsString> {
+        let Some((PrefixKind::UNC(server, share), rest)) = parse_prefix(path) else {
+            return None;
+        };
+        let eq = |a: &OsStr, b: &OsStr| {
+            a.as_encoded_bytes()
+                .eq_ignore_ascii_case(b.as_encoded_bytes())
+        };
+        if !eq(server, &self.server) || !eq(share, &self.share) {
+            return None;
+        }
+        let mut local = OsString::with_capacity(path.len());
+        local.push(format!("{}:", self.drive as char));
+        let dir = self.dir.iter().map(OsString::as_os_str);
+        for name in dir.chain(win32_names(rest)) {
+            local.push(r"\");
+            local.push(name);
+        }
+        if self.dir.is_empty() && local.len() == 2 {
+            local.push(r"\");
+        }
+        Some(local)
+    }
+}
+
+/// Re-roots the absolute `path` on a drive onto the administrative share of its drive on `server`, like `\\server\d$\apps` for `D:\apps`. See [`UncShare`] to use another share.
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::to_unc;
+///
+/// assert_eq!(to_unc(OsStr::new(r"D:\apps\svc\..\config.json"), "srv").unwrap(), r"\\srv\d$\apps\config.json");
+/// assert_eq!(to_unc(OsStr::new(r"apps\svc"), "srv"), None);
+/// ```
+pub fn to_unc(path: &OsStr, server: impl AsRef<OsStr>) -> Option<OsString> {
+    let (drive, _) = parse_drive_path(path)?;
+    UncShare::admin(server, drive).to_unc(path)
+}
+
+/// Maps a path on an administrative share, like `\\server\d$\apps`, back to the local path on that machine, `D:\apps`. Returns `None` for other paths.
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::from_unc;
+///
+/// assert_eq!(from_unc(OsStr::new(r"\\srv\d$\apps\.\config.json")).unwrap(), r"D:\apps\config.json");
+/// assert_eq!(from_unc(OsStr::new(r"\\srv\apps\config.json")), None);
+/// ```
+pub fn from_unc(path: &OsStr) -> Option<OsString> {
+    let Some((PrefixKind::UNC(server, share), _)) = parse_prefix(path) else {
+        return None;
+    };
+    let drive = match share.as_encoded_bytes() {
+        [drive, b'$'] if drive.is_ascii_alphabetic() => *drive,
+        _ => return None,
+    };
+    UncShare::admin(server, drive).to_local(path)
+}
+
+/// Decodes the UTF-16 that wide-char Win32 APIs deal in, up to the first NUL if there is one.
+///
+/// On Windows this is lossless, like `OsString::from_wide`. Elsewhere an `OsString` can't hold unpaired surrogates, so they make it fail.
+///
+/// ```rust
+/// use sugar_path::windows::from_wide;
+///
+/// let wide = "C:\\Temp\\a.txt\0garbage".encode_utf16().collect::<Vec<_>>();
+/// assert_eq!(from_wide(&wide).unwrap(), r"C:\Temp\a.txt");
+/// ```
+pub fn from_wide(wide: &[u16]) -> Option<OsString> {
+    let len = wide
+        .iter()
+        .position(|unit| *unit == 0)
+        .unwrap_or(wide.len());
+    let wide = &wide[..len];
+    #[cfg(target_family = "windows")]
+    {
+        use std::os::windows::ffi::OsStringExt;
+        Some(OsString::from_wide(wide))
+    }
+    #[cfg(not(target_family = "windows"))]
+    {
+        String::from_utf16(wide).ok().map(OsString::from)
+    }
+}
+
+/// Encodes `path` as NUL-terminated UTF-16, ready to pass to a wide-char Win32 API, or `None` if it contains a NUL.
+///
+/// On Windows this is lossless, like `OsStr::encode_wide`. Elsewhere paths that aren't valid Unicode fail too.
+///
+/// ```rust
+/// use std::ffi::OsStr;
+/// use sugar_path::windows::to_wide;
+///
+/// assert_eq!(to_wide(OsStr::new("a\\b")).unwrap(), [b'a' as u16, b'\\' as u16, b'b' as u16, 0]);
+/// assert_eq!(to_wide(OsStr::new("a\0b")), None);
+/// ```
+pub fn to_wide(path: &OsStr) -> Option<Vec<u16>> {
+    #[cfg(target_family = "windows")]
+    let units = {
+        use std::os::windows::ffi::OsStrExt;
+        path.encode_wide()
+    };
+    #[cfg(not(target_family = "windows"))]
+    let units = path.to_str()?.encode_utf16();
+    let mut wide = Vec::with_capacity(path.len() + 1);
+    for unit in units {
+        if unit == 0 {
+            return None;
+        }
+        wide.push(unit);
+    }
+    wide.push(0);
+    Some(wide)
+}
+
+/// Normalizes the UTF-16 path `wide` following the rules of Windows, see [`SugarPath::normalize_as`], and encodes the result like [`to_wide`].
+///
+/// On Windows nothing goes through UTF-8, so names with unpaired surrogates survive. Elsewhere they make it fail, like in [`from_wide`].
+///
+/// ```rust
+/// use sugar_path::windows::{from_wide, normalize_wide};
+///
+/// let wide = "C:/Users/./me/../you\\".encode_utf16().collect::<Vec<_>>();
+/// assert_eq!(from_wide(&normalize_wide(&wide).unwrap()).unwrap(), r"C:\Users\you\");
+/// ```
+pub fn normalize_wide(wide: &[u16]) -> Option<Vec<u16>> {
+    let path = from_wide(wide)?;
+    to_wide(Path::new(&path).normalize_as(Platform::Windows).as_os_str())
+}
+
+/// Parses `D:\names`, normalized, into the uppercase drive letter and the names.
+fn parse_drive_path(path: &OsStr) -> Option<(u8, Vec<&OsStr>)> {
+    match parse_prefix(path)? {
+        (PrefixKind::Disk(drive), rest)
+            if rest
+                .as_encoded_bytes()
+                .first()
+                .is_some_and(|b| matches!(b, b'\\' | b'/')) =>
+        {
+            Some((drive, win32_names(rest)))
+        }
+        _ => None,
+    }
+}
+
+/// The names of the rest of a path after its prefix, normalized the way Win32 does: `.` and empty names are dropped and `..` stops at the root.
+fn win32_names(rest: &OsStr) -> Vec<&OsStr> {
+    let mut names = vec![];
+    for name in rest.as_encoded_bytes().split(|b| matches!(b, b'\\' | b'/')) {
+        match name {
+            b"" | b"." => {}
+            b".." => {
+                names.pop();
+            }
+            name => names.push(os_str::from_encoded_bytes(name)),
+        }
+    }
+    names
+}
+
+/// The names left of `names` after `dir`, if `dir` contains them.
+fn names_beneath<'a>(names: &'a [&'a OsStr], dir: &[OsString]) -> Option<&'a [&'a OsStr]> {
+    let contained = dir.len() <= names.len()
+        && dir.iter().zip(names).all(|(dir, name)| {
+            dir.as_encoded_bytes()
+                .eq_ignore_ascii_case(name.as_encoded_bytes())
+        });
+    contained.then(|| &names[dir.len()..])
+}
+
+/// Whether Win32 would leave `name` as it is.
+fn is_plain_name(name: &OsStr) -> bool {
+    let bytes = name.as_encoded_bytes();
+    // This also rules out `.` and `..`.
+    !matches!(bytes, [] | [.., b'.' | b' ']) && !bytes.contains(&b'/') && !is_reserved_name(name)
+}
+
+fn parse_drive(bytes: &[u8]) -> Option<u8> {
+    match bytes {
+        [letter, b':', ..] if letter.is_ascii_alphabetic() => Some(letter.to_ascii_uppercase()),
+        _ => None,
+    }
+}
+
+/// Splits `bytes` before its first separator.
+fn split_at_separator(bytes: &[u8], is_separator: impl Fn(&u8) -> bool) -> (&[u8], &[u8]) {
+    let index = bytes.iter().position(is_separator).unwrap_or(bytes.len());
+    bytes.split_at(index)
+}
+
+/// Splits `server\share\rest` into `server`, `share` and `\rest`.
+fn parse_two_components(
+    bytes: &[u8],
+    is_separator: impl Fn(&u8) -> bool + Copy,
+) -> (&[u8], &[u8], &[u8]) {
+    let (first, rest) = split_at_separator(bytes, is_separator);
+    let rest = rest.get(1..).unwrap_or(rest);
+    let (second, rest) = split_at_separator(rest, is_separator);
+    (first, second, rest)
+}
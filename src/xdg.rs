@@ -0,0 +1,142 @@
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use crate::SugarPath;
+
+/// One of the base directories of the XDG Base Directory Specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum XdgDir {
+    /// `$XDG_CONFIG_HOME`, `~/.config` by default.
+    Config,
+    /// `$XDG_CACHE_HOME`, `~/.cache` by default.
+    Cache,
+    /// `$XDG_DATA_HOME`, `~/.local/share` by default.
+    Data,
+    /// `$XDG_STATE_HOME`, `~/.local/state` by default.
+    State,
+    /// `$XDG_RUNTIME_DIR`, which has no default.
+    Runtime,
+}
+
+impl XdgDir {
+    /// The environment variable that overrides the directory.
+    pub fn env_var(self) -> &'static str {
+        match self {
+            XdgDir::Config => "XDG_CONFIG_HOME",
+            XdgDir::Cache => "XDG_CACHE_HOME",
+            XdgDir::Data => "XDG_DATA_HOME",
+            XdgDir::State => "XDG_STATE_HOME",
+            XdgDir::Runtime => "XDG_RUNTIME_DIR",
+        }
+    }
+
+    /// The default location of the directory, relative to the home directory.
+    fn default_in_home(self) -> Option<&'static str> {
+        match self {
+            XdgDir::Config => Some(".config"),
+            XdgDir::Cache => Some(".cache"),
+            XdgDir::Data => Some(".local/share"),
+            XdgDir::State => Some(".local/state"),
+            XdgDir::Runtime => None,
+        }
+    }
+}
+
+/// Looks up the XDG base directories, as normalized absolute paths.
+///
+/// Each directory comes from its environment variable, see [`XdgDir::env_var`], or defaults to a directory in `$HOME`. As the specification requires, variables that are empty or hold a relative path are ignored. The environment can be replaced with [`with_env`](XdgDirs::with_env), e.g. in tests.
+///
+/// ```rust
+/// use std::{ffi::OsString, path::Path};
+/// use sugar_path::{XdgDir, XdgDirs};
+///
+/// let dirs = XdgDirs::with_env(|name| match name {
+///     "HOME" => Some(OsString::from("/home/me")),
+///     "XDG_CACHE_HOME" => Some(OsString::from("/tmp/cache/")),
+///     _ => None,
+/// });
+/// #[cfg(target_family = "unix")]
+/// {
+///     assert_eq!(dirs.config_home().unwrap(), Path::new("/home/me/.config"));
+///     assert_eq!(dirs.cache_home().unwrap(), Path::new("/tmp/cache"));
+///     assert_eq!(dirs.runtime_dir(), None);
+///     assert_eq!(
+///         dirs.join(XdgDir::Config, "app/../tool/config.toml").unwrap(),
+///         Path::new("/home/me/.config/tool/config.toml")
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct XdgDirs<F = fn(&str) -> Option<OsString>> {
+    env: F,
+}
+
+impl XdgDirs {
+    /// Looks the directories up in the environment of the process.
+    pub fn new() -> Self {
+        Self {
+            env: |name| env::var_os(name),
+        }
+    }
+}
+
+impl Default for XdgDirs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Fn(&str) -> Option<OsString>> XdgDirs<F> {
+    /// Looks the directories up with `env`, which returns the value of an environment variable.
+    pub fn with_env(env: F) -> Self {
+        Self { env }
+    }
+
+    /// The normalized absolute path of `dir`, or `None` if it isn't set and there is no home directory to default to.
+    pub fn dir(&self, dir: XdgDir) -> Option<PathBuf> {
+        let absolute = |name: &str| {
+            (self.env)(name)
+                .map(PathBuf::from)
+                .filter(|path| path.is_absolute())
+        };
+        let path = match absolute(dir.env_var()) {
+            Some(path) => path,
+            None => absolute("HOME")?.join(dir.default_in_home()?),
+        };
+        // Collecting the components drops a trailing separator, which `normalize` would keep.
+        Some(path.components().collect::<PathBuf>().normalize())
+    }
+
+    /// [`XdgDir::Config`].
+    pub fn config_home(&self) -> Option<PathBuf> {
+        self.dir(XdgDir::Config)
+    }
+
+    /// [`XdgDir::Cache`].
+    pub fn cache_home(&self) -> Option<PathBuf> {
+        self.dir(XdgDir::Cache)
+    }
+
+    /// [`XdgDir::Data`].
+    pub fn data_home(&self) -> Option<PathBuf> {
+        self.dir(XdgDir::Data)
+    }
+
+    /// [`XdgDir::State`].
+    pub fn state_home(&self) -> Option<PathBuf> {
+        self.dir(XdgDir::State)
+    }
+
+    /// [`XdgDir::Runtime`].
+    pub fn runtime_dir(&self) -> Option<PathBuf> {
+        self.dir(XdgDir::Runtime)
+    }
+
+    /// Joins `path` onto `dir` and normalizes the result, so an application can locate its files in one call.
+    pub fn join(&self, dir: XdgDir, path: impl AsRef<Path>) -> Option<PathBuf> {
+        Some(self.dir(dir)?.join(path).normalize())
+    }
+}
@@ -0,0 +1,55 @@
+use std::path::{Path, PathBuf};
+use sugar_path::SugarPath;
+
+#[test]
+fn relative() {
+    let path = Path::new("a/b/../c/d/");
+    assert_eq!(path.nth_ancestor(0).unwrap(), Path::new("a/c/d"));
+    assert_eq!(path.nth_ancestor(1).unwrap(), Path::new("a/c"));
+    assert_eq!(path.nth_ancestor(3).unwrap(), Path::new("."));
+    assert_eq!(path.nth_ancestor(4), None);
+    assert_eq!(path.nth_ancestor(usize::MAX), None);
+    assert_eq!(
+        path.parents(),
+        [Path::new("a/c"), Path::new("a"), Path::new(".")]
+    );
+
+    assert_eq!(
+        Path::new("../x").parents(),
+        [Path::new(".."), Path::new(".")]
+    );
+    assert_eq!(Path::new("").nth_ancestor(0).unwrap(), Path::new("."));
+    assert_eq!(Path::new("").nth_ancestor(1), None);
+    assert!(Path::new(".").parents().is_empty());
+
+    let path = Path::new("x/y/z");
+    for (i, parent) in path.parents().iter().enumerate() {
+        assert_eq!(Some(parent.clone()), path.nth_ancestor(i + 1));
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(
+        Path::new("/a/b").parents(),
+        [PathBuf::from("/a"), PathBuf::from("/")]
+    );
+    assert_eq!(Path::new("/").nth_ancestor(0).unwrap(), Path::new("/"));
+    assert_eq!(Path::new("/").nth_ancestor(1), None);
+    assert_eq!(Path::new("/a/..").nth_ancestor(1), None);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("C:\\a\\b").parents(),
+        [PathBuf::from("C:\\a"), PathBuf::from("C:\\")]
+    );
+    assert_eq!(Path::new("C:\\").nth_ancestor(1), None);
+    assert_eq!(
+        Path::new("\\\\server\\share\\x").nth_ancestor(1).unwrap(),
+        Path::new("\\\\server\\share\\")
+    );
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[test]
+fn relative() {
+    for path in ["", ".", "foo", "./foo/bar", "../foo"] {
+        assert_eq!(Path::new(path).root(), None, "{:?}", path);
+        assert_eq!(Path::new(path).anchor(), Path::new(""), "{:?}", path);
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(Path::new("/").root().unwrap(), Path::new("/"));
+    assert_eq!(Path::new("/foo/../bar").root().unwrap(), Path::new("/"));
+    assert_eq!(Path::new("///foo").anchor(), Path::new("/"));
+
+    let path = Path::new("/foo/bar");
+    let (_, rest) = path.split_root();
+    assert_eq!(path.anchor().join(rest), path);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(Path::new("C:\\foo").root().unwrap(), Path::new("C:\\"));
+    assert_eq!(Path::new("C:foo").root(), None);
+    assert_eq!(Path::new("C:foo").anchor(), Path::new("C:"));
+    assert_eq!(Path::new("\\foo").root().unwrap(), Path::new("\\"));
+    assert_eq!(
+        Path::new("\\\\server\\share\\foo").root().unwrap(),
+        Path::new("\\\\server\\share\\")
+    );
+    assert_eq!(
+        Path::new("\\\\server\\share").anchor(),
+        Path::new("\\\\server\\share\\")
+    );
+    assert_eq!(
+        Path::new("\\\\?\\C:\\foo").anchor(),
+        Path::new("\\\\?\\C:\\")
+    );
+}
@@ -0,0 +1,120 @@
+use std::path::{Path, PathBuf};
+use sugar_path::{Anchor, AnchorDirs, AnchoredPathBuf, AnchoredPathError, SugarPath};
+
+struct Fixtures;
+
+impl AnchorDirs for Fixtures {
+    fn anchor_dir(anchor: Anchor) -> Option<PathBuf> {
+        let dir = match anchor {
+            Anchor::Home => "home/me",
+            Anchor::Cwd => "home/me/projects/app",
+            Anchor::ConfigDir => "home/me/.config",
+            Anchor::ManifestDir | Anchor::OutDir => return None,
+        };
+        Some(Path::new("/").join(dir).resolve())
+    }
+}
+
+struct NoHome;
+
+impl AnchorDirs for NoHome {
+    fn anchor_dir(anchor: Anchor) -> Option<PathBuf> {
+        match anchor {
+            Anchor::Home => None,
+            anchor => Fixtures::anchor_dir(anchor),
+        }
+    }
+}
+
+type Anchored = AnchoredPathBuf<Fixtures>;
+
+#[test]
+fn names() {
+    for anchor in [
+        Anchor::Home,
+        Anchor::Cwd,
+        Anchor::ConfigDir,
+        Anchor::ManifestDir,
+        Anchor::OutDir,
+    ] {
+        assert_eq!(Anchor::from_name(anchor.name()), Some(anchor));
+    }
+    assert_eq!(Anchor::from_name("Home"), None);
+    assert!(Anchor::Cwd.dir().unwrap().is_absolute());
+}
+
+#[test]
+fn new() {
+    let path = Anchored::new(Anchor::Home, "a/./b/../c").unwrap();
+    assert_eq!(path.anchor(), Anchor::Home);
+    assert_eq!(path.rel(), Path::new("a/c"));
+    assert_eq!(
+        path.path(),
+        Fixtures::anchor_dir(Anchor::Home).unwrap().join("a/c")
+    );
+
+    let path = Anchored::new(Anchor::Cwd, "").unwrap();
+    assert_eq!(path.rel().as_os_str(), ".");
+    assert_eq!(path.path(), Fixtures::anchor_dir(Anchor::Cwd).unwrap());
+
+    assert_eq!(
+        Anchored::new(Anchor::Home, "/etc"),
+        Err(AnchoredPathError::NotRelative(PathBuf::from("/etc")))
+    );
+    assert_eq!(
+        AnchoredPathBuf::<NoHome>::new(Anchor::Home, "x").unwrap_err(),
+        AnchoredPathError::UnknownAnchor(Anchor::Home)
+    );
+}
+
+#[test]
+fn from_path() {
+    let home = Fixtures::anchor_dir(Anchor::Home).unwrap();
+    let anchored = |path: &Path| {
+        let path = Anchored::from_path(path).unwrap();
+        (path.anchor(), path.rel().to_path_buf())
+    };
+    assert_eq!(
+        anchored(&home.join(".config/app/config.toml")),
+        (Anchor::ConfigDir, PathBuf::from("app/config.toml"))
+    );
+    assert_eq!(
+        anchored(&home.join("projects/app/src/../lib")),
+        (Anchor::Cwd, PathBuf::from("lib"))
+    );
+    assert_eq!(
+        anchored(&home.join("projects")),
+        (Anchor::Home, PathBuf::from("projects"))
+    );
+    assert_eq!(anchored(&home), (Anchor::Home, PathBuf::from(".")));
+    assert_eq!(Anchored::from_path(home.join("..")), None);
+
+    assert_eq!(AnchoredPathBuf::<NoHome>::from_path(home.join("x")), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let path = Anchored::new(Anchor::ConfigDir, "app/config.toml").unwrap();
+    let json = serde_json::to_string(&path).unwrap();
+    assert_eq!(json, r#"{"anchor":"config_dir","rel":"app/config.toml"}"#);
+    let loaded: Anchored = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, path);
+    assert_eq!(loaded.path(), path.path());
+
+    let loaded: Anchored = serde_json::from_str(r#"{"rel":"./a/../b","anchor":"home"}"#).unwrap();
+    assert_eq!(loaded.rel(), Path::new("b"));
+
+    for json in [
+        r#"{"anchor":"home"}"#,
+        r#"{"rel":"a"}"#,
+        r#"{"anchor":"root","rel":"a"}"#,
+        r#"{"anchor":"home","rel":"/a"}"#,
+        r#"{"anchor":"home","rel":"a","extra":1}"#,
+    ] {
+        assert!(serde_json::from_str::<Anchored>(json).is_err(), "{}", json);
+    }
+    assert!(
+        serde_json::from_str::<AnchoredPathBuf<NoHome>>(r#"{"anchor":"home","rel":"a"}"#).is_err()
+    );
+}
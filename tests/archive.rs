@@ -0,0 +1,70 @@
+use std::path::{Path, PathBuf};
+use sugar_path::{sanitize_archive_entry, ArchiveEntryError, SugarPath};
+
+fn expected(parts: &[&str]) -> PathBuf {
+    let mut path = Path::new("dest").normalize();
+    path.extend(parts);
+    path
+}
+
+#[test]
+fn sanitize() {
+    let ok = |entry: &str, parts: &[&str]| {
+        assert_eq!(
+            sanitize_archive_entry(entry, "dest"),
+            Ok(expected(parts)),
+            "{:?}",
+            entry
+        );
+    };
+    ok("a/b/c.txt", &["a", "b", "c.txt"]);
+    ok("./a//b/", &["a", "b"]);
+    ok("a\\b\\c.txt", &["a", "b", "c.txt"]);
+    ok("/etc/passwd", &["etc", "passwd"]);
+    ok("///etc/passwd", &["etc", "passwd"]);
+    ok("C:\\Windows\\win.ini", &["Windows", "win.ini"]);
+    ok("C:foo\\bar", &["foo", "bar"]);
+    ok("\\\\server\\share\\x\\y", &["x", "y"]);
+    ok("\\\\?\\C:\\x", &["x"]);
+    ok("\\\\?\\UNC\\server\\share\\x", &["x"]);
+    ok("//./COM1/x", &["x"]);
+    ok("a/b/../c", &["a", "c"]);
+    ok("a/../b", &["b"]);
+    ok("a..b/c", &["a..b", "c"]);
+
+    let err = |entry: &str, error: ArchiveEntryError| {
+        assert_eq!(
+            sanitize_archive_entry(entry, "dest"),
+            Err(error),
+            "{:?}",
+            entry
+        );
+    };
+    err("..", ArchiveEntryError::Escapes);
+    err("../x", ArchiveEntryError::Escapes);
+    err("a/../../x", ArchiveEntryError::Escapes);
+    err("a\\..\\..\\x", ArchiveEntryError::Escapes);
+    err("/../x", ArchiveEntryError::Escapes);
+    err("C:..\\x", ArchiveEntryError::Escapes);
+    err(
+        "a/nul.txt",
+        ArchiveEntryError::InvalidName("nul.txt".into()),
+    );
+    err("a/b:c", ArchiveEntryError::InvalidName("b:c".into()));
+    err("a/b?", ArchiveEntryError::InvalidName("b?".into()));
+    err("a/b./c", ArchiveEntryError::InvalidName("b.".into()));
+    err("a/b /c", ArchiveEntryError::InvalidName("b ".into()));
+    err("a/\u{1}", ArchiveEntryError::InvalidName("\u{1}".into()));
+    err("", ArchiveEntryError::Empty);
+    err("/", ArchiveEntryError::Empty);
+    err("./.", ArchiveEntryError::Empty);
+    err("a/..", ArchiveEntryError::Empty);
+}
+
+#[test]
+fn dest_dir_is_normalized() {
+    assert_eq!(
+        sanitize_archive_entry("x", "out/./a/../b"),
+        Ok(Path::new("out/b/x").normalize())
+    );
+}
@@ -0,0 +1,68 @@
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+use sugar_path::SugarPath;
+
+fn crumbs(path: &str) -> Vec<(OsString, PathBuf)> {
+    Path::new(path).breadcrumbs().collect()
+}
+
+fn crumb(name: &str, path: &str) -> (OsString, PathBuf) {
+    (name.into(), path.into())
+}
+
+#[test]
+fn relative() {
+    assert_eq!(
+        crumbs("a/./b/../c/d.txt"),
+        [
+            crumb("a", "a"),
+            crumb("c", "a/c"),
+            crumb("d.txt", "a/c/d.txt")
+        ]
+    );
+    assert_eq!(crumbs("../x"), [crumb("..", ".."), crumb("x", "../x")]);
+    assert!(crumbs("").is_empty());
+    assert!(crumbs("a/..").is_empty());
+
+    let mut iter = Path::new("a/b/c").breadcrumbs();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(crumb("c", "a/b/c")));
+    assert_eq!(iter.len(), 2);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(
+        crumbs("/usr//lib/"),
+        [
+            crumb("/", "/"),
+            crumb("usr", "/usr"),
+            crumb("lib", "/usr/lib")
+        ]
+    );
+    assert_eq!(crumbs("/.."), [crumb("/", "/")]);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        crumbs("C:/Users/me"),
+        [
+            crumb("C:\\", "C:\\"),
+            crumb("Users", "C:\\Users"),
+            crumb("me", "C:\\Users\\me")
+        ]
+    );
+    assert_eq!(crumbs("C:foo"), [crumb("C:", "C:"), crumb("foo", "C:foo")]);
+    assert_eq!(
+        crumbs("\\\\server\\share\\dir"),
+        [
+            crumb("\\\\server\\share\\", "\\\\server\\share\\"),
+            crumb("dir", "\\\\server\\share\\dir")
+        ]
+    );
+}
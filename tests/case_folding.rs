@@ -0,0 +1,129 @@
+use std::{collections::hash_map::DefaultHasher, hash::Hasher, path::Path};
+use sugar_path::{CaseSensitivity, Pattern, SugarPath};
+
+fn hash(path: &str, case: CaseSensitivity) -> u64 {
+    let mut state = DefaultHasher::new();
+    Path::new(path).hash_normalized(&mut state, case);
+    state.finish()
+}
+
+#[test]
+fn path_eq() {
+    use CaseSensitivity::*;
+    assert!(Path::new("a/b").path_eq("a/./b/", Sensitive));
+    assert!(!Path::new("a/B").path_eq("a/b", Sensitive));
+    assert!(Path::new("a/B").path_eq("a/b", AsciiInsensitive));
+    assert!(!Path::new("a/b").path_eq("a/b/c", AsciiInsensitive));
+
+    assert!(!Path::new("Ärger").path_eq("ärger", AsciiInsensitive));
+    assert!(Path::new("Ärger").path_eq("ärger", UnicodeInsensitive));
+    assert!(Path::new("ΣΊΣΥΦΟΣ").path_eq("σίσυφος", UnicodeInsensitive));
+    // Turkish dotless and dotted i both uppercase to ASCII `I`.
+    assert!(Path::new("ı").path_eq("i", UnicodeInsensitive));
+    // `ß` uppercases to two characters, so it only matches itself.
+    assert!(!Path::new("straße").path_eq("strasse", UnicodeInsensitive));
+    assert!(Path::new("straße").path_eq("STRAßE", UnicodeInsensitive));
+}
+
+#[test]
+fn hash_agrees_with_path_eq() {
+    use CaseSensitivity::*;
+    assert_eq!(
+        hash("Ärger/x", UnicodeInsensitive),
+        hash("ärger/./X", UnicodeInsensitive)
+    );
+    assert_ne!(
+        hash("Ärger", AsciiInsensitive),
+        hash("ärger", AsciiInsensitive)
+    );
+    assert_ne!(
+        hash("straße", UnicodeInsensitive),
+        hash("strasse", UnicodeInsensitive)
+    );
+}
+
+#[test]
+fn relative_with_case() {
+    assert_eq!(
+        Path::new("/Ärger/x/y")
+            .relative_with_case("/ärger/X/z", CaseSensitivity::UnicodeInsensitive),
+        Path::new("../y")
+    );
+    assert_eq!(
+        Path::new("/a/B").relative_with_case("/a/b", CaseSensitivity::Sensitive),
+        Path::new("../B")
+    );
+    assert_eq!(
+        Path::new("/a/B").relative_with_case("/A/b", CaseSensitivity::AsciiInsensitive),
+        Path::new("")
+    );
+}
+
+#[test]
+fn relative_deep() {
+    let depth = 200;
+    let upper = (0..depth).map(|i| format!("DIR{}", i)).collect::<Vec<_>>();
+    let lower = (0..depth).map(|i| format!("dir{}", i)).collect::<Vec<_>>();
+    let target = format!("/{}/File", upper.join("/"));
+    let base = format!("/{}/sub", lower.join("/"));
+    assert_eq!(
+        Path::new(&target).relative_with_case(&base, CaseSensitivity::AsciiInsensitive),
+        Path::new("../File")
+    );
+    assert_eq!(
+        Path::new(&target)
+            .relative_with_case(&base, CaseSensitivity::Sensitive)
+            .components()
+            .count(),
+        depth + 1 + depth + 1
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+    use sugar_path::{with_default_platform, Platform};
+
+    // Names that aren't valid UTF-8 are still compared ignoring ASCII case.
+    let target = Path::new(OsStr::from_bytes(b"/Data\xff/A/b"));
+    let base = Path::new(OsStr::from_bytes(b"/data\xff/a/c"));
+    for case in [
+        CaseSensitivity::AsciiInsensitive,
+        CaseSensitivity::UnicodeInsensitive,
+    ] {
+        assert_eq!(target.relative_with_case(base, case), Path::new("../b"));
+    }
+    assert_eq!(
+        target.relative_with_case(base, CaseSensitivity::Sensitive),
+        Path::new(OsStr::from_bytes(b"../../../Data\xff/A/b"))
+    );
+
+    // An overridden platform still parses the paths, `case` only decides how names compare.
+    let relative = with_default_platform(Platform::Windows, || {
+        Path::new("C:\\Ärger\\b")
+            .relative_with_case("c:\\ärger\\c", CaseSensitivity::UnicodeInsensitive)
+    });
+    assert_eq!(relative.as_os_str(), "..\\b");
+    let relative = with_default_platform(Platform::Windows, || {
+        Path::new("C:\\a\\b").relative_with_case("C:\\A\\c", CaseSensitivity::Sensitive)
+    });
+    assert_eq!(relative.as_os_str(), "..\\..\\a\\b");
+}
+
+#[test]
+fn glob() {
+    let pattern = Pattern::new("ÄRGER/*.TXT").unwrap();
+    assert!(!pattern
+        .clone()
+        .case_sensitivity(CaseSensitivity::AsciiInsensitive)
+        .matches("ärger/a.txt"));
+    assert!(pattern
+        .case_sensitivity(CaseSensitivity::UnicodeInsensitive)
+        .matches("ärger/a.txt"));
+    let pattern = Pattern::new("[α-ω]").unwrap();
+    assert!(!pattern.matches("Σ"));
+    assert!(pattern
+        .case_sensitivity(CaseSensitivity::UnicodeInsensitive)
+        .matches("Σ"));
+}
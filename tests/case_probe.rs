@@ -0,0 +1,98 @@
+#![cfg(feature = "fs")]
+
+use std::{fs, path::PathBuf};
+use sugar_path::{probe_case_sensitivity, CaseSensitivity};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sugar_path_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn probe_case_sensitivity_errors() {
+    let dir = temp_dir("case_probe_missing");
+    assert!(probe_case_sensitivity(dir.join("missing")).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn probe_case_sensitivity_concurrently() {
+    let dir = temp_dir("case_probe_threads");
+    let cases = std::thread::scope(|scope| {
+        let probes = (0..8)
+            .map(|_| scope.spawn(|| probe_case_sensitivity(&dir).unwrap()))
+            .collect::<Vec<_>>();
+        probes
+            .into_iter()
+            .map(|probe| probe.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+    assert!(cases.iter().all(|case| *case == cases[0]));
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    // Linux filesystems are case-sensitive unless casefolding is turned on for a directory.
+    if !cfg!(target_os = "linux") {
+        return;
+    }
+    let dir = temp_dir("case_probe");
+    assert_eq!(
+        probe_case_sensitivity(&dir).unwrap(),
+        CaseSensitivity::Sensitive
+    );
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+    fs::write(dir.join("123"), "").unwrap();
+    fs::write(dir.join("a"), "").unwrap();
+    fs::write(dir.join("A"), "").unwrap();
+    fs::write(dir.join("Readme"), "").unwrap();
+    assert_eq!(
+        probe_case_sensitivity(&dir).unwrap(),
+        CaseSensitivity::Sensitive
+    );
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 4);
+    fs::remove_dir_all(&dir).unwrap();
+
+    // Neither name has a one-char other case, so the probe file is used.
+    let dir = temp_dir("case_probe_unicode");
+    fs::write(dir.join("ß"), "").unwrap();
+    fs::write(dir.join("İ"), "").unwrap();
+    assert_eq!(
+        probe_case_sensitivity(&dir).unwrap(),
+        CaseSensitivity::Sensitive
+    );
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let dir = temp_dir("case_probe");
+    assert_eq!(
+        probe_case_sensitivity(&dir).unwrap(),
+        CaseSensitivity::UnicodeInsensitive
+    );
+    fs::write(dir.join("Readme"), "").unwrap();
+    assert_eq!(
+        probe_case_sensitivity(&dir).unwrap(),
+        CaseSensitivity::UnicodeInsensitive
+    );
+    fs::remove_dir_all(&dir).unwrap();
+
+    // NTFS doesn't find `ß` spelled `SS`, which mustn't make it look case-sensitive.
+    let dir = temp_dir("case_probe_unicode");
+    fs::write(dir.join("ß"), "").unwrap();
+    fs::write(dir.join("straße"), "").unwrap();
+    assert_eq!(
+        probe_case_sensitivity(&dir).unwrap(),
+        CaseSensitivity::UnicodeInsensitive
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[test]
+fn clamp_to() {
+    let base = Path::new("base");
+    assert_eq!(Path::new("a/b").clamp_to(base), Path::new("base/a/b"));
+    assert_eq!(Path::new("a/../../b").clamp_to(base), Path::new("base/b"));
+    assert_eq!(Path::new("../../..").clamp_to(base), Path::new("base"));
+    assert_eq!(Path::new("./a/./b/..").clamp_to(base), Path::new("base/a"));
+    assert_eq!(Path::new("").clamp_to(base), Path::new("base"));
+    assert_eq!(Path::new("a").clamp_to("x/../base/."), Path::new("base/a"));
+    assert_eq!(Path::new("a/..").clamp_to("").as_os_str(), ".");
+    assert_eq!(Path::new("../a").clamp_to(".").as_os_str(), "a");
+    // Only the clamped path is confined, the base itself is just normalized.
+    assert_eq!(Path::new("../a").clamp_to(".."), Path::new("../a"));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    assert_eq!(
+        Path::new("/etc/passwd").clamp_to("/srv"),
+        Path::new("/srv/etc/passwd")
+    );
+    assert_eq!(Path::new("/../..").clamp_to("/srv"), Path::new("/srv"));
+    assert_eq!(Path::new("a").clamp_to("/"), Path::new("/a"));
+    assert_eq!(Path::new("..").clamp_to("/"), Path::new("/"));
+    assert!(Path::new("../../x").clamp_to("/srv").is_relative_to("/srv"));
+
+    with_default_platform(Platform::Windows, || {
+        assert_eq!(
+            Path::new(r"..\..\Windows\System32")
+                .clamp_to(r"C:\sandbox")
+                .as_os_str(),
+            r"C:\sandbox\Windows\System32"
+        );
+        assert_eq!(
+            Path::new(r"D:\x").clamp_to("C:/sandbox/").as_os_str(),
+            r"C:\sandbox\x"
+        );
+        assert_eq!(
+            Path::new("..").clamp_to(r"C:\sandbox").as_os_str(),
+            r"C:\sandbox"
+        );
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("C:\\Windows\\..\\..\\x").clamp_to("D:\\srv"),
+        Path::new("D:\\srv\\x")
+    );
+    assert_eq!(
+        Path::new("\\\\server\\share\\x").clamp_to("D:\\srv"),
+        Path::new("D:\\srv\\x")
+    );
+    assert_eq!(Path::new("..\\x").clamp_to("D:\\"), Path::new("D:\\x"));
+}
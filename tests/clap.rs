@@ -0,0 +1,105 @@
+#![cfg(feature = "clap")]
+
+use clap::{error::ErrorKind, value_parser, Arg, Command};
+use std::path::Path;
+use sugar_path::{
+    AbsolutePathBuf, AbsolutePathParser, NormalizedPathBuf, RelativePathBuf, SugarPath,
+};
+
+fn command(arg: Arg) -> Command {
+    Command::new("app").arg(arg.required(true))
+}
+
+#[test]
+fn normalized() {
+    let cmd = command(Arg::new("path").value_parser(value_parser!(NormalizedPathBuf)));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["app", "a/./b/../c"])
+        .unwrap();
+    assert_eq!(
+        matches
+            .get_one::<NormalizedPathBuf>("path")
+            .unwrap()
+            .as_path(),
+        Path::new("a/c")
+    );
+    let err = cmd.try_get_matches_from(["app", ""]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("a path can't be empty"));
+}
+
+#[test]
+fn absolute() {
+    let cmd = command(Arg::new("path").value_parser(value_parser!(AbsolutePathBuf)));
+    let matches = cmd.try_get_matches_from(["app", "a/../b"]).unwrap();
+    assert_eq!(
+        matches
+            .get_one::<AbsolutePathBuf>("path")
+            .unwrap()
+            .as_path(),
+        Path::new("b").resolve()
+    );
+
+    let cmd = command(
+        Arg::new("path")
+            .long("path")
+            .value_parser(AbsolutePathParser::new().require_absolute(true)),
+    );
+    let err = cmd
+        .try_get_matches_from(["app", "--path", "b"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    let message = err.to_string();
+    assert!(
+        message.contains("invalid value 'b' for '--path <path>'"),
+        "{}",
+        message
+    );
+    assert!(message.contains("is not an absolute path"), "{}", message);
+}
+
+#[test]
+fn relative() {
+    let cmd = command(Arg::new("path").value_parser(value_parser!(RelativePathBuf)));
+    let matches = cmd.try_get_matches_from(["app", "./a/"]).unwrap();
+    assert_eq!(
+        matches
+            .get_one::<RelativePathBuf>("path")
+            .unwrap()
+            .as_path(),
+        Path::new("a")
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let cmd =
+        command(Arg::new("path").value_parser(AbsolutePathParser::new().require_absolute(true)));
+    let matches = cmd.try_get_matches_from(["app", "/a/../b"]).unwrap();
+    assert_eq!(
+        matches
+            .get_one::<AbsolutePathBuf>("path")
+            .unwrap()
+            .as_path(),
+        Path::new("/b")
+    );
+
+    let cmd = command(Arg::new("path").value_parser(value_parser!(RelativePathBuf)));
+    let err = cmd.try_get_matches_from(["app", "/a"]).unwrap_err();
+    assert!(err.to_string().contains("is not a relative path"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let cmd = command(Arg::new("path").value_parser(value_parser!(RelativePathBuf)));
+    for path in ["C:\\a", "C:a", "\\a"] {
+        assert!(
+            cmd.clone().try_get_matches_from(["app", path]).is_err(),
+            "{:?}",
+            path
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use std::path::Path;
+use sugar_path::{ComponentError, SugarPath};
+
+#[test]
+fn replace() {
+    assert_eq!(
+        Path::new("a/./b/../c/d").replace_component(1, "x").unwrap(),
+        Path::new("a/x/d")
+    );
+    assert_eq!(
+        Path::new("a/b").replace_component(2, "x"),
+        Err(ComponentError::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    for segment in ["", ".", "..", "x/y", "x\0y"] {
+        assert_eq!(
+            Path::new("a/b").replace_component(0, segment),
+            Err(ComponentError::InvalidSegment(segment.into()))
+        );
+    }
+}
+
+#[test]
+fn insert() {
+    assert_eq!(
+        Path::new("a/b").insert_component(0, "x").unwrap(),
+        Path::new("x/a/b")
+    );
+    assert_eq!(
+        Path::new("").insert_component(0, "x").unwrap(),
+        Path::new("x")
+    );
+    assert_eq!(
+        Path::new("a").insert_component(2, "x"),
+        Err(ComponentError::IndexOutOfBounds { index: 2, len: 1 })
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(
+        Path::new("/a/b/c").replace_component(0, "x").unwrap(),
+        Path::new("/x/b/c")
+    );
+    assert_eq!(
+        Path::new("/").insert_component(0, "x").unwrap(),
+        Path::new("/x")
+    );
+    assert_eq!(
+        Path::new("../a").replace_component(0, "x").unwrap(),
+        Path::new("x/a")
+    );
+    // `\` isn't a separator on POSIX.
+    assert_eq!(
+        Path::new("/a").replace_component(0, "x\\y").unwrap(),
+        Path::new("/x\\y")
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("C:\\a\\b").replace_component(0, "x").unwrap(),
+        Path::new("C:\\x\\b")
+    );
+    assert!(Path::new("C:\\a").replace_component(0, "x\\y").is_err());
+}
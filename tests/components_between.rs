@@ -0,0 +1,93 @@
+use std::path::{Component, Path, PathBuf};
+use sugar_path::{SugarPath, TryRelativeError};
+
+fn between(path: &str, base: &str) -> Result<PathBuf, TryRelativeError> {
+    Path::new(path)
+        .components_between(base)
+        .map(|components| components.collect())
+}
+
+#[test]
+fn matches_try_relative() {
+    for (path, base) in [
+        ("a/b/c", "a/b"),
+        ("a/b", "a/b/c/d"),
+        ("a/x/y", "a/b/c"),
+        ("a", "b"),
+        ("a", "a"),
+        ("../x", "a"),
+        ("../x", "../y"),
+        ("./a/b", "a/./c"),
+    ] {
+        assert_eq!(
+            between(path, base).unwrap(),
+            Path::new(path).try_relative(base).unwrap(),
+            "{} from {}",
+            path,
+            base
+        );
+    }
+    assert_eq!(between("", "").unwrap(), Path::new(""));
+    assert_eq!(between("a", "").unwrap(), Path::new("a"));
+    assert_eq!(between("", "a/b").unwrap(), Path::new("../.."));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        between("x", "../a"),
+        Err(TryRelativeError::UnresolvedParent)
+    );
+    assert_eq!(
+        between("a/x", "a/../b"),
+        Err(TryRelativeError::UnresolvedParent)
+    );
+}
+
+#[test]
+fn yields_components() {
+    let mut components = Path::new("a/x/y").components_between("a/b/c").unwrap();
+    assert_eq!(components.size_hint(), (2, None));
+    assert_eq!(components.next(), Some(Component::ParentDir));
+    assert_eq!(components.next(), Some(Component::ParentDir));
+    assert_eq!(components.next(), Some(Component::Normal("x".as_ref())));
+    assert_eq!(components.next(), Some(Component::Normal("y".as_ref())));
+    assert_eq!(components.next(), None);
+    assert_eq!(components.next(), None);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(between("/a/b/c", "/a/d").unwrap(), Path::new("../b/c"));
+    assert_eq!(between("/", "/a/b").unwrap(), Path::new("../.."));
+    assert_eq!(between("/A", "/a").unwrap(), Path::new("../A"));
+    assert_eq!(between("/a", "a"), Err(TryRelativeError::DifferentRoots));
+    assert_eq!(between("a", "/a"), Err(TryRelativeError::DifferentRoots));
+    assert_eq!(between("", "/"), Err(TryRelativeError::DifferentRoots));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        between("C:\\a\\B\\c", "c:\\A\\b\\d").unwrap(),
+        Path::new("..\\c")
+    );
+    assert_eq!(
+        between("C:\\a", "D:\\a"),
+        Err(TryRelativeError::DifferentRoots)
+    );
+    assert_eq!(
+        between("C:\\a", "C:a"),
+        Err(TryRelativeError::DifferentRoots)
+    );
+    assert_eq!(
+        between("\\a", "C:\\a"),
+        Err(TryRelativeError::DifferentRoots)
+    );
+    assert_eq!(
+        between("\\\\server\\share\\a", "\\\\server\\share\\b").unwrap(),
+        Path::new("..\\a")
+    );
+}
@@ -0,0 +1,74 @@
+use std::path::{Path, PathBuf};
+use sugar_path::SugarPath;
+
+/// Compares the exact strings, so that a stray trailing separator fails.
+fn assert_concat(a: &str, b: &str, expected: &str) {
+    let concat: PathBuf = Path::new(a).concat_overlapping(b);
+    assert_eq!(
+        concat.as_os_str(),
+        Path::new(expected).normalize().as_os_str(),
+        "{:?} + {:?}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn concat_overlapping() {
+    assert_concat("a/b/c", "b/c/d", "a/b/c/d");
+    assert_concat("a/b/c", "c/d", "a/b/c/d");
+    assert_concat("a/b/c", "a/b/c", "a/b/c");
+    assert_concat("a/b/c", "b/c", "a/b/c");
+    // The longest overlap wins.
+    assert_concat("x/a/x/a", "x/a/y", "x/a/x/a/y");
+    assert_concat("a/b", "x/y", "a/b/x/y");
+    // Only a suffix of the path can overlap.
+    assert_concat("a/b/c", "b/d", "a/b/c/b/d");
+    assert_concat("./a/./b/", "x/../b/c", "a/b/c");
+    assert_concat("a/b", "../c", "a/c");
+    assert_concat("..", "../a", "../../a");
+    assert_concat("", "a", "a");
+    assert_concat("a", "", "a");
+    assert_concat("a/b", "./b", "a/b");
+    assert_concat("a/b/", "b", "a/b");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    assert_concat("/ws/pkg/src", "src/lib.rs", "/ws/pkg/src/lib.rs");
+    assert_concat("/ws/pkg", "/other/x", "/other/x");
+    assert_concat("/ws/Pkg", "pkg/x", "/ws/Pkg/pkg/x");
+    assert_concat("/", "a", "/a");
+
+    with_default_platform(Platform::Windows, || {
+        for (a, b, expected) in [
+            (r"C:\ws\Pkg", r"pkg\x", r"C:\ws\Pkg\x"),
+            (r"C:\ws", r"\other", r"C:\other"),
+            (r"C:\ws", r"D:\x", r"D:\x"),
+            ("a/b", "b/", r"a\b"),
+        ] {
+            assert_eq!(
+                Path::new(a).concat_overlapping(b).as_os_str(),
+                expected,
+                "{:?} {:?}",
+                a,
+                b
+            );
+        }
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_concat(
+        "C:\\ws\\Pkg\\src",
+        "pkg/SRC/lib.rs",
+        "C:\\ws\\Pkg\\src\\lib.rs",
+    );
+    assert_concat("C:\\ws", "D:\\x", "D:\\x");
+    assert_concat("C:\\ws", "\\x", "C:\\x");
+}
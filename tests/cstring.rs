@@ -0,0 +1,52 @@
+use std::path::Path;
+use sugar_path::{AbsolutePathBuf, NormalizedPathBuf, RelativePathBuf, SugarPath};
+
+#[test]
+fn to_cstring() {
+    let path = NormalizedPathBuf::new("a/./b");
+    assert_eq!(
+        path.to_cstring().unwrap().as_bytes(),
+        path.as_os_str().as_encoded_bytes()
+    );
+    let path = AbsolutePathBuf::resolve("a");
+    assert_eq!(
+        path.to_cstring().unwrap().to_bytes(),
+        Path::new("a").resolve().as_os_str().as_encoded_bytes()
+    );
+    let err = RelativePathBuf::new("a/b\0c")
+        .unwrap()
+        .to_cstring()
+        .unwrap_err();
+    assert_eq!(err.nul_position(), 3);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::ffi::CString;
+
+    let path = NormalizedPathBuf::from_bytes(b"a//b/../\xffc");
+    assert_eq!(path.as_bytes(), b"a/\xffc");
+    assert_eq!(
+        path.to_cstring().unwrap(),
+        CString::new(b"a/\xffc".to_vec()).unwrap()
+    );
+
+    assert_eq!(
+        AbsolutePathBuf::from_bytes(b"/a/../b").unwrap().as_bytes(),
+        b"/b"
+    );
+    assert!(AbsolutePathBuf::from_bytes(b"a").is_err());
+    assert_eq!(
+        RelativePathBuf::from_bytes(b"./a").unwrap().as_bytes(),
+        b"a"
+    );
+    assert!(RelativePathBuf::from_bytes(b"/a").is_err());
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let path = AbsolutePathBuf::new("C:\\a\\..\\b").unwrap();
+    assert_eq!(path.to_cstring().unwrap().to_bytes(), b"C:\\b");
+}
@@ -0,0 +1,31 @@
+use std::path::Path;
+use sugar_path::{
+    default_platform, set_default_platform, with_default_platform, Platform, SugarPath,
+};
+
+// The default is process-wide, so this is the only test of this file.
+#[test]
+fn set_default_platform_globally() {
+    let foreign = match Platform::current() {
+        Platform::Posix => Platform::Windows,
+        Platform::Windows => Platform::Posix,
+    };
+    set_default_platform(Some(foreign));
+    assert_eq!(default_platform(), foreign);
+    assert_eq!(
+        Path::new("a/./b").normalize(),
+        Path::new("a/./b").normalize_as(foreign)
+    );
+    let other = std::thread::spawn(default_platform).join().unwrap();
+    assert_eq!(other, foreign);
+    // A scope still wins.
+    with_default_platform(Platform::current(), || {
+        assert_eq!(default_platform(), Platform::current());
+    });
+
+    set_default_platform(Some(Platform::current()));
+    assert_eq!(default_platform(), Platform::current());
+    set_default_platform(None);
+    assert_eq!(default_platform(), Platform::current());
+    assert_eq!(Path::new("a/./b").normalize(), Path::new("a/b"));
+}
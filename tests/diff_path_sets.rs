@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+use sugar_path::{diff_path_sets, MovedSubtree, PathSetDiff};
+
+fn paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+fn moved(from: &str, to: &str, beneath: &[&str]) -> MovedSubtree {
+    MovedSubtree {
+        from: PathBuf::from(from),
+        to: PathBuf::from(to),
+        paths: paths(beneath),
+    }
+}
+
+#[test]
+fn added_and_removed() {
+    let empty: [&str; 0] = [];
+    assert_eq!(diff_path_sets(empty, empty), PathSetDiff::default());
+
+    let diff = diff_path_sets(["a", "b", "c/../b", "d/e"], ["./a", "c", "d/f"]);
+    assert_eq!(diff.added, paths(&["c", "d/f"]));
+    assert_eq!(diff.removed, paths(&["b", "d/e"]));
+    assert!(diff.moved.is_empty());
+}
+
+#[test]
+fn moves() {
+    // Only the outermost directory is reported.
+    let diff = diff_path_sets(["a/b/c/x", "a/b/y"], ["z/b/c/x", "z/b/y"]);
+    assert_eq!(diff.moved, [moved("a", "z", &["b/c/x", "b/y"])]);
+    assert!(diff.added.is_empty() && diff.removed.is_empty());
+
+    // A directory with a file left behind didn't move as a whole, its subdirectory did.
+    let diff = diff_path_sets(["a/keep", "a/b/x", "a/b/y"], ["a/keep", "c/b/x", "c/b/y"]);
+    assert_eq!(diff.moved, [moved("a/b", "c/b", &["x", "y"])]);
+
+    // The target must hold exactly the same paths.
+    let diff = diff_path_sets(["a/x", "a/y"], ["b/x", "b/y", "b/z"]);
+    assert!(diff.moved.is_empty());
+    assert_eq!(diff.removed, paths(&["a/x", "a/y"]));
+    assert_eq!(diff.added, paths(&["b/x", "b/y", "b/z"]));
+
+    // The target must be new.
+    let diff = diff_path_sets(["a/x", "b/y"], ["b/x", "b/y"]);
+    assert!(diff.moved.is_empty());
+
+    // Moving into a subdirectory of itself.
+    let diff = diff_path_sets(["a/x", "a/y"], ["a/old/x", "a/old/y"]);
+    assert_eq!(diff.moved, [moved("a", "a/old", &["x", "y"])]);
+
+    // Two moves of identical trees each get their own target.
+    let diff = diff_path_sets(["a/x", "b/x", "keep"], ["c/x", "d/x", "keep"]);
+    assert_eq!(
+        diff.moved,
+        [moved("a", "c", &["x"]), moved("b", "d", &["x"])]
+    );
+    assert!(diff.added.is_empty() && diff.removed.is_empty());
+
+    // Paths at the top level can't form a move.
+    let diff = diff_path_sets(["x"], ["y/x"]);
+    assert!(diff.moved.is_empty());
+    let diff = diff_path_sets(["../a/x"], ["../b/x"]);
+    assert_eq!(diff.moved, [moved("../a", "../b", &["x"])]);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let diff = diff_path_sets(["/srv/a/x", "/srv/a/y/z"], ["/opt/a/x", "/opt/a/y/z"]);
+    assert_eq!(diff.moved, [moved("/srv", "/opt", &["a/x", "a/y/z"])]);
+    let diff = diff_path_sets(["/x"], ["/y"]);
+    assert_eq!(diff.removed, [Path::new("/x")]);
+    assert_eq!(diff.added, [Path::new("/y")]);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let diff = diff_path_sets(["C:\\a\\x", "C:\\a\\y"], ["D:\\a\\x", "D:\\a\\y"]);
+    assert_eq!(diff.moved, [moved("C:\\a", "D:\\a", &["x", "y"])]);
+    let diff = diff_path_sets(["C:\\x"], ["D:\\x"]);
+    assert_eq!(diff.removed, [Path::new("C:\\x")]);
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(Path::new("/foo").drive(), None);
+    assert_eq!(Path::new("C:\\foo").drive(), None);
+    assert_eq!(Path::new("/foo").with_drive("D:"), Path::new("/foo"));
+    assert_eq!(Path::new("C:\\foo").with_drive("D:"), Path::new("C:\\foo"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    use std::ffi::OsStr;
+
+    assert_eq!(Path::new("C:\\foo").drive(), Some(OsStr::new("C:")));
+    assert_eq!(Path::new("c:foo").drive(), Some(OsStr::new("c:")));
+    assert_eq!(
+        Path::new("\\\\server\\share\\foo").drive(),
+        Some(OsStr::new("\\\\server\\share"))
+    );
+    assert_eq!(Path::new("\\foo").drive(), None);
+    assert_eq!(Path::new("foo").drive(), None);
+
+    assert_eq!(Path::new("C:\\foo").with_drive("D:"), Path::new("D:\\foo"));
+    assert_eq!(Path::new("C:foo").with_drive("D:"), Path::new("D:foo"));
+    assert_eq!(Path::new("\\foo").with_drive("D:"), Path::new("D:\\foo"));
+    assert_eq!(Path::new("foo").with_drive("D:"), Path::new("D:foo"));
+    assert_eq!(
+        Path::new("C:\\foo").with_drive("\\\\server\\share"),
+        Path::new("\\\\server\\share\\foo")
+    );
+    assert_eq!(
+        Path::new("foo").with_drive("\\\\server\\share"),
+        Path::new("\\\\server\\share\\foo")
+    );
+    assert_eq!(
+        Path::new("\\\\server\\share\\foo").with_drive("E:"),
+        Path::new("E:\\foo")
+    );
+}
@@ -0,0 +1,80 @@
+use std::path::Path;
+use sugar_path::{decode_components, DecodeComponentsError, SugarPath};
+
+fn round_trip(path: &Path) {
+    let encoded = path.encode_components();
+    assert!(!encoded.contains('\\'));
+    assert_eq!(decode_components(&encoded).unwrap(), path.normalize());
+}
+
+#[test]
+fn encode_decode() {
+    assert_eq!(Path::new("").encode_components(), ".");
+    assert_eq!(Path::new("../a/./b/").encode_components(), "../a/b");
+    assert_eq!(Path::new("100%/%25").encode_components(), "100%25/%2525");
+    assert_eq!(
+        decode_components("100%25/%2525").unwrap(),
+        Path::new("100%/%25")
+    );
+    assert_eq!(decode_components("%41%62c").unwrap(), Path::new("Abc"));
+
+    for path in ["", ".", "..", "a/b", "100%/%25", "a b/ü/%%/x.y", "../../z"] {
+        round_trip(Path::new(path));
+    }
+
+    assert_eq!(
+        decode_components("a/b%2"),
+        Err(DecodeComponentsError::InvalidEscape(3))
+    );
+    assert_eq!(
+        decode_components("a/%zz"),
+        Err(DecodeComponentsError::InvalidEscape(2))
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    assert_eq!(Path::new("/").encode_components(), "/");
+    assert_eq!(Path::new("/a\\b/c").encode_components(), "/a%5Cb/c");
+    assert_eq!(decode_components("/a%5Cb/c").unwrap(), Path::new("/a\\b/c"));
+
+    let invalid = Path::new(OsStr::from_bytes(b"/x/\xff\xfe%"));
+    assert_eq!(invalid.encode_components(), "/x/%FF%FE%25");
+    round_trip(invalid);
+    for path in ["/", "/a\\b/c", "//x"] {
+        round_trip(Path::new(path));
+    }
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    use std::path::PathBuf;
+
+    assert_eq!(Path::new("C:/a/b").encode_components(), "C:/a/b");
+    assert_eq!(Path::new("C:a").encode_components(), "C:a");
+    assert_eq!(
+        Path::new("\\\\server\\share\\x").encode_components(),
+        "%5C%5Cserver%5Cshare/x"
+    );
+    assert_eq!(
+        decode_components("%5C%5Cserver%5Cshare/x").unwrap(),
+        PathBuf::from("\\\\server\\share\\x")
+    );
+    for path in [
+        "C:\\",
+        "C:a",
+        "C:\\a\\b",
+        "\\\\server\\share\\x",
+        "\\\\?\\C:\\x",
+    ] {
+        round_trip(Path::new(path));
+    }
+    assert_eq!(
+        decode_components("%FF"),
+        Err(DecodeComponentsError::NotUnicode)
+    );
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[test]
+fn escape_depth() {
+    let depth = |path: &str| Path::new(path).escape_depth();
+    assert_eq!(depth(""), 0);
+    assert_eq!(depth("."), 0);
+    assert_eq!(depth("a/b/c"), 0);
+    assert_eq!(depth("a/.."), 0);
+    assert_eq!(depth(".."), 1);
+    assert_eq!(depth("../"), 1);
+    assert_eq!(depth("./../."), 1);
+    assert_eq!(depth("a/../../b"), 1);
+    assert_eq!(depth("../../.."), 3);
+    assert_eq!(depth("a/b/../../../../c/d"), 2);
+    assert_eq!(depth("../a/../../b/.."), 2);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    assert_eq!(Path::new("/..").escape_depth(), 0);
+    assert_eq!(Path::new("/a/../../b").escape_depth(), 0);
+    assert_eq!(Path::new("..\\x").escape_depth(), 0);
+
+    with_default_platform(Platform::Windows, || {
+        assert_eq!(Path::new(r"..\..\x").escape_depth(), 2);
+        assert_eq!(Path::new(r"C:\..\x").escape_depth(), 0);
+        assert_eq!(Path::new(r"C:..\x").escape_depth(), 1);
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(Path::new("..\\x").escape_depth(), 1);
+    assert_eq!(Path::new("C:\\..\\..").escape_depth(), 0);
+    assert_eq!(Path::new("\\..").escape_depth(), 0);
+    assert_eq!(Path::new("C:..\\..\\x").escape_depth(), 2);
+    assert_eq!(Path::new("\\\\server\\share\\..").escape_depth(), 0);
+}
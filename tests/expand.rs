@@ -0,0 +1,128 @@
+use std::{env, path::Path};
+use sugar_path::{expand, ExpandError, SugarPath};
+
+#[test]
+fn variables() {
+    env::set_var("SUGAR_PATH_EXPAND_DIR", "out/./dist");
+    env::set_var("SUGAR_PATH_EXPAND_TILDE", "~");
+    assert_eq!(
+        expand("$SUGAR_PATH_EXPAND_DIR/a").unwrap(),
+        Path::new("out/dist/a")
+    );
+    assert_eq!(
+        expand("x${SUGAR_PATH_EXPAND_DIR}y/../z").unwrap(),
+        Path::new("xout/z")
+    );
+    assert_eq!(
+        expand("$SUGAR_PATH_EXPAND_DIR.bak").unwrap(),
+        Path::new("out/dist.bak")
+    );
+    // A `~` coming from a variable stays.
+    assert_eq!(
+        expand("$SUGAR_PATH_EXPAND_TILDE/a").unwrap(),
+        Path::new("~/a")
+    );
+    for literal in ["a$", "a/$-b", "$1/x", "100%", "50%/a%"] {
+        assert_eq!(expand(literal).unwrap(), Path::new(literal).normalize());
+    }
+    assert_eq!(expand("").unwrap(), Path::new("."));
+}
+
+#[test]
+fn errors() {
+    assert!(matches!(
+        expand("a/$SUGAR_PATH_EXPAND_UNSET/b"),
+        Err(ExpandError::MissingVariable(name)) if name == "SUGAR_PATH_EXPAND_UNSET"
+    ));
+    assert!(matches!(
+        expand("a/${SUGAR_PATH_EXPAND_DIR"),
+        Err(ExpandError::UnclosedBrace(2))
+    ));
+    assert!(matches!(
+        expand("${}"),
+        Err(ExpandError::MissingVariable(name)) if name.is_empty()
+    ));
+    assert!(matches!(
+        expand("~sugar-path-no-such-user/a"),
+        Err(ExpandError::User(_))
+    ));
+}
+
+#[test]
+fn tilde() {
+    env::set_var("SUGAR_PATH_EXPAND_NAME", "notes");
+    if let Ok(home) = Path::new("~").expand_user() {
+        assert_eq!(
+            expand("~/./$SUGAR_PATH_EXPAND_NAME/../a").unwrap(),
+            home.join("a").normalize()
+        );
+        assert_eq!(expand("~").unwrap(), home.normalize());
+    }
+    assert_eq!(expand("a/~").unwrap(), Path::new("a/~"));
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn glob() {
+    use sugar_path::{expand_glob, VirtualTree};
+
+    let tree: VirtualTree = [
+        "conf/a.toml",
+        "conf/b.toml",
+        "conf/nested/c.toml",
+        "conf/d.json",
+    ]
+    .into_iter()
+    .collect();
+    env::set_var("SUGAR_PATH_EXPAND_CONF", "conf");
+    assert_eq!(
+        expand_glob("$SUGAR_PATH_EXPAND_CONF/**/*.toml", &tree).unwrap(),
+        [
+            Path::new("conf/a.toml"),
+            Path::new("conf/b.toml"),
+            Path::new("conf/nested/c.toml"),
+        ]
+    );
+    assert_eq!(
+        expand_glob("*/[d]*", &tree).unwrap(),
+        [Path::new("conf/d.json")]
+    );
+    assert!(expand_glob("conf/*.yaml", &tree).unwrap().is_empty());
+    assert_eq!(
+        expand_glob("./conf/../conf/a.toml", &tree).unwrap(),
+        [Path::new("conf/a.toml")]
+    );
+    assert!(matches!(
+        expand_glob("conf/[a", &tree),
+        Err(ExpandError::Pattern(_))
+    ));
+    assert!(matches!(
+        expand_glob("missing/*", &tree),
+        Err(ExpandError::Io(_))
+    ));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    env::set_var("SUGAR_PATH_EXPAND_ROOT", "/srv//app");
+    assert_eq!(
+        expand("$SUGAR_PATH_EXPAND_ROOT/../%SUGAR_PATH_EXPAND_ROOT%").unwrap(),
+        Path::new("/srv/%SUGAR_PATH_EXPAND_ROOT%")
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    env::set_var("SUGAR_PATH_EXPAND_ROOT", "C:/srv//app");
+    assert_eq!(
+        expand("%SUGAR_PATH_EXPAND_ROOT%\\..\\lib").unwrap(),
+        Path::new("C:\\srv\\lib")
+    );
+    assert_eq!(expand("100%").unwrap(), Path::new("100%"));
+    assert!(matches!(
+        expand("%SUGAR_PATH_EXPAND_UNSET%"),
+        Err(ExpandError::MissingVariable(_))
+    ));
+}
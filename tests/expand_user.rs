@@ -0,0 +1,73 @@
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+use sugar_path::{ExpandUserError, SugarPath};
+
+fn lookup(user: Option<&OsStr>) -> Option<PathBuf> {
+    match user.map(|user| user.to_str().unwrap()) {
+        None => Some(PathBuf::from("home/me")),
+        Some("alice") => Some(PathBuf::from("home/alice")),
+        Some(_) => None,
+    }
+}
+
+#[test]
+fn expand_user_with() {
+    let expand = |path: &str| Path::new(path).expand_user_with(lookup);
+    assert_eq!(expand("~").unwrap(), Path::new("home/me"));
+    assert_eq!(expand("~/").unwrap(), Path::new("home/me"));
+    assert_eq!(expand("~/a/b").unwrap(), Path::new("home/me/a/b"));
+    assert_eq!(expand("~alice").unwrap(), Path::new("home/alice"));
+    assert_eq!(
+        expand("~alice/x/../y").unwrap(),
+        Path::new("home/alice/x/../y")
+    );
+    assert_eq!(
+        expand("~bob/x"),
+        Err(ExpandUserError::UnknownUser("bob".into()))
+    );
+    assert_eq!(expand("a/~").unwrap(), Path::new("a/~"));
+    assert_eq!(expand("").unwrap(), Path::new(""));
+    assert_eq!(expand("x~").unwrap(), Path::new("x~"));
+
+    assert_eq!(
+        Path::new("~").expand_user_with(|_| None),
+        Err(ExpandUserError::NoHome)
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(
+        Path::new("/~/x").expand_user_with(lookup).unwrap(),
+        Path::new("/~/x")
+    );
+    #[cfg(feature = "fs")]
+    {
+        if std::fs::read_to_string("/etc/passwd").is_ok_and(|passwd| passwd.starts_with("root:")) {
+            assert!(Path::new("~root/x").expand_user().unwrap().ends_with("x"));
+        }
+        assert!(matches!(
+            Path::new("~no-such-user-here").expand_user(),
+            Err(ExpandUserError::UnknownUser(_))
+        ));
+    }
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("~\\a").expand_user_with(lookup).unwrap(),
+        Path::new("home/me/a")
+    );
+    if let Some(profile) = std::env::var_os("USERPROFILE") {
+        let profile = PathBuf::from(profile);
+        assert_eq!(
+            Path::new("~other").expand_user().unwrap(),
+            profile.parent().unwrap().join("other")
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[test]
+fn explicitly_relative() {
+    for path in ["./", "./foo", "../foo", ".//foo", "../../foo", "./../foo"] {
+        assert!(Path::new(path).is_explicitly_relative(), "{:?}", path);
+    }
+    for path in [
+        "",
+        ".",
+        "..",
+        "foo",
+        "foo/./bar",
+        ".foo/bar",
+        "..foo/bar",
+        ".../foo",
+    ] {
+        assert!(!Path::new(path).is_explicitly_relative(), "{:?}", path);
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert!(!Path::new("/./foo").is_explicitly_relative());
+    assert!(!Path::new(".\\foo").is_explicitly_relative());
+    assert!(!Path::new("..\\foo").is_explicitly_relative());
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert!(Path::new(".\\foo").is_explicitly_relative());
+    assert!(Path::new("..\\foo").is_explicitly_relative());
+    assert!(!Path::new("C:.\\foo").is_explicitly_relative());
+    assert!(!Path::new("\\.\\foo").is_explicitly_relative());
+}
@@ -0,0 +1,88 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    path::Path,
+};
+use sugar_path::{Extension, ExtensionError, SugarPath};
+
+fn hash(extension: &Extension) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    extension.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn new() {
+    assert_eq!(Extension::new("rs").unwrap().as_str(), "rs");
+    assert_eq!(Extension::new(".Rs").unwrap().as_str(), "Rs");
+    assert_eq!("TOML".parse::<Extension>().unwrap(), Extension::TOML);
+    assert_eq!(Extension::try_from("md").unwrap(), Extension::MD);
+    for invalid in ["", ".", "..rs", "tar.gz", "a/b", "a\0", "rs."] {
+        assert_eq!(
+            Extension::new(invalid),
+            Err(ExtensionError {
+                extension: invalid.to_string()
+            }),
+            "{:?}",
+            invalid
+        );
+    }
+    assert_eq!(
+        Extension::new("a/b").unwrap_err().to_string(),
+        "\"a/b\" is not a valid extension"
+    );
+}
+
+#[test]
+fn case_insensitive() {
+    let upper = Extension::new("JSON").unwrap();
+    assert_eq!(upper, Extension::JSON);
+    assert_eq!(hash(&upper), hash(&Extension::JSON));
+    assert_ne!(Extension::JS, Extension::JSON);
+    assert_ne!(Extension::TS, Extension::TSX);
+    assert_eq!(Extension::YAML, "YAML");
+    assert_eq!(Extension::YAML, *".yaml");
+    assert_ne!(Extension::YAML, "yml");
+    assert_eq!(upper.to_string(), "JSON");
+
+    let mut loaders = HashMap::new();
+    loaders.insert(Extension::TS, "ts");
+    loaders.insert(Extension::new("Ts").unwrap(), "typescript");
+    assert_eq!(loaders.len(), 1);
+    assert_eq!(
+        loaders[&Path::new("index.TS").extension_typed().unwrap()],
+        "typescript"
+    );
+}
+
+#[test]
+fn extension_typed() {
+    assert_eq!(Path::new("a/b.Png").extension_typed(), Some(Extension::PNG));
+    assert_eq!(Path::new("a.tar.gz").extension_typed(), Some(Extension::GZ));
+    for path in ["", "a", ".env", "a/", "a/..", "a."] {
+        assert_eq!(Path::new(path).extension_typed(), None, "{:?}", path);
+    }
+    assert!(Extension::HTML.matches("public/INDEX.HTML"));
+    assert!(!Extension::HTML.matches("public/index.htm"));
+    assert!(!Extension::GZ.matches("gz"));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let path = Path::new(OsStr::from_bytes(b"a.\xff"));
+    assert_eq!(path.extension_typed(), None);
+    assert!(Extension::new("a\\b").is_ok());
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert!(Extension::new("a\\b").is_err());
+    assert_eq!(
+        Path::new("C:\\a\\b.C").extension_typed(),
+        Some(Extension::C)
+    );
+}
@@ -0,0 +1,84 @@
+use std::path::Path;
+use sugar_path::{Casing, SugarPath};
+
+#[test]
+fn apply() {
+    assert_eq!(Casing::Lower.apply("ÀB c"), "àb c");
+    assert_eq!(Casing::Upper.apply("àb c"), "ÀB C");
+    assert_eq!(Casing::Kebab.apply("myFileName"), "my-file-name");
+    assert_eq!(Casing::Kebab.apply("MyFileName"), "my-file-name");
+    assert_eq!(Casing::Kebab.apply("my_file  name"), "my-file-name");
+    assert_eq!(Casing::Kebab.apply("__my--file__"), "my-file");
+    assert_eq!(Casing::Kebab.apply("parseURL2Html"), "parse-url2-html");
+    assert_eq!(Casing::Kebab.apply("v2Final"), "v2-final");
+    assert_eq!(Casing::Kebab.apply("ABC"), "abc");
+    assert_eq!(Casing::Kebab.apply("a.B"), "a.b");
+    assert_eq!(
+        Casing::Snake.apply("Hello World-Again"),
+        "hello_world_again"
+    );
+    assert_eq!(Casing::Snake.apply("ÉtéChaud"), "été_chaud");
+    assert_eq!(Casing::Snake.apply(""), "");
+}
+
+#[test]
+fn with_file_name_casing() {
+    let casing = |path: &str, casing: Casing, extension: bool| {
+        Path::new(path)
+            .with_file_name_casing(casing, extension)
+            .into_os_string()
+    };
+    assert_eq!(
+        casing("Dir/My File.TXT", Casing::Snake, false),
+        "Dir/my_file.TXT"
+    );
+    assert_eq!(
+        casing("Dir/My File.TXT", Casing::Snake, true),
+        "Dir/my_file.txt"
+    );
+    assert_eq!(
+        casing("Dir/My File.txt", Casing::Upper, true),
+        "Dir/MY FILE.TXT"
+    );
+    assert_eq!(
+        casing("Dir/My File.txt", Casing::Upper, false),
+        "Dir/MY FILE.txt"
+    );
+    assert_eq!(
+        casing("Dir/archive.Tar.GZ", Casing::Lower, true),
+        "Dir/archive.tar.gz"
+    );
+    assert_eq!(
+        casing("Dir/archive.Tar.GZ", Casing::Lower, false),
+        "Dir/archive.tar.GZ"
+    );
+    assert_eq!(casing("Dir/.EnvRC", Casing::Kebab, true), "Dir/.env-rc");
+    assert_eq!(casing("README", Casing::Lower, true), "readme");
+    assert_eq!(casing("A/B/", Casing::Lower, true), "A/b");
+    assert_eq!(casing("A/..", Casing::Lower, true), "A/..");
+    assert_eq!(casing("", Casing::Lower, true), "");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let path = Path::new(OsStr::from_bytes(b"A/\xffB"));
+    assert_eq!(path.with_file_name_casing(Casing::Lower, true), path);
+    assert_eq!(
+        Path::new("/").with_file_name_casing(Casing::Lower, true),
+        Path::new("/")
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("C:\\Assets\\Big Logo.SVG")
+            .with_file_name_casing(Casing::Kebab, true)
+            .into_os_string(),
+        "C:\\Assets\\big-logo.svg"
+    );
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "fs")]
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+use sugar_path::{FileKind, FileSystem, StdFileSystem, VirtualTree};
+
+#[test]
+fn std_file_system() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let fs = StdFileSystem;
+    assert!(fs.exists(&root.join("Cargo.toml")));
+    assert!(!fs.exists(&root.join("missing")));
+    assert!(fs.metadata(&root.join("src")).unwrap().is_dir());
+    let metadata = fs.metadata(&root.join("Cargo.toml")).unwrap();
+    assert_eq!(metadata.kind, FileKind::File);
+    assert!(metadata.len > 0);
+    let entries = fs.read_dir(&root.join("src")).unwrap();
+    assert!(entries.contains(&root.join("src").join("lib.rs")));
+    assert!(entries.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(fs.read_link(&root.join("Cargo.toml")).is_err());
+}
+
+#[test]
+fn virtual_tree() {
+    let tree: VirtualTree = [
+        "src/main.rs",
+        "src/util/mod.rs",
+        "src/util/io.rs",
+        "README.md",
+    ]
+    .into_iter()
+    .collect();
+    assert!(tree.metadata(Path::new("src/main.rs")).unwrap().is_file());
+    assert!(tree.metadata(Path::new("./src/util/")).unwrap().is_dir());
+    assert!(tree.metadata(Path::new(".")).unwrap().is_dir());
+    assert_eq!(
+        tree.metadata(Path::new("src/ma")).unwrap_err().kind(),
+        io::ErrorKind::NotFound
+    );
+    assert!(tree.exists(Path::new("src")));
+    assert!(!tree.exists(Path::new("sr")));
+
+    assert_eq!(
+        tree.read_dir(Path::new(".")).unwrap(),
+        [PathBuf::from("README.md"), PathBuf::from("src")]
+    );
+    assert_eq!(
+        tree.read_dir(Path::new("src")).unwrap(),
+        [PathBuf::from("src/main.rs"), PathBuf::from("src/util")]
+    );
+    assert_eq!(
+        tree.read_dir(Path::new("src/util")).unwrap(),
+        [
+            PathBuf::from("src/util/io.rs"),
+            PathBuf::from("src/util/mod.rs")
+        ]
+    );
+    assert!(tree.read_dir(Path::new("src/main.rs")).is_err());
+    assert!(tree.read_dir(Path::new("missing")).is_err());
+    assert!(tree.read_link(Path::new("src/main.rs")).is_err());
+
+    // Works through a reference and a trait object too.
+    let fs: &dyn FileSystem = &tree;
+    assert!((&fs).exists(Path::new("src/util/io.rs")));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let tree: VirtualTree = ["/etc/hosts", "/usr/bin/env", "relative"]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        tree.read_dir(Path::new("/")).unwrap(),
+        [PathBuf::from("/etc"), PathBuf::from("/usr")]
+    );
+    assert_eq!(
+        tree.read_dir(Path::new(".")).unwrap(),
+        [PathBuf::from("relative")]
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let tree: VirtualTree = ["C:\\Windows\\notepad.exe", "C:/Users/me", "relative"]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        tree.read_dir(Path::new("C:\\")).unwrap(),
+        [PathBuf::from("C:\\Users"), PathBuf::from("C:\\Windows")]
+    );
+    assert_eq!(
+        tree.read_dir(Path::new(".")).unwrap(),
+        [PathBuf::from("relative")]
+    );
+}
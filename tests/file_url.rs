@@ -0,0 +1,94 @@
+use std::path::Path;
+use sugar_path::{path_from_file_url, FileUrlError, HostPolicy};
+
+#[test]
+fn errors() {
+    let policy = HostPolicy::default();
+    assert_eq!(
+        path_from_file_url("http://example.com/a", policy),
+        Err(FileUrlError::NotFileUrl)
+    );
+    assert_eq!(
+        path_from_file_url("file:relative", policy),
+        Err(FileUrlError::NotFileUrl)
+    );
+    assert_eq!(
+        path_from_file_url("file:///a/b%2", policy),
+        Err(FileUrlError::InvalidEscape(11))
+    );
+    assert_eq!(
+        path_from_file_url("file:///a%2Fb", policy),
+        Err(FileUrlError::EncodedSeparator)
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use HostPolicy::*;
+    assert_eq!(
+        path_from_file_url("file:///etc/hosts", Error).unwrap(),
+        Path::new("/etc/hosts")
+    );
+    assert_eq!(
+        path_from_file_url("FILE:/etc/./x/../hosts?query#frag", Error).unwrap(),
+        Path::new("/etc/hosts")
+    );
+    assert_eq!(
+        path_from_file_url("file:///a%20b/%C3%BC", Error).unwrap(),
+        Path::new("/a b/ü")
+    );
+    assert_eq!(
+        path_from_file_url("file:///dir/", Error)
+            .unwrap()
+            .as_os_str(),
+        "/dir/"
+    );
+
+    assert_eq!(
+        path_from_file_url("file://localhost/etc/hosts", Error),
+        Err(FileUrlError::UnsupportedHost("localhost".to_string()))
+    );
+    assert_eq!(
+        path_from_file_url("file://LocalHost/etc/hosts", LocalhostOnly).unwrap(),
+        Path::new("/etc/hosts")
+    );
+    assert_eq!(
+        path_from_file_url("file://server/share/x", LocalhostOnly),
+        Err(FileUrlError::UnsupportedHost("server".to_string()))
+    );
+    assert_eq!(
+        path_from_file_url("file://server/share/x", Ignore).unwrap(),
+        Path::new("/share/x")
+    );
+    assert_eq!(
+        path_from_file_url("file://server", Ignore),
+        Err(FileUrlError::NotFileUrl)
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    use HostPolicy::*;
+    assert_eq!(
+        path_from_file_url("file:///C:/Users/me/a%20b.txt", Error).unwrap(),
+        Path::new("C:\\Users\\me\\a b.txt")
+    );
+    assert_eq!(
+        path_from_file_url("file://localhost/c|/x", Error).unwrap(),
+        Path::new("C:\\x")
+    );
+    assert_eq!(
+        path_from_file_url("file://server/share/dir/x.txt", Error).unwrap(),
+        Path::new("\\\\server\\share\\dir\\x.txt")
+    );
+    assert_eq!(
+        path_from_file_url("file:////server/share/x", Error).unwrap(),
+        Path::new("\\\\server\\share\\x")
+    );
+    assert_eq!(
+        path_from_file_url("file:///a%5Cb", Error),
+        Err(FileUrlError::EncodedSeparator)
+    );
+}
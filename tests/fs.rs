@@ -0,0 +1,33 @@
+#![cfg(feature = "fs")]
+
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let temp = std::env::temp_dir();
+    assert_eq!(temp.expand_short_names().unwrap(), temp);
+    assert_eq!(
+        Path::new("/PROGRA~1/foo")
+            .expand_short_names()
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::NotFound
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let temp = std::env::temp_dir();
+    let expanded = temp.expand_short_names().unwrap();
+    assert!(expanded.is_dir());
+    assert_eq!(
+        Path::new("C:\\definitely\\missing\\PROGRA~1")
+            .expand_short_names()
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::NotFound
+    );
+}
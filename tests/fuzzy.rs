@@ -0,0 +1,56 @@
+use sugar_path::{fuzzy_match, fuzzy_rank};
+
+fn positions(query: &str, path: &str) -> Option<Vec<usize>> {
+    fuzzy_match(query, path).map(|m| m.positions)
+}
+
+fn score(query: &str, path: &str) -> i32 {
+    fuzzy_match(query, path).unwrap().score
+}
+
+#[test]
+fn matching() {
+    assert_eq!(positions("abc", "abc"), Some(vec![0, 1, 2]));
+    assert_eq!(positions("ac", "abc"), Some(vec![0, 2]));
+    assert_eq!(positions("ABC", "abc"), None);
+    assert_eq!(positions("abc", "ABC"), Some(vec![0, 1, 2]));
+    assert_eq!(positions("abcd", "abc"), None);
+    assert_eq!(positions("ba", "ab"), None);
+    assert_eq!(positions("", "abc"), Some(vec![]));
+    assert_eq!(fuzzy_match("", "abc").unwrap().score, 0);
+    // Positions are in characters of the normalized path.
+    assert_eq!(positions("lib", "./src/../lib.rs"), Some(vec![0, 1, 2]));
+    assert_eq!(positions("ü", "aü"), Some(vec![1]));
+}
+
+#[test]
+fn bonuses() {
+    // Component starts win over matches in the middle of a name.
+    assert_eq!(positions("b", "ab/b"), Some(vec![3]));
+    assert_eq!(positions("ml", "html/main.rs/lib"), Some(vec![5, 13]));
+    assert!(score("fb", "foo_bar") > score("fb", "fobbar"));
+    assert!(score("fb", "fooBar") > score("fb", "foobar"));
+    assert!(score("ab", "ab") > score("ab", "a_b"));
+    assert!(score("ab", "axb") > score("ab", "axxxb"));
+    assert!(score("slib", "src/lib.rs") > score("slib", "tests/slice_lib.rs"));
+    assert!(score("mod", "src/mod.rs") > score("mod", "src/my_old_dir.rs"));
+}
+
+#[test]
+fn ranking() {
+    let paths = [
+        "src/lib.rs",
+        "lib/src.rs",
+        "src/lib/mod.rs",
+        "docs/readme.md",
+    ];
+    let ranked = fuzzy_rank("srclib", &paths);
+    assert_eq!(ranked.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [0, 2]);
+    // Without a query, shorter paths come first.
+    let ranked = fuzzy_rank("", &["a/b/c", "ab", "a/b", "ba"]);
+    assert_eq!(
+        ranked.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        [1, 3, 2, 0]
+    );
+    assert!(fuzzy_rank("xyz", &paths).is_empty());
+}
@@ -0,0 +1,114 @@
+use std::path::Path;
+use sugar_path::{CaseSensitivity, Pattern, PatternError, VirtualTree};
+
+#[test]
+fn wildcards() {
+    let pattern = Pattern::new("*.rs").unwrap();
+    assert!(pattern.matches("lib.rs"));
+    assert!(pattern.matches(".rs"));
+    assert!(!pattern.matches("src/lib.rs"));
+    assert!(!pattern.matches("lib.rsx"));
+
+    let pattern = Pattern::new("a?c*d*").unwrap();
+    assert!(pattern.matches("abcd"));
+    assert!(pattern.matches("axcxxdxx"));
+    assert!(!pattern.matches("acd"));
+
+    let pattern = Pattern::new("file[0-9][!a-c].[*]").unwrap();
+    assert!(pattern.matches("file1d.*"));
+    assert!(!pattern.matches("file1a.*"));
+    assert!(!pattern.matches("filex1.*"));
+    assert!(!pattern.matches("file1d.x"));
+
+    let pattern = Pattern::new("[]-]").unwrap();
+    assert!(pattern.matches("]"));
+    assert!(pattern.matches("-"));
+    assert!(!pattern.matches("a"));
+}
+
+#[test]
+fn any_depth() {
+    let pattern = Pattern::new("src/**/*.rs").unwrap();
+    assert!(pattern.matches("src/lib.rs"));
+    assert!(pattern.matches("src/a/b/c.rs"));
+    assert!(!pattern.matches("lib.rs"));
+
+    let pattern = Pattern::new("**").unwrap();
+    assert!(pattern.matches("a/b/c"));
+    assert!(pattern.matches(""));
+    assert!(Pattern::new("").unwrap().matches("."));
+    assert!(!Pattern::new("").unwrap().matches("a"));
+
+    let pattern = Pattern::new("**/**/test").unwrap();
+    assert!(pattern.matches("test"));
+    assert!(pattern.matches("a/b/test"));
+    assert!(!pattern.matches("a/b/test/c"));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Pattern::new("src/[abc"),
+        Err(PatternError::UnclosedClass("[abc".to_string()))
+    );
+    assert_eq!(
+        Pattern::new("[z-a]"),
+        Err(PatternError::InvalidRange("[z-a]".to_string()))
+    );
+}
+
+#[test]
+fn case_insensitive() {
+    let pattern = Pattern::new("SRC/*.RS")
+        .unwrap()
+        .case_sensitivity(CaseSensitivity::AsciiInsensitive);
+    assert!(pattern.matches("src/lib.rs"));
+    let pattern = Pattern::new("[A-C]").unwrap();
+    assert!(!pattern.matches("b"));
+    assert!(pattern
+        .case_sensitivity(CaseSensitivity::AsciiInsensitive)
+        .matches("b"));
+}
+
+#[test]
+fn virtual_tree() {
+    let mut tree = VirtualTree::new();
+    assert!(tree.is_empty());
+    assert!(tree.insert("src/main.rs"));
+    assert!(!tree.insert("./src//main.rs"));
+    tree.extend(["src/a/b.rs", "src/a/c.txt", "README.md", "src/a/../z.rs"]);
+    assert_eq!(tree.len(), 5);
+    assert!(tree.contains("src/z.rs"));
+
+    let rs = Pattern::new("**/*.rs").unwrap();
+    assert_eq!(
+        tree.glob(&rs).collect::<Vec<_>>(),
+        [
+            Path::new("src/a/b.rs"),
+            Path::new("src/main.rs"),
+            Path::new("src/z.rs")
+        ]
+    );
+    assert!(tree.remove("src/z.rs"));
+    assert_eq!(tree.glob(&rs).count(), 2);
+    let none = Pattern::new("*.rs").unwrap();
+    assert_eq!(tree.glob(&none).count(), 0);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let pattern = Pattern::new("/usr/**/bin/*").unwrap();
+    assert!(pattern.matches("/usr/local/bin/cargo"));
+    assert!(!pattern.matches("usr/local/bin/cargo"));
+    assert!(!Pattern::new("**/bin").unwrap().matches("/bin"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let pattern = Pattern::new("C:\\Users\\*\\*.txt").unwrap();
+    assert!(pattern.matches("C:/Users/me/notes.txt"));
+    assert!(!pattern.matches("D:\\Users\\me\\notes.txt"));
+    assert!(!Pattern::new("**\\bin").unwrap().matches("C:\\bin"));
+}
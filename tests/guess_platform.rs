@@ -0,0 +1,43 @@
+use sugar_path::{guess_platform, Confidence, Platform};
+
+fn guess(path: &str) -> (Platform, Confidence) {
+    let guess = guess_platform(path);
+    (guess.platform, guess.confidence)
+}
+
+#[test]
+fn windows() {
+    use Confidence::*;
+    assert_eq!(guess(r"C:\a"), (Platform::Windows, High));
+    assert_eq!(guess("c:/a"), (Platform::Windows, High));
+    assert_eq!(guess("C:"), (Platform::Windows, High));
+    assert_eq!(guess("C:foo"), (Platform::Windows, Medium));
+    assert_eq!(guess(r"\\server\share"), (Platform::Windows, High));
+    assert_eq!(guess(r"\\?\C:\a"), (Platform::Windows, High));
+    assert_eq!(guess(r"\\.\pipe\x"), (Platform::Windows, High));
+    assert_eq!(guess(r"\??\C:\a"), (Platform::Windows, High));
+    assert_eq!(guess(r"\a\b"), (Platform::Windows, Medium));
+    assert_eq!(guess(r"a\b"), (Platform::Windows, Medium));
+    assert_eq!(guess(r"/a\b"), (Platform::Windows, Medium));
+    assert_eq!(guess(r"a/b\c"), (Platform::Windows, Low));
+}
+
+#[test]
+fn posix() {
+    use Confidence::*;
+    assert_eq!(guess("/usr/bin"), (Platform::Posix, High));
+    assert_eq!(guess("/"), (Platform::Posix, High));
+    assert_eq!(guess("//net/x"), (Platform::Posix, High));
+    assert_eq!(guess("~/x"), (Platform::Posix, Medium));
+    assert_eq!(guess("a/b"), (Platform::Posix, Low));
+    assert_eq!(guess("1:2/x"), (Platform::Posix, Low));
+}
+
+#[test]
+fn no_evidence() {
+    for path in ["", "foo", "foo.txt", ".."] {
+        assert_eq!(guess(path), (Platform::current(), Confidence::Low));
+    }
+    assert!(Confidence::High > Confidence::Medium);
+    assert!(Confidence::Medium > Confidence::Low);
+}
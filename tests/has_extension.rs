@@ -0,0 +1,44 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[test]
+fn ends_with_extension() {
+    let ends_with = |path: &str, extension: &str| Path::new(path).ends_with_extension(extension);
+    assert!(ends_with("a.rs", "rs"));
+    assert!(ends_with("a.rs", ".rs"));
+    assert!(ends_with("a.Rs", "rS"));
+    assert!(ends_with("dir/a.tar.gz", "tar.gz"));
+    assert!(ends_with("dir/a.tar.gz", "gz"));
+    assert!(ends_with("a.b.c", "b.c"));
+    assert!(ends_with("a.rs/.", "rs"));
+    assert!(ends_with("..rs", "rs"));
+    assert!(!ends_with("a.tar.gz", "tar"));
+    assert!(!ends_with("a.targz", "gz"));
+    assert!(!ends_with("atar.gz", "tar.gz"));
+    assert!(!ends_with("tar.gz", "tar.gz"));
+    assert!(!ends_with(".rs", "rs"));
+    assert!(!ends_with("a.rs", ""));
+    assert!(!ends_with("a.rs", "."));
+    assert!(!ends_with("a.", ""));
+    assert!(!ends_with("a.rs/..", "rs"));
+    assert!(!ends_with("", "rs"));
+    assert!(!ends_with("a.ä", "Ä"));
+    assert!(ends_with("a.ä", "ä"));
+}
+
+#[test]
+fn has_extension_in() {
+    let has = |path: &str, extensions: &[&str]| Path::new(path).has_extension_in(extensions);
+    assert!(has("Cargo.TOML", &["rs", "toml"]));
+    assert!(has("types.d.ts", &["js", "d.ts"]));
+    assert!(has("archive.tar.xz", &[".tar.gz", ".tar.xz"]));
+    assert!(!has("main.rs", &[]));
+    assert!(!has("main.rs", &["r", "s", "ts"]));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert!(Path::new("C:\\dir\\a.TXT").ends_with_extension("txt"));
+    assert!(!Path::new("C:\\").ends_with_extension("txt"));
+}
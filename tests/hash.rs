@@ -0,0 +1,78 @@
+use std::{collections::hash_map::DefaultHasher, hash::Hasher, path::Path};
+use sugar_path::{CaseSensitivity, SugarPath};
+
+fn hash(path: &str, case: CaseSensitivity) -> u64 {
+    let mut state = DefaultHasher::new();
+    Path::new(path).hash_normalized(&mut state, case);
+    state.finish()
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let cases = [
+        "/foo/../../../bar",
+        "a//b//../b",
+        "a//b//./c",
+        "a//b//.",
+        "/a/b/c/../../../x/y/z",
+        "///..//./foo/.//bar",
+        "bar/foo../../",
+        "bar/foo../../baz",
+        "../foo../../../bar",
+        "../.../.././.../../../bar",
+        "../../../foo/../../../bar/../../",
+        "../foobar/barfoo/foo/../../../bar/../../",
+        "/a/b/c/../../../",
+        "a/b/c/../../..",
+        "",
+        ".",
+        "..",
+        "/",
+    ];
+    for a in cases {
+        for b in cases {
+            assert_eq!(
+                Path::new(a).normalize() == Path::new(b).normalize(),
+                hash(a, CaseSensitivity::Sensitive) == hash(b, CaseSensitivity::Sensitive),
+                "{:?} {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    assert_ne!(
+        hash("/foo/Bar", CaseSensitivity::Sensitive),
+        hash("/foo/bar", CaseSensitivity::Sensitive)
+    );
+    assert_eq!(
+        hash("/foo/Bar", CaseSensitivity::AsciiInsensitive),
+        hash("/FOO/bar", CaseSensitivity::AsciiInsensitive)
+    );
+    assert_ne!(
+        hash("/foo/bar", CaseSensitivity::Sensitive),
+        hash("foo/bar", CaseSensitivity::Sensitive)
+    );
+    assert_ne!(
+        hash("a/bc", CaseSensitivity::Sensitive),
+        hash("ab/c", CaseSensitivity::Sensitive)
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        hash("C:\\foo\\..\\Bar", CaseSensitivity::AsciiInsensitive),
+        hash("c:/bar", CaseSensitivity::AsciiInsensitive)
+    );
+    assert_eq!(
+        hash("C:..\\a\\..", CaseSensitivity::Sensitive),
+        hash("C:..", CaseSensitivity::Sensitive)
+    );
+    assert_ne!(
+        hash("C:..", CaseSensitivity::Sensitive),
+        hash("C:", CaseSensitivity::Sensitive)
+    );
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+use sugar_path::{with_default_platform, Platform, SugarPath};
+
+#[test]
+fn hidden() {
+    assert!(Path::new(".git").is_hidden());
+    assert!(Path::new("/a/b/.env.local").is_hidden());
+    assert!(!Path::new("/a/.b/c").is_hidden());
+    assert!(!Path::new(".").is_hidden());
+    assert!(!Path::new("..").is_hidden());
+    assert!(!Path::new("a/..").is_hidden());
+    assert!(!Path::new("").is_hidden());
+}
+
+#[test]
+fn inside_hidden_dir() {
+    assert!(Path::new(".git/config").is_inside_hidden_dir());
+    assert!(Path::new("/a/.cache/b/c").is_inside_hidden_dir());
+    assert!(Path::new("a/.cache/b/../c").is_inside_hidden_dir());
+    assert!(!Path::new("a/.cache/../c").is_inside_hidden_dir());
+    assert!(!Path::new("a/.hidden").is_inside_hidden_dir());
+    assert!(!Path::new("./a/b").is_inside_hidden_dir());
+    assert!(!Path::new("../a").is_inside_hidden_dir());
+    assert!(!Path::new("").is_inside_hidden_dir());
+}
+
+#[test]
+fn inside_hidden_dir_default_platform() {
+    with_default_platform(Platform::Windows, || {
+        assert!(Path::new(r"C:\repo\.git\config").is_inside_hidden_dir());
+        assert!(!Path::new(r"..\x").is_inside_hidden_dir());
+        assert!(!Path::new(r"a\.hidden").is_inside_hidden_dir());
+    });
+}
@@ -0,0 +1,55 @@
+use std::{collections::HashMap, path::Path};
+use sugar_path::{interpolate, InterpolateError, SugarPath};
+
+#[test]
+fn interpolate_template() {
+    let vars = HashMap::from([
+        ("out_dir", "dist"),
+        ("name", "main"),
+        ("hash", "1a2b"),
+        ("nested", "a/../b"),
+    ]);
+    assert_eq!(
+        interpolate("{out_dir}/{name}.{hash}.js", &vars).unwrap(),
+        Path::new("dist/main.1a2b.js")
+    );
+    assert_eq!(
+        interpolate("{out_dir}//./{nested}/{name}", &vars).unwrap(),
+        Path::new("dist/b/main")
+    );
+    assert_eq!(
+        interpolate("{{literal}}/{name}", &vars).unwrap(),
+        Path::new("{literal}/main")
+    );
+    assert_eq!(interpolate("a}}b{{", &vars).unwrap(), Path::new("a}b{"));
+    assert_eq!(interpolate("", &vars).unwrap(), Path::new("").normalize());
+
+    assert_eq!(
+        interpolate("{out_dir}/{missing}", &vars),
+        Err(InterpolateError::MissingVariable("missing".to_string()))
+    );
+    assert_eq!(
+        interpolate("a/{name", &vars),
+        Err(InterpolateError::UnclosedBrace(2))
+    );
+    assert_eq!(
+        interpolate("a/{na{me}", &vars),
+        Err(InterpolateError::UnclosedBrace(2))
+    );
+    assert_eq!(
+        interpolate("a/name}", &vars),
+        Err(InterpolateError::UnmatchedBrace(6))
+    );
+}
+
+#[test]
+fn owned_keys_and_path_values() {
+    let vars = HashMap::from([(
+        "root".to_string(),
+        Path::new("/srv").to_path_buf().into_os_string(),
+    )]);
+    assert_eq!(
+        interpolate("{root}/www", &vars).unwrap(),
+        Path::new("/srv/www").normalize()
+    );
+}
@@ -0,0 +1,63 @@
+use std::{borrow::Cow, path::Path};
+use sugar_path::SugarPath;
+
+#[test]
+fn detect() {
+    for c in [
+        '\u{FEFF}', '\u{200B}', '\u{200C}', '\u{200D}', '\u{200E}', '\u{200F}', '\u{2060}',
+        '\u{202A}', '\u{202E}', '\u{2066}', '\u{2069}', '\u{061C}',
+    ] {
+        let path = format!("a/b{}c", c);
+        assert!(Path::new(&path).has_invisible_chars(), "{:?}", c);
+        assert_eq!(
+            Path::new(&path).strip_invisible(),
+            Path::new("a/bc"),
+            "{:?}",
+            c
+        );
+    }
+    for path in ["", "a/b", "caf\u{e9}", "\u{2028}", "\u{00A0}"] {
+        assert!(!Path::new(path).has_invisible_chars(), "{:?}", path);
+    }
+}
+
+#[test]
+fn strip() {
+    assert!(matches!(
+        Path::new("a/b").strip_invisible(),
+        Cow::Borrowed(_)
+    ));
+    assert_eq!(
+        Path::new("\u{FEFF}\u{FEFF}a\u{200B}/\u{202E}b\u{202C}").strip_invisible(),
+        Path::new("a/b")
+    );
+    assert_eq!(Path::new("\u{200B}").strip_invisible().as_os_str(), "");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let path = Path::new(OsStr::from_bytes(b"\xff\xe2\x80\x8b\xfe/a"));
+    assert!(path.has_invisible_chars());
+    assert_eq!(path.strip_invisible().as_os_str().as_bytes(), b"\xff\xfe/a");
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    use std::{
+        ffi::OsString,
+        os::windows::ffi::{OsStrExt, OsStringExt},
+    };
+
+    // A lone high and low surrogate around a zero-width space join into one character once it's gone.
+    let path = OsString::from_wide(&[0xD83D, 0x200B, 0xDE00]);
+    let stripped = Path::new(&path).strip_invisible();
+    assert_eq!(
+        stripped.as_os_str().encode_wide().collect::<Vec<_>>(),
+        [0xD83D, 0xDE00]
+    );
+    assert_eq!(stripped.to_str(), Some("\u{1F600}"));
+}
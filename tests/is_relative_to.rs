@@ -0,0 +1,49 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[test]
+fn relative() {
+    assert!(Path::new("a/b/c").is_relative_to("a/b"));
+    assert!(Path::new("a/b/c").is_relative_to("a//b/"));
+    assert!(Path::new("a/b").is_relative_to("a/b/c/.."));
+    assert!(!Path::new("a/b").is_relative_to("a/b/c"));
+    assert!(!Path::new("a/bc").is_relative_to("a/b"));
+    assert!(!Path::new("a/b/../../x").is_relative_to("a"));
+
+    assert!(Path::new("a").is_relative_to(""));
+    assert!(Path::new("").is_relative_to("."));
+    assert!(!Path::new("..").is_relative_to("."));
+    assert!(Path::new("../a").is_relative_to(".."));
+    assert!(!Path::new("a").is_relative_to(".."));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    assert!(Path::new("/usr/lib").is_relative_to("/"));
+    assert!(Path::new("/usr/lib").is_relative_to("/usr"));
+    assert!(Path::new("/usr/../etc").is_relative_to("/etc"));
+    assert!(!Path::new("/usr/lib").is_relative_to("usr"));
+    assert!(!Path::new("usr/lib").is_relative_to("/usr"));
+    assert!(!Path::new("/usr").is_relative_to("."));
+    assert!(!Path::new("/Usr/lib").is_relative_to("/usr"));
+
+    with_default_platform(Platform::Windows, || {
+        assert!(Path::new(r"C:\Users\Me\x").is_relative_to("c:/users/me"));
+        assert!(!Path::new(r"C:\a").is_relative_to(r"D:\a"));
+        assert!(Path::new(r"a\b").is_relative_to("."));
+        assert!(!Path::new(r"..\a").is_relative_to("."));
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert!(Path::new("C:\\Users\\me").is_relative_to("c:/users"));
+    assert!(Path::new("C:\\Users\\me").is_relative_to("C:\\"));
+    assert!(!Path::new("C:\\Users\\me").is_relative_to("D:\\"));
+    assert!(!Path::new("C:\\Users").is_relative_to("."));
+    assert!(Path::new("\\\\server\\share\\x").is_relative_to("\\\\server\\share"));
+}
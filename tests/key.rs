@@ -0,0 +1,73 @@
+use std::path::Path;
+use sugar_path::{Platform, SugarPath};
+
+#[test]
+fn canonical_key_as() {
+    // The same on every host.
+    for (path, expected) in [
+        ("C:\\Foo\\..\\Bar", "c:/bar"),
+        ("c:/bar/", "c:/bar"),
+        ("C:", "c:."),
+        ("C:..\\a", "c:../a"),
+        ("\\\\Server\\Share\\x", "//server/share/x"),
+        ("\\\\Server\\Share", "//server/share/"),
+        ("\\A", "/a"),
+        ("a\\B", "a/b"),
+        ("", "."),
+    ] {
+        assert_eq!(
+            Path::new(path).canonical_key_as(Platform::Windows),
+            expected
+        );
+    }
+    for (path, expected) in [
+        ("/foo//bar/../Baz", "/foo/Baz"),
+        ("/", "/"),
+        ("a/./b/", "a/b"),
+        ("../../a", "../../a"),
+        ("a/..", "."),
+        ("a\\B", "a\\B"),
+    ] {
+        assert_eq!(Path::new(path).canonical_key_as(Platform::Posix), expected);
+    }
+    assert_eq!(
+        Path::new("a/B").canonical_key(),
+        Path::new("a/B").canonical_key_as(Platform::current())
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::with_default_platform;
+
+    assert_eq!(Path::new("/foo//bar/../Baz").canonical_key(), "/foo/Baz");
+    assert_eq!(Path::new("/").canonical_key(), "/");
+    assert_eq!(Path::new("///..//./foo").canonical_key(), "/foo");
+    assert_eq!(Path::new("a/./b/").canonical_key(), "a/b");
+    assert_eq!(Path::new("../../a").canonical_key(), "../../a");
+    assert_eq!(Path::new("").canonical_key(), ".");
+    assert_eq!(Path::new("a/..").canonical_key(), ".");
+    assert_eq!(Path::new("a\\b").canonical_key(), "a\\b");
+    assert_eq!(Path::new("a:").canonical_key(), "a:");
+
+    with_default_platform(Platform::Windows, || {
+        assert_eq!(Path::new(r"C:\Foo\..\Bar").canonical_key(), "c:/bar");
+        assert_eq!(Path::new("/foo/Bar").canonical_key(), "/foo/bar");
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(Path::new("C:\\Foo\\..\\Bar").canonical_key(), "c:/bar");
+    assert_eq!(Path::new("c:/bar").canonical_key(), "c:/bar");
+    assert_eq!(Path::new("C:").canonical_key(), "c:.");
+    assert_eq!(Path::new("C:..\\a").canonical_key(), "c:../a");
+    assert_eq!(
+        Path::new("\\\\Server\\Share\\x").canonical_key(),
+        "//server/share/x"
+    );
+    assert_eq!(Path::new("a\\B").canonical_key(), "a/b");
+    assert_eq!(Path::new("").canonical_key(), ".");
+}
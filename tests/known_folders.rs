@@ -0,0 +1,67 @@
+#![cfg(feature = "known-folders")]
+
+use std::path::PathBuf;
+use sugar_path::{KnownFolder, KnownFolders};
+
+#[test]
+fn with_lookup() {
+    let folders = KnownFolders::with_lookup(|_| None);
+    assert_eq!(folders.dir(KnownFolder::Documents), None);
+    assert_eq!(folders.join(KnownFolder::Documents, "x"), None);
+
+    let folders = KnownFolders::with_lookup(|_| Some(PathBuf::from("relative")));
+    assert_eq!(folders.local_app_data(), None);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let folders = KnownFolders::with_lookup(|folder| {
+        Some(PathBuf::from(match folder {
+            KnownFolder::RoamingAppData => "/c/Users/me/AppData/Roaming/",
+            KnownFolder::LocalAppData => "/c/Users/me/AppData/./Local",
+            KnownFolder::Documents => "/c/Users/me/Documents",
+            KnownFolder::ProgramData => "/c/ProgramData",
+        }))
+    });
+    assert_eq!(
+        folders.roaming_app_data().unwrap().as_os_str(),
+        "/c/Users/me/AppData/Roaming"
+    );
+    assert_eq!(
+        folders.local_app_data(),
+        Some(PathBuf::from("/c/Users/me/AppData/Local"))
+    );
+    assert_eq!(
+        folders.documents(),
+        Some(PathBuf::from("/c/Users/me/Documents"))
+    );
+    assert_eq!(
+        folders.program_data(),
+        Some(PathBuf::from("/c/ProgramData"))
+    );
+    assert_eq!(
+        folders.join(KnownFolder::LocalAppData, "tool/../app/cache"),
+        Some(PathBuf::from("/c/Users/me/AppData/Local/app/cache"))
+    );
+
+    // There are no known folders outside of Windows.
+    assert_eq!(KnownFolders::new().documents(), None);
+    assert_eq!(KnownFolders::default().program_data(), None);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let folders = KnownFolders::new();
+    for folder in [
+        KnownFolder::RoamingAppData,
+        KnownFolder::LocalAppData,
+        KnownFolder::Documents,
+        KnownFolder::ProgramData,
+    ] {
+        let path = folders.dir(folder).unwrap();
+        assert!(path.is_absolute());
+    }
+    assert_ne!(folders.roaming_app_data(), folders.local_app_data());
+}
@@ -0,0 +1,55 @@
+use std::path::Path;
+use sugar_path::{LimitViolation, Platform, SugarPath};
+
+#[test]
+fn posix() {
+    assert!(Path::new("/foo/bar")
+        .check_limits(Platform::Posix)
+        .is_empty());
+
+    let name = "a".repeat(256);
+    assert_eq!(
+        Path::new(&format!("/foo/{}/bar", name)).check_limits(Platform::Posix),
+        vec![LimitViolation::ComponentTooLong {
+            component: name.clone().into(),
+            len: 256,
+            max: 255,
+        }]
+    );
+    assert!(Path::new(&"a".repeat(255))
+        .check_limits(Platform::Posix)
+        .is_empty());
+
+    // `é` is two bytes in UTF-8 but a single UTF-16 unit.
+    let name = "é".repeat(200);
+    assert_eq!(Path::new(&name).check_limits(Platform::Posix).len(), 1);
+    assert!(Path::new(&name).check_limits(Platform::Windows).is_empty());
+
+    let path = "abcdefgh/".repeat(455);
+    assert!(Path::new(&path).check_limits(Platform::Posix).is_empty());
+    let path = format!("{}a", path);
+    assert_eq!(
+        Path::new(&path).check_limits(Platform::Posix),
+        vec![LimitViolation::PathTooLong {
+            len: 4096,
+            max: 4095
+        }]
+    );
+}
+
+#[test]
+fn windows() {
+    let path = format!("C:\\{}", "abcdefgh\\".repeat(28));
+    assert!(Path::new(&path).check_limits(Platform::Windows).is_empty());
+    let path = format!("{}abcdefgh", path);
+    assert_eq!(
+        Path::new(&path).check_limits(Platform::Windows),
+        vec![LimitViolation::PathTooLong { len: 263, max: 259 }]
+    );
+
+    // Both separators split components on Windows.
+    let name = "a".repeat(200);
+    let path = format!("{}\\{}", name, name);
+    assert_eq!(Path::new(&path).check_limits(Platform::Windows).len(), 1);
+    assert_eq!(Path::new(&path).check_limits(Platform::Posix).len(), 1);
+}
@@ -0,0 +1,86 @@
+use std::path::Path;
+use sugar_path::{LogPath, SugarPath};
+
+fn show(path: &Path) -> String {
+    LogPath::new(path).to_string()
+}
+
+#[test]
+fn normalized() {
+    assert_eq!(show(Path::new("")), ".");
+    assert_eq!(show(Path::new("a//b/../c/")), "a/c");
+    assert_eq!(show(Path::new("../a")), "../a");
+}
+
+#[test]
+fn contract() {
+    let path = Path::new("a/b").resolve();
+    assert_eq!(LogPath::new(&path).contract_cwd(true).to_string(), "a/b");
+    assert_eq!(LogPath::new(&path).to_string(), show(&path));
+    assert_eq!(
+        LogPath::new(Path::new("").resolve().as_path())
+            .contract_cwd(true)
+            .to_string(),
+        "."
+    );
+    assert_eq!(
+        LogPath::new(Path::new("../x"))
+            .contract_cwd(true)
+            .to_string(),
+        "../x"
+    );
+
+    let home = Path::new("home-for-log-path").resolve();
+    std::env::set_var(
+        if cfg!(target_family = "windows") {
+            "USERPROFILE"
+        } else {
+            "HOME"
+        },
+        &home,
+    );
+    let path = home.join("docs/./a.txt");
+    assert_eq!(
+        LogPath::new(&path).contract_home(true).to_string(),
+        "~/docs/a.txt"
+    );
+    assert_eq!(LogPath::new(&home).contract_home(true).to_string(), "~");
+    // The working directory is tried first.
+    assert_eq!(
+        LogPath::new(&path)
+            .contract_home(true)
+            .contract_cwd(true)
+            .to_string(),
+        "home-for-log-path/docs/a.txt"
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_field() {
+    let path = Path::new("a/./b");
+    assert_eq!(format!("{:?}", LogPath::new(path).field()), "a/b");
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn log_value() {
+    use log::kv::ToValue;
+
+    let path = Path::new("a/./b");
+    assert_eq!(LogPath::new(path).as_value().to_string(), "a/b");
+    assert_eq!(LogPath::new(path).to_value().to_string(), "a/b");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(show(Path::new("//a/./b")), "/a/b");
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(show(Path::new("C:\\a\\.\\b")), "C:/a/b");
+    assert_eq!(show(Path::new("\\\\server\\share\\a")), "//server/share/a");
+}
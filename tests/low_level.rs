@@ -0,0 +1,93 @@
+use std::path::{Component, Path};
+use sugar_path::{
+    low_level::{components_to_path_buf, normalize_components, ComponentStack, Folded},
+    SugarPath,
+};
+
+#[test]
+fn stack() {
+    let mut stack = ComponentStack::new();
+    assert_eq!(stack.push(Component::CurDir), Folded::Skipped);
+    assert_eq!(stack.push(Component::ParentDir), Folded::Escaped);
+    assert_eq!(stack.as_slice(), []);
+    stack.push_escaped_parent();
+    assert_eq!(stack.push(Component::ParentDir), Folded::Escaped);
+    assert_eq!(stack.push(Component::Normal("a".as_ref())), Folded::Pushed);
+    assert_eq!(stack.push(Component::RootDir), Folded::Misplaced);
+    assert_eq!(stack.push(Component::ParentDir), Folded::Popped);
+    assert_eq!(stack.to_path_buf(), Path::new(".."));
+    assert!(!stack.has_root());
+    assert_eq!(stack.clone().into_vec(), [Component::ParentDir]);
+
+    let mut stack = ComponentStack::with_capacity(2);
+    assert_eq!(stack.push(Component::RootDir), Folded::Pushed);
+    assert!(stack.has_root());
+    assert_eq!(stack.push(Component::ParentDir), Folded::Escaped);
+    assert_eq!(stack.push(Component::RootDir), Folded::Misplaced);
+}
+
+#[test]
+fn matches_normalize() {
+    for path in [
+        "",
+        ".",
+        "a/b/../c",
+        "../../a",
+        "a/../..",
+        "./a//b/.",
+        "a/b/../../..",
+    ] {
+        let mut normalized = components_to_path_buf(&normalize_components(Path::new(path)));
+        if normalized.as_os_str().is_empty() {
+            normalized.push(".");
+        }
+        assert_eq!(normalized, Path::new(path).normalize(), "{}", path);
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(
+        normalize_components(Path::new("/../a/..")),
+        [Component::RootDir]
+    );
+    assert_eq!(
+        components_to_path_buf(&[Component::RootDir]),
+        Path::new("/")
+    );
+
+    // A bundler that maps `..` above the root onto a virtual root instead.
+    let mut stack = ComponentStack::new();
+    let mut escaped = 0;
+    for component in Path::new("/a/../../b").components() {
+        if stack.push(component) == Folded::Escaped {
+            escaped += 1;
+        }
+    }
+    assert_eq!(escaped, 1);
+    assert_eq!(stack.to_path_buf(), Path::new("/b"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let components = normalize_components(Path::new("C:..\\a\\..\\.."));
+    assert_eq!(components.len(), 3);
+    assert!(matches!(components[0], Component::Prefix(_)));
+    assert_eq!(components_to_path_buf(&components), Path::new("C:..\\.."));
+    assert_eq!(
+        components_to_path_buf(&normalize_components(Path::new("C:\\..\\a"))),
+        Path::new("C:\\a")
+    );
+
+    let mut stack = ComponentStack::new();
+    for component in Path::new("C:\\a").components() {
+        stack.push(component);
+    }
+    assert!(stack.has_root());
+    assert_eq!(
+        stack.push(Path::new("D:").components().next().unwrap()),
+        Folded::Misplaced
+    );
+}
@@ -0,0 +1,54 @@
+use std::{env, path::Path};
+use sugar_path::{Anchor, AnchoredPathBuf, AnchoredPathError, SugarPath};
+
+#[test]
+fn manifest_dir() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    assert_eq!(Anchor::ManifestDir.env_var(), Some("CARGO_MANIFEST_DIR"));
+    assert_eq!(Anchor::ManifestDir.dir().unwrap(), manifest_dir.normalize());
+    assert_eq!(
+        Path::new("src/../Cargo.toml")
+            .resolve_from_manifest_dir()
+            .unwrap(),
+        manifest_dir.join("Cargo.toml")
+    );
+    assert_eq!(
+        Path::new("").resolve_from_manifest_dir().unwrap(),
+        manifest_dir.normalize()
+    );
+    assert!(Path::new("tests/manifest_dir.rs")
+        .resolve_from_manifest_dir()
+        .unwrap()
+        .is_file());
+    let absolute = env::temp_dir().join("a/../b");
+    assert_eq!(
+        absolute.resolve_from_manifest_dir().unwrap(),
+        absolute.normalize()
+    );
+
+    let anchored: AnchoredPathBuf = AnchoredPathBuf::new(Anchor::ManifestDir, "./src").unwrap();
+    assert_eq!(anchored.path(), manifest_dir.join("src"));
+}
+
+#[test]
+fn env_anchors() {
+    assert_eq!(Anchor::OutDir.env_var(), Some("OUT_DIR"));
+    for anchor in [Anchor::Home, Anchor::Cwd, Anchor::ConfigDir] {
+        assert_eq!(anchor.env_var(), None);
+    }
+
+    // Only this test touches `OUT_DIR`.
+    let out_dir = env::temp_dir().join("sugar_path_out");
+    env::set_var("OUT_DIR", &out_dir);
+    assert_eq!(
+        Anchor::OutDir.resolve("gen/./bindings.rs").unwrap(),
+        out_dir.join("gen/bindings.rs")
+    );
+    env::set_var("OUT_DIR", "relative/out");
+    assert_eq!(Anchor::OutDir.dir(), None);
+    env::remove_var("OUT_DIR");
+    assert_eq!(
+        Anchor::OutDir.resolve("x"),
+        Err(AnchoredPathError::UnknownAnchor(Anchor::OutDir))
+    );
+}
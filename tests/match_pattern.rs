@@ -0,0 +1,69 @@
+use std::path::Path;
+use sugar_path::{CaseSensitivity, PatternError, SugarPath};
+
+const SENSITIVE: CaseSensitivity = CaseSensitivity::Sensitive;
+
+#[test]
+fn match_pattern() {
+    let matches =
+        |path: &str, pattern: &str| Path::new(path).match_pattern(pattern, SENSITIVE).unwrap();
+    assert!(matches("a/b.py", "*.py"));
+    assert!(matches("a/b/c.py", "b/*.py"));
+    assert!(!matches("a/b/c.py", "a/*.py"));
+    assert!(matches("a/b/c.py", "a/**/*.py"));
+    assert!(matches("a/b.py", "a/**/*.py"));
+    assert!(matches("a/./x/../b.py", "a/b.py"));
+    assert!(!matches("b.py", "a/b.py"));
+    assert!(!matches("a/b.pyc", "*.py"));
+    assert_eq!(
+        Path::new("a").match_pattern("", SENSITIVE),
+        Err(PatternError::Empty)
+    );
+    assert!(Path::new("a").match_pattern("[a", SENSITIVE).is_err());
+}
+
+#[test]
+fn full_match() {
+    let matches =
+        |path: &str, pattern: &str| Path::new(path).full_match(pattern, SENSITIVE).unwrap();
+    assert!(matches("a/b.py", "a/*.py"));
+    assert!(!matches("a/b.py", "*.py"));
+    assert!(matches("a/b/c/d.py", "**/*.py"));
+    assert!(matches("a/b/c/d.py", "a/**"));
+    assert!(matches("", ""));
+    assert!(!Path::new("A/B.PY").full_match("a/*.py", SENSITIVE).unwrap());
+    assert!(Path::new("A/B.PY")
+        .full_match("a/*.py", CaseSensitivity::AsciiInsensitive)
+        .unwrap());
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert!(Path::new("/a/b.py")
+        .match_pattern("/*/*.py", SENSITIVE)
+        .unwrap());
+    assert!(!Path::new("/a/b/c.py")
+        .match_pattern("/*/*.py", SENSITIVE)
+        .unwrap());
+    assert!(!Path::new("a/b.py")
+        .match_pattern("/*/*.py", SENSITIVE)
+        .unwrap());
+    assert!(Path::new("/a/b.py")
+        .match_pattern("b.py", SENSITIVE)
+        .unwrap());
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert!(Path::new("C:\\a\\b.py")
+        .match_pattern("C:\\*\\*.py", SENSITIVE)
+        .unwrap());
+    assert!(!Path::new("C:\\x\\a\\b.py")
+        .match_pattern("C:\\*\\*.py", SENSITIVE)
+        .unwrap());
+    assert!(Path::new("C:\\a\\b.py")
+        .match_pattern("a/b.py", SENSITIVE)
+        .unwrap());
+}
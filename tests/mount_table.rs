@@ -0,0 +1,149 @@
+use std::path::Path;
+use sugar_path::MountTable;
+
+#[test]
+fn to_physical() {
+    let mounts = MountTable::new()
+        .mount("/assets", "public")
+        .mount("/assets/icons/", "./icons/dist")
+        .mount("/empty", ".");
+    let physical = |path: &str| mounts.to_physical(path);
+    assert_eq!(
+        physical("/assets/a.png").unwrap(),
+        Path::new("public/a.png")
+    );
+    assert_eq!(
+        physical("assets//a.png").unwrap(),
+        Path::new("public/a.png")
+    );
+    assert_eq!(physical("/assets").unwrap(), Path::new("public"));
+    assert_eq!(physical("/assets/").unwrap(), Path::new("public"));
+    assert_eq!(
+        physical("/assets/icons/x.svg").unwrap(),
+        Path::new("icons/dist/x.svg")
+    );
+    assert_eq!(
+        physical("/assets/icons/../b.png").unwrap(),
+        Path::new("public/b.png")
+    );
+    assert_eq!(physical("/empty/a").unwrap(), Path::new("a"));
+    // Prefix matching is per component.
+    assert_eq!(physical("/assets2/a.png"), None);
+    assert_eq!(physical("/other"), None);
+    // `..` can't climb out of the mount.
+    assert_eq!(physical("/assets/../../etc/passwd"), None);
+    assert_eq!(
+        physical("/../assets/./a.png").unwrap(),
+        Path::new("public/a.png")
+    );
+}
+
+#[test]
+fn root_mount() {
+    let mounts = MountTable::new().mount("/", "dist").mount("/api", "mock");
+    assert_eq!(mounts.to_physical("/").unwrap(), Path::new("dist"));
+    assert_eq!(mounts.to_physical("/../x").unwrap(), Path::new("dist/x"));
+    assert_eq!(mounts.to_physical("/api/x").unwrap(), Path::new("mock/x"));
+    assert_eq!(mounts.to_virtual("dist").as_deref(), Some("/"));
+    assert_eq!(mounts.to_virtual("dist/a/./b").as_deref(), Some("/a/b"));
+    assert_eq!(mounts.to_virtual("mock/x").as_deref(), Some("/api/x"));
+    assert_eq!(mounts.to_virtual("mock/../x"), None);
+}
+
+#[test]
+fn remount() {
+    let mounts = MountTable::new().mount("/a", "one").mount("/a/", "two");
+    assert_eq!(mounts.to_physical("/a/x").unwrap(), Path::new("two/x"));
+    assert_eq!(mounts.to_virtual("one/x"), None);
+}
+
+#[test]
+fn to_virtual() {
+    let mounts = MountTable::new()
+        .mount("/assets", "public")
+        .mount("/static", "public")
+        .mount("/icons", "public/icons")
+        .mount("/here", ".");
+    let virtual_path = |path: &str| mounts.to_virtual(path);
+    assert_eq!(
+        virtual_path("public/a.png").as_deref(),
+        Some("/assets/a.png")
+    );
+    assert_eq!(
+        virtual_path("./public/x/../a.png").as_deref(),
+        Some("/assets/a.png")
+    );
+    assert_eq!(
+        virtual_path("public/icons/x.svg").as_deref(),
+        Some("/icons/x.svg")
+    );
+    assert_eq!(virtual_path("public").as_deref(), Some("/assets"));
+    assert_eq!(
+        virtual_path("src/main.rs").as_deref(),
+        Some("/here/src/main.rs")
+    );
+    assert_eq!(virtual_path("../outside"), None);
+    assert_eq!(MountTable::new().to_virtual("a"), None);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let mounts = MountTable::new()
+        .mount("/src", "/repo/src")
+        .passthrough("/@fs");
+    assert_eq!(
+        mounts.to_physical("/@fs/home/me/a.js").unwrap(),
+        Path::new("/home/me/a.js")
+    );
+    assert_eq!(
+        mounts.to_physical("/@fs/etc/../hosts").unwrap(),
+        Path::new("/hosts")
+    );
+    assert_eq!(mounts.to_physical("/@fs/../etc/hosts"), None);
+    assert_eq!(mounts.to_physical("/@fs").unwrap(), Path::new("/"));
+    assert_eq!(
+        mounts.to_physical("/src/a\\b").unwrap(),
+        Path::new("/repo/src/a\\b")
+    );
+    assert_eq!(
+        mounts.to_virtual("/repo/src/lib.rs").as_deref(),
+        Some("/src/lib.rs")
+    );
+    assert_eq!(
+        mounts.to_virtual("/repo/lib.rs").as_deref(),
+        Some("/@fs/repo/lib.rs")
+    );
+    assert_eq!(mounts.to_virtual("/").as_deref(), Some("/@fs"));
+    assert_eq!(mounts.to_virtual("repo/lib.rs"), None);
+    assert_eq!(
+        mounts.to_virtual(Path::new(OsStr::from_bytes(b"/repo/src/\xff"))),
+        None
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let mounts = MountTable::new()
+        .mount("/src", "C:\\repo\\src")
+        .passthrough("/@fs");
+    assert_eq!(
+        mounts.to_physical("/@fs/C:/Users/me/a.js").unwrap(),
+        Path::new("C:\\Users\\me\\a.js")
+    );
+    assert_eq!(mounts.to_physical("/@fs/Users/me"), None);
+    assert_eq!(mounts.to_physical("/src/a\\..\\..\\b"), None);
+    assert_eq!(mounts.to_physical("/src/C:"), None);
+    assert_eq!(
+        mounts.to_virtual("C:/repo/src/lib.rs").as_deref(),
+        Some("/src/lib.rs")
+    );
+    assert_eq!(
+        mounts.to_virtual("D:\\x\\y").as_deref(),
+        Some("/@fs/D:/x/y")
+    );
+    assert_eq!(mounts.to_virtual("\\\\server\\share\\x"), None);
+}
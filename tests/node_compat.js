@@ -0,0 +1,88 @@
+// Generates the vectors of `node_compat.rs`: `node tests/node_compat.js > tests/node_compat.json`.
+// Only the results that don't depend on the current directory are kept, by computing each twice.
+const path = require("path");
+
+const posixPaths = [
+  "", ".", "./", "..", "../", "/", "//", "///", "/.", "/..", "/../", "/./", "//a", "///a",
+  "a", "a/", "a//", "a/.", "a/./", "a/..", "a/../", "a/../..", "a/../../", "./a", "./..", ".//",
+  "../a/..", "a/b/../../..", "a/b/..", "a/b/../", "/a/b/", "/a/b/../..", "/a/b/../../..",
+  "foo/bar//baz/asdf/quux/..", "/foo/../../../bar", "..a/b", "a../b", "a/...", "a/.../..",
+  ".a/.b/..", "a\\b/../c", "C:\\a/../b", "/héllo/wörld/../€", "//server/share/../x", "a/b/c/",
+];
+
+const win32Paths = [
+  ...posixPaths,
+  "\\", "\\\\", "\\\\\\", "a\\b", "a\\b\\", "a\\..\\..", "\\a\\..\\..", "C:", "c:", "C:.", "C:..",
+  "C:\\", "C:/", "C:\\..", "C:a\\..\\..", "C:..\\x", "C:\\a\\..\\b\\", "c:/a/./b", "C:\\a\\b\\..\\..\\..",
+  "\\\\server", "\\\\server\\", "\\\\server\\share", "\\\\server\\share\\", "//server/share",
+  "\\\\server\\share\\a\\..\\..", "//a//b", "//a/b/c/../..", "\\\\server\\\\share", "\\\\.\\", "\\\\?",
+  "\\\\.\\CON", "\\\\.\\C:\\a\\..\\b", "\\\\?\\C:\\a\\..", "\\\\?\\UNC\\s\\h\\..", "\\\\?\\C:/a/./b",
+  "foo\\..\\C:", "foo\\..\\C:\\x", "a/../b:/c", "foo:bar", "foo:bar\\..\\baz:", ".\\CON:", "CON:",
+  "CON:x", "a\\..\\CON:x", "CONx", "con1", "aux/", "nul.txt", "NULx\\..\\y", "lpt3:foo\\..\\..\\bar",
+  "COM¹:", "COM¹x", "C:\\CONx", "CON😀", "é", "😀", "x:y:", "\\CON:", "C:CON",
+];
+
+const posixResolve = [
+  ["/"], ["/a/b", "c"], ["/a/b/", "./c/"], ["/a", "b", "", "../c/"], ["a", "/b", "c/.."],
+  ["/a", "/b", "c"], ["/foo/bar", "./baz"], ["/foo/bar", "/tmp/file/"], ["/", "..", "..", "a"],
+  ["/a/b", "../../../x/"], ["/a", "b\\c", ".."], ["//a", "b"], ["/a/", "", "", "b//"],
+  ["/x", "."], ["/x", "./"], ["/x/", "."], ["/é", "ü/../€"],
+];
+
+const win32Resolve = [
+  ["C:\\"], ["C:\\a", "b"], ["c:/blah\\blah", "d:/games", "c:../a"], ["c:/ignore", "d:\\a/b\\c/d", "\\e.exe"],
+  ["c:/ignore", "c:/some/file"], ["d:/ignore", "d:some/dir//"], ["//server/share", "..", "relative\\"],
+  ["c:/", "//"], ["c:/", "//dir"], ["c:/", "//server/share"], ["c:/", "//server//share"], ["c:/", "///some//dir"],
+  ["C:\\foo\\tmp.3\\", "..\\tmp.3\\cycles\\root.js"], ["\\\\.\\PHYSICALDRIVE0"], ["\\\\?\\PHYSICALDRIVE0"],
+  ["C:\\a", "C:b\\..\\c"], ["C:\\a", "D:b"], ["C:\\a", "", "b\\"], ["\\\\s\\h", "C:x", "\\y"],
+  ["C:\\a", "\\b", "c"], ["C:\\é", "Ü\\..\\€"], ["C:\\a", "..\\..\\..\\x\\"], ["C:/a", "b:c"],
+];
+
+const posixRelative = [
+  ["/a", "/a"], ["/a/", "/a"], ["/a/b", "/a"], ["/a", "/a/b"], ["/", "/"], ["/a", "/"], ["/", "/a"],
+  ["/var/lib", "/var"], ["/var/lib", "/bin"], ["/var/lib", "/var/lib"], ["/var/lib", "/var/apache"],
+  ["/var/", "/var/lib"], ["/", "/var/lib"], ["/foo/test", "/foo/test/bar/package.json"],
+  ["/Users/a/web/b/test/mails", "/Users/a/web/b"], ["/foo/bar/baz-quux", "/foo/bar/baz"],
+  ["/foo/bar/baz", "/foo/bar/baz-quux"], ["/baz-quux", "/baz"], ["/baz", "/baz-quux"],
+  ["/page1/page2/foo", "/"], ["/a/b/c", "/a/x/y"], ["/a/../b", "/b/./c/"], ["/é/ü", "/é/€"],
+  ["/ab", "/ac"], ["/a", "/A"],
+];
+
+const win32Relative = [
+  ["c:/blah\\blah", "d:/games"], ["c:/aaaa/bbbb", "c:/aaaa"], ["c:/aaaa/bbbb", "c:/cccc"],
+  ["c:/aaaa/bbbb", "c:/aaaa/bbbb"], ["c:/aaaa/bbbb", "c:/aaaa/cccc"], ["c:/aaaa/", "c:/aaaa/cccc"],
+  ["c:/", "c:\\aaaa\\bbbb"], ["c:/aaaa/bbbb", "d:\\"], ["c:/AaAa/bbbb", "c:/aaaa/bbbb"],
+  ["c:/aaaaa/", "c:/aaaa/cccc"], ["C:\\foo\\bar\\baz\\quux", "C:\\"], ["C:\\foo\\test", "C:\\foo\\test\\bar\\package.json"],
+  ["C:\\foo\\bar\\baz-quux", "C:\\foo\\bar\\baz"], ["C:\\foo\\bar\\baz", "C:\\foo\\bar\\baz-quux"],
+  ["\\\\foo\\bar", "\\\\foo\\bar\\baz"], ["\\\\foo\\bar\\baz", "\\\\foo\\bar"], ["\\\\foo\\bar\\baz-quux", "\\\\foo\\bar\\baz"],
+  ["\\\\foo\\bar\\baz", "\\\\foo\\bar\\baz-quux"], ["C:\\baz-quux", "C:\\baz"], ["C:\\baz", "C:\\baz-quux"],
+  ["\\\\foo\\baz-quux", "\\\\foo\\baz"], ["\\\\foo\\baz", "\\\\foo\\baz-quux"], ["C:\\baz", "\\\\foo\\bar\\baz"],
+  ["\\\\foo\\bar\\baz", "C:\\baz"], ["C:\\İ\\a", "C:\\i̇\\b"], ["C:\\İx\\a", "C:\\İy\\b"], ["C:\\a\\İ", "C:\\a"],
+  ["C:\\ÉCOLE\\a", "C:\\école\\b"], ["C:\\\u212a\\a", "C:\\k\\b"], ["C:\\a", "C:\\a\\İ\\b"], ["C:\\x\\İ", "C:\\y"],
+];
+
+function generate(cases, compute) {
+  const vectors = [];
+  for (const args of cases) {
+    process.chdir("/");
+    const first = compute(...args);
+    process.chdir(__dirname);
+    if (compute(...args) === first) vectors.push([...args, first]);
+  }
+  return vectors;
+}
+
+const vectors = {
+  posix: {
+    normalize: posixPaths.map((p) => [p, path.posix.normalize(p)]),
+    resolve: generate(posixResolve.map((args) => [args]), (args) => path.posix.resolve(...args)),
+    relative: generate(posixRelative, path.posix.relative),
+  },
+  win32: {
+    normalize: win32Paths.map((p) => [p, path.win32.normalize(p)]),
+    resolve: generate(win32Resolve.map((args) => [args]), (args) => path.win32.resolve(...args)),
+    relative: generate(win32Relative, path.win32.relative),
+  },
+};
+vectors.node = process.version;
+console.log(JSON.stringify(vectors, null, 1));
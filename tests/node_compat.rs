@@ -0,0 +1,88 @@
+#![cfg(feature = "node-compat")]
+
+use serde_json::Value;
+use sugar_path::{NodePath, Platform};
+
+fn vectors(platform: &str, method: &str) -> Vec<Vec<Value>> {
+    let vectors: Value = serde_json::from_str(include_str!("node_compat.json")).unwrap();
+    serde_json::from_value(vectors[platform][method].clone()).unwrap()
+}
+
+fn check(node: NodePath, platform: &str) {
+    for vector in vectors(platform, "normalize") {
+        let (path, expected) = (vector[0].as_str().unwrap(), vector[1].as_str().unwrap());
+        assert_eq!(node.normalize(path), expected, "normalize({:?})", path);
+    }
+    for vector in vectors(platform, "resolve") {
+        let paths: Vec<String> = serde_json::from_value(vector[0].clone()).unwrap();
+        let expected = vector[1].as_str().unwrap();
+        assert_eq!(node.resolve(&paths), expected, "resolve({:?})", paths);
+    }
+    for vector in vectors(platform, "relative") {
+        let (from, to) = (vector[0].as_str().unwrap(), vector[1].as_str().unwrap());
+        let expected = vector[2].as_str().unwrap();
+        assert_eq!(
+            node.relative(from, to),
+            expected,
+            "relative({:?}, {:?})",
+            from,
+            to
+        );
+    }
+}
+
+#[test]
+fn posix_vectors() {
+    check(NodePath::POSIX, "posix");
+}
+
+#[test]
+fn win32_vectors() {
+    check(NodePath::WIN32, "win32");
+}
+
+#[test]
+fn new() {
+    assert_eq!(NodePath::new(Platform::Posix), NodePath::POSIX);
+    assert_eq!(NodePath::WIN32.platform(), Platform::Windows);
+    assert_eq!(NodePath::default().platform(), Platform::current());
+}
+
+#[test]
+fn relative_to_cwd() {
+    for node in [NodePath::POSIX, NodePath::WIN32] {
+        assert_eq!(node.relative("", ""), "");
+        assert_eq!(node.relative("", "."), "");
+        assert_eq!(node.relative("a", "./a/"), "");
+        assert_eq!(node.resolve([""; 0]), node.resolve(["."]));
+    }
+    assert_eq!(NodePath::POSIX.relative("a/b", "a"), "..");
+    assert_eq!(NodePath::POSIX.relative("a", "a/b/c"), "b/c");
+    assert_eq!(NodePath::WIN32.relative("a\\b", "a/c"), "..\\c");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let cwd = std::env::current_dir().unwrap();
+    let cwd = cwd.to_str().unwrap();
+    assert_eq!(NodePath::POSIX.resolve([""; 0]), cwd);
+    assert_eq!(
+        NodePath::POSIX.resolve(["a/", "b/.."]),
+        format!("{}/a", cwd)
+    );
+    assert_eq!(NodePath::default().normalize("a\\b/../c"), "c");
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let cwd = std::env::current_dir().unwrap();
+    let cwd = cwd.to_str().unwrap();
+    assert_eq!(NodePath::WIN32.resolve([""; 0]), cwd);
+    assert_eq!(
+        NodePath::WIN32.resolve(["a/", "b/.."]),
+        format!("{}\\a", cwd)
+    );
+    assert_eq!(NodePath::default().normalize("a\\b/../c"), "a\\c");
+}
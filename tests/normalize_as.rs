@@ -0,0 +1,128 @@
+use std::path::Path;
+use sugar_path::{default_platform, with_default_platform, Platform, SugarPath};
+
+fn normalize(path: &str, platform: Platform) -> String {
+    Path::new(path)
+        .normalize_as(platform)
+        .into_os_string()
+        .into_string()
+        .unwrap()
+}
+
+#[test]
+fn posix() {
+    for (path, expected) in [
+        ("", "."),
+        ("a/./b/../c", "a/c"),
+        ("a/b/", "a/b/"),
+        ("../a/../..", "../.."),
+        ("/../a//b/.", "/a/b"),
+        ("//a", "/a"),
+        ("/", "/"),
+        ("a/..", "."),
+        ("a/../", "./"),
+    ] {
+        assert_eq!(normalize(path, Platform::Posix), expected, "{}", path);
+    }
+}
+
+#[test]
+fn windows() {
+    for (path, expected) in [
+        ("", "."),
+        ("a/b\\..\\c", "a\\c"),
+        ("C:/a/./b/../c/", "C:\\a\\c\\"),
+        ("c:\\..\\a", "c:\\a"),
+        ("C:", "C:."),
+        ("C:a\\..\\..", "C:.."),
+        ("\\a\\..\\..\\b", "\\b"),
+        ("//server/share/a/../..", "\\\\server\\share\\"),
+        ("\\\\server\\share", "\\\\server\\share\\"),
+        ("//./COM1/x", "\\\\.\\COM1\\x"),
+        ("\\\\?\\C:\\a\\..\\b", "\\\\?\\C:\\a\\..\\b"),
+    ] {
+        assert_eq!(normalize(path, Platform::Windows), expected, "{}", path);
+    }
+}
+
+#[test]
+fn matches_the_host() {
+    for path in ["", "a/./b/../c/", "../../a", "/x/../y"] {
+        assert_eq!(
+            Path::new(path).normalize_as(Platform::current()),
+            Path::new(path).normalize()
+        );
+    }
+}
+
+#[test]
+fn scoped_default() {
+    let foreign = match Platform::current() {
+        Platform::Posix => Platform::Windows,
+        Platform::Windows => Platform::Posix,
+    };
+    assert_eq!(default_platform(), Platform::current());
+    let normalized = with_default_platform(foreign, || {
+        assert_eq!(default_platform(), foreign);
+        Path::new("a/b/../c").normalize()
+    });
+    assert_eq!(normalized, Path::new("a/b/../c").normalize_as(foreign));
+    assert_eq!(default_platform(), Platform::current());
+
+    // Scopes nest, and the outer one is restored after a panic.
+    with_default_platform(foreign, || {
+        let result = std::panic::catch_unwind(|| {
+            with_default_platform(Platform::current(), || panic!("boom"))
+        });
+        assert!(result.is_err());
+        assert_eq!(default_platform(), foreign);
+    });
+
+    // Other threads keep the default.
+    with_default_platform(foreign, || {
+        let other = std::thread::spawn(default_platform).join().unwrap();
+        assert_eq!(other, Platform::current());
+    });
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    with_default_platform(Platform::Windows, || {
+        assert_eq!(Path::new("C:/a/../b").normalize().as_os_str(), "C:\\b");
+        assert_eq!(Path::new("C:/a/b").resolve().as_os_str(), "C:\\a\\b");
+        assert_eq!(Path::new("D:x\\y").resolve().as_os_str(), "D:\\x\\y");
+        assert_eq!(
+            Path::new("C:\\a\\b\\c").relative("c:\\A\\d").as_os_str(),
+            "..\\b\\c"
+        );
+        assert_eq!(Path::new("C:\\a").relative("C:\\a").as_os_str(), "");
+        assert_eq!(Path::new("C:\\a").relative("D:\\a").as_os_str(), "C:\\a");
+        assert_eq!(
+            Path::new("\\\\srv\\share\\a")
+                .relative("\\\\SRV\\share\\b\\c")
+                .as_os_str(),
+            "..\\..\\a"
+        );
+        let mut buf = std::path::PathBuf::new();
+        Path::new("C:\\a\\b").relative_into("C:\\a", &mut buf);
+        assert_eq!(buf.as_os_str(), "b");
+        // Relative paths are joined onto the current directory as it is.
+        assert!(Path::new("x")
+            .resolve()
+            .as_os_str()
+            .to_str()
+            .unwrap()
+            .ends_with("\\x"));
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows_host() {
+    with_default_platform(Platform::Posix, || {
+        assert_eq!(Path::new("/a/../b\\c").normalize().as_os_str(), "/b\\c");
+        assert_eq!(Path::new("/a/b").resolve().as_os_str(), "/a/b");
+        assert_eq!(Path::new("/a/B/c").relative("/a/b").as_os_str(), "../B/c");
+    });
+}
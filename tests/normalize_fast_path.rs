@@ -0,0 +1,43 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+fn normalize(path: &str) -> String {
+    Path::new(path).normalize().to_str().unwrap().to_owned()
+}
+
+#[test]
+fn already_normalized() {
+    for path in [
+        ".", "..", "../..", "a", "a/b", "../a/b", "a/", "../", "a..", ".a/b.",
+    ] {
+        assert_eq!(normalize(path), path);
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    for path in ["/", "/a", "/a/b/", "/a..", "/.a"] {
+        assert_eq!(normalize(path), path);
+    }
+    let cases = [
+        ("", "."),
+        ("./", "./"),
+        ("a/.", "a"),
+        ("a//", "a/"),
+        ("a//b", "a/b"),
+        ("a/..", "."),
+        ("a/../", "./"),
+        ("../a/..", ".."),
+        ("./..", ".."),
+        ("/..", "/"),
+        ("//", "/"),
+        ("//a", "/a"),
+        ("/a/./b", "/a/b"),
+        ("/a/.", "/a"),
+        ("a/../../b", "../b"),
+    ];
+    for (path, expected) in cases {
+        assert_eq!(normalize(path), expected, "{:?}", path);
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::{Path, PathBuf};
+use sugar_path::SugarPath;
+
+#[test]
+fn normalize_into() {
+    let mut buf = PathBuf::from("stale/content/that/is/long");
+    let capacity = buf.capacity();
+    for path in ["", ".", "./", "a", "a/", "a//b/../c", "../a/..", "a/../.."] {
+        Path::new(path).normalize_into(&mut buf);
+        assert_eq!(
+            buf.as_os_str(),
+            Path::new(path).normalize().as_os_str(),
+            "{:?}",
+            path
+        );
+    }
+    assert_eq!(buf.capacity(), capacity);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    let mut buf = PathBuf::new();
+    for path in ["/", "//a/./b/", "/a/../.."] {
+        Path::new(path).normalize_into(&mut buf);
+        assert_eq!(
+            buf.as_os_str(),
+            Path::new(path).normalize().as_os_str(),
+            "{:?}",
+            path
+        );
+    }
+
+    with_default_platform(Platform::Windows, || {
+        for path in ["//server/share/../x", "C:/a/../b/", "a/b\\..\\c"] {
+            Path::new(path).normalize_into(&mut buf);
+            assert_eq!(
+                buf.as_os_str(),
+                Path::new(path).normalize().as_os_str(),
+                "{:?}",
+                path
+            );
+        }
+    });
+    Path::new("//server/share/../x").normalize_into(&mut buf);
+    assert_eq!(buf.as_os_str(), "/server/x");
+
+    // The override writes into `buf` too.
+    let mut buf = PathBuf::from("stale/content/that/is/long");
+    let capacity = buf.capacity();
+    with_default_platform(Platform::Windows, || {
+        for path in ["C:/a/./b", "//server/share", "a/../.."] {
+            Path::new(path).normalize_into(&mut buf);
+        }
+    });
+    assert_eq!(buf.as_os_str(), "..");
+    assert_eq!(buf.capacity(), capacity);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let mut buf = PathBuf::new();
+    for path in [
+        "C:",
+        "C:\\a\\..\\..",
+        "C:a/b",
+        "\\\\server\\share\\a\\..",
+        "a/b\\c",
+    ] {
+        Path::new(path).normalize_into(&mut buf);
+        assert_eq!(
+            buf.as_os_str(),
+            Path::new(path).normalize().as_os_str(),
+            "{:?}",
+            path
+        );
+    }
+}
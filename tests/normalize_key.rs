@@ -0,0 +1,108 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+use sugar_path::{AbsolutePathBuf, NormalizedPathBuf, RelativePathBuf, SugarPath};
+
+#[test]
+fn normalize_key() {
+    for path in [
+        "a",
+        "a/b",
+        "a//b/",
+        "a/./b",
+        "../a",
+        "../../a/b",
+        "..",
+        "a/b/.",
+    ] {
+        let key = Path::new(path).normalize_key();
+        assert!(matches!(key, Cow::Borrowed(_)), "{}", path);
+        assert_eq!(*key, *Path::new(path).normalize(), "{}", path);
+    }
+    for path in ["", ".", "./a", "a/..", "a/b/../c", "../a/.."] {
+        let key = Path::new(path).normalize_key();
+        assert!(matches!(key, Cow::Owned(_)), "{}", path);
+        assert_eq!(key, Path::new(path).normalize(), "{}", path);
+    }
+}
+
+#[test]
+fn hash_map() {
+    let mut map = HashMap::new();
+    for path in ["src/lib.rs", "src/bin/", "../shared", "."] {
+        map.insert(NormalizedPathBuf::new(path), path);
+    }
+    for (query, expected) in [
+        ("src/lib.rs", Some("src/lib.rs")),
+        ("./src/./lib.rs", Some("src/lib.rs")),
+        ("src/x/../lib.rs", Some("src/lib.rs")),
+        ("src//bin", Some("src/bin/")),
+        ("a/../../shared/", Some("../shared")),
+        ("", Some(".")),
+        ("a/..", Some(".")),
+        ("src", None),
+        ("src/lib.rs/..", None),
+    ] {
+        let key = Path::new(query).normalize_key();
+        assert_eq!(map.get(&*key).copied(), expected, "{}", query);
+    }
+    // `Path` lookups compare components as they are.
+    assert_eq!(map.get(Path::new("src/lib.rs")), Some(&"src/lib.rs"));
+    assert_eq!(map.get(Path::new("src/x/../lib.rs")), None);
+
+    let key = Path::new("src/bin/../lib.rs").normalize_key();
+    *map.entry(NormalizedPathBuf::new(key)).or_default() = "replaced";
+    assert_eq!(map.len(), 4);
+    assert_eq!(map[Path::new("src/lib.rs")], "replaced");
+}
+
+#[test]
+fn partial_eq() {
+    let path = NormalizedPathBuf::new("a/./b");
+    assert_eq!(path, Path::new("a/b"));
+    assert_eq!(path, *Path::new("a//b/"));
+    assert_ne!(path, Path::new("a/c/../b"));
+
+    let relative = RelativePathBuf::new("a/../b").unwrap();
+    assert_eq!(relative, Path::new("b"));
+    let set = HashSet::from([relative]);
+    assert!(set.contains(Path::new("b")));
+
+    let absolute = AbsolutePathBuf::resolve("a");
+    assert_eq!(absolute, *Path::new("a").resolve());
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    for path in ["/a/b", "/./a", "//a"] {
+        assert!(
+            matches!(Path::new(path).normalize_key(), Cow::Borrowed(_)),
+            "{}",
+            path
+        );
+    }
+    for path in ["/..", "/a/.."] {
+        assert!(
+            matches!(Path::new(path).normalize_key(), Cow::Owned(_)),
+            "{}",
+            path
+        );
+    }
+    assert_eq!(Path::new("/../a").normalize_key(), Path::new("/a"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let key = Path::new("C:/a/b").normalize_key();
+    assert!(matches!(key, Cow::Borrowed(_)));
+    assert_eq!(key, Path::new("C:\\a\\b"));
+    for path in ["C:", "C:\\..", "\\\\?\\C:/a", "\\a\\.."] {
+        let key = Path::new(path).normalize_key();
+        assert!(matches!(key, Cow::Owned(_)), "{}", path);
+        assert_eq!(key, Path::new(path).normalize(), "{}", path);
+    }
+}
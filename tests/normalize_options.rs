@@ -0,0 +1,81 @@
+use std::path::Path;
+use sugar_path::{DriveLetterCase, NormalizeOptions, SugarPath};
+
+#[test]
+fn defaults_match_sugar_path() {
+    let options = NormalizeOptions::new();
+    for path in ["", "a/../b", "//a", "///a/./b/", "../x"] {
+        assert_eq!(
+            options.normalize(path).as_os_str(),
+            Path::new(path).normalize().as_os_str()
+        );
+        assert_eq!(options.resolve(path), Path::new(path).resolve());
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let options = NormalizeOptions::new().preserve_double_root(true);
+    let normalize = |path: &str| options.normalize(path).into_os_string();
+    assert_eq!(normalize("//"), "//");
+    assert_eq!(normalize("//net/share/../x/"), "//net/x/");
+    assert_eq!(normalize("//.."), "//");
+    assert_eq!(normalize("///a"), "/a");
+    assert_eq!(normalize("/a//b"), "/a/b");
+    assert_eq!(normalize("a//b"), "a/b");
+    assert_eq!(options.resolve("//net/./x").as_os_str(), "//net/x");
+    assert!(!options
+        .resolve("x")
+        .as_os_str()
+        .as_encoded_bytes()
+        .starts_with(b"//"));
+
+    assert_eq!(Path::new("//net/x").normalize().as_os_str(), "/net/x");
+
+    let options = NormalizeOptions::new().drive_letter_case(DriveLetterCase::Upper);
+    assert_eq!(options.normalize("c:/a/../b").as_os_str(), "c:/b");
+
+    assert_eq!(Path::new("a\\..\\b").normalize().as_os_str(), "a\\..\\b");
+    let options = NormalizeOptions::new().backslash_as_separator(true);
+    let normalize = |path: &str| options.normalize(path).into_os_string();
+    assert_eq!(normalize("a\\..\\b"), "b");
+    assert_eq!(normalize("src\\lib.rs"), "src/lib.rs");
+    assert_eq!(normalize("\\a\\.\\b\\"), "/a/b/");
+    assert_eq!(normalize("a/b\\c"), "a/b/c");
+    assert_eq!(options.resolve("\\x\\..\\y").as_os_str(), "/y");
+    let options = options.preserve_double_root(true);
+    assert_eq!(options.normalize("\\\\net\\x").as_os_str(), "//net/x");
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let options = NormalizeOptions::new().preserve_double_root(true);
+    assert_eq!(
+        options.normalize("//server/share/../x").as_os_str(),
+        "\\\\server\\share\\x"
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn drive_letter_case() {
+    let upper = NormalizeOptions::new().drive_letter_case(DriveLetterCase::Upper);
+    let lower = NormalizeOptions::new().drive_letter_case(DriveLetterCase::Lower);
+    assert_eq!(upper.normalize("c:/a/../b").as_os_str(), "C:\\b");
+    assert_eq!(upper.normalize("c:a").as_os_str(), "C:a");
+    assert_eq!(upper.normalize("\\\\?\\c:\\a").as_os_str(), "\\\\?\\C:\\a");
+    assert_eq!(lower.normalize("C:\\a").as_os_str(), "c:\\a");
+    assert_eq!(
+        upper.normalize("\\\\server\\share\\a").as_os_str(),
+        "\\\\server\\share\\a"
+    );
+    assert_eq!(
+        NormalizeOptions::new().normalize("c:\\a").as_os_str(),
+        "c:\\a"
+    );
+    // Whatever the case sensitivity, drive letters are compared ignoring case.
+    assert!(Path::new("c:\\a").path_eq("C:\\a", sugar_path::CaseSensitivity::Sensitive));
+    assert!(!Path::new("c:\\a").path_eq("C:\\A", sugar_path::CaseSensitivity::Sensitive));
+}
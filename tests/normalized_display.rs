@@ -0,0 +1,72 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+fn display(path: &str, separator: char) -> String {
+    Path::new(path).normalized_display(separator).to_string()
+}
+
+#[test]
+fn separator() {
+    assert_eq!(display("a/b/./c", '|'), "a|b|c");
+    assert_eq!(display("../a//b/", ':'), "..:a:b:");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let paths = [
+        "",
+        ".",
+        "./",
+        "..",
+        "../..",
+        "a",
+        "a/",
+        "a/.",
+        "a//b",
+        "a/..",
+        "a/../",
+        "a/b/../..",
+        "a/../../b",
+        "../a/..",
+        "x/a/b/../../y",
+        "/",
+        "//",
+        "/..",
+        "/a/../..",
+        "//a/./b/",
+        "/a/b/../../../c",
+        "bar/foo../..",
+    ];
+    for path in paths {
+        assert_eq!(
+            display(path, '/'),
+            Path::new(path).normalize().to_str().unwrap(),
+            "{:?}",
+            path
+        );
+    }
+    assert_eq!(display("/a/b", '\\'), "\\a\\b");
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let paths = [
+        "C:",
+        "C:\\a\\..\\..",
+        "C:a\\..\\..",
+        "\\a\\..",
+        "a/b\\..\\c",
+        "C:\\",
+    ];
+    for path in paths {
+        assert_eq!(
+            display(path, '\\'),
+            Path::new(path).normalize().to_str().unwrap(),
+            "{:?}",
+            path
+        );
+    }
+    assert_eq!(display("\\\\server\\share\\a", '/'), "//server/share/a");
+}
@@ -0,0 +1,84 @@
+use std::path::{Component, Path, PathBuf};
+use sugar_path::{CollectNormalized, NormalizedPathBuf, SugarPath};
+
+fn collect(path: &str) -> PathBuf {
+    Path::new(path).components().collect_normalized().into()
+}
+
+#[test]
+fn new() {
+    assert_eq!(NormalizedPathBuf::new("").as_path(), Path::new("."));
+    assert_eq!(NormalizedPathBuf::new("a//b/").as_path(), Path::new("a/b/"));
+    assert_eq!(NormalizedPathBuf::new("a/../..").as_path(), Path::new(".."));
+}
+
+#[test]
+fn collect_matches_normalize() {
+    for path in [
+        "",
+        ".",
+        "a",
+        "a/./b/../c",
+        "../a/..",
+        "a/../../b",
+        "./a/b/../../..",
+    ] {
+        assert_eq!(collect(path), Path::new(path).normalize(), "{:?}", path);
+    }
+}
+
+#[test]
+fn from_iter() {
+    let path: NormalizedPathBuf = [
+        Component::Normal("a".as_ref()),
+        Component::CurDir,
+        Component::ParentDir,
+        Component::ParentDir,
+        Component::Normal("b".as_ref()),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(path.as_path(), Path::new("../b"));
+    assert_eq!(
+        std::iter::empty().collect_normalized().as_path(),
+        Path::new(".")
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    for path in ["/", "/a/../..", "//a/./b/", "/../a"] {
+        assert_eq!(
+            collect(path),
+            Path::new(path)
+                .normalize()
+                .components()
+                .collect::<PathBuf>()
+        );
+    }
+    let path = Path::new("a/b")
+        .components()
+        .chain(Path::new("/c/../d").components())
+        .collect_normalized();
+    assert_eq!(path.as_path(), Path::new("/d"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    for path in [
+        "C:\\",
+        "C:\\a\\..\\..",
+        "C:a\\..",
+        "\\\\server\\share\\a\\..",
+        "\\a",
+    ] {
+        assert_eq!(collect(path), Path::new(path).normalize(), "{:?}", path);
+    }
+    let path = Path::new("C:\\a")
+        .components()
+        .chain(Path::new("\\b").components())
+        .collect_normalized();
+    assert_eq!(path.as_path(), Path::new("C:\\b"));
+}
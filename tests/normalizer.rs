@@ -0,0 +1,59 @@
+use std::path::Path;
+use sugar_path::{Normalizer, SugarPath};
+
+fn normalize_segments(segments: &[&str]) -> (std::path::PathBuf, bool) {
+    let mut normalizer = Normalizer::new();
+    for segment in segments {
+        normalizer.feed(segment);
+    }
+    let escaped = normalizer.has_escaped();
+    (normalizer.finish(), escaped)
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let cases: &[&[&str]] = &[
+        &["/foo", "..", "..", "..", "bar"],
+        &["a", "", "b", "..", "b"],
+        &["a", ".", "c"],
+        &["/", "a", "b", "c", "..", "..", "..", "x", "y", "z"],
+        &["bar", "foo..", ".."],
+        &["..", "foo..", "..", "..", "bar"],
+        &["a", "b", "c", "..", "..", ".."],
+        &[],
+        &[""],
+        &["a", "/b"],
+        &["a//b", "./c/"],
+    ];
+    for segments in cases {
+        assert_eq!(
+            normalize_segments(segments).0.as_os_str(),
+            Path::new(&segments.join("/")).normalize().as_os_str(),
+            "{:?}",
+            segments
+        );
+    }
+
+    assert!(!normalize_segments(&["a", "b", "..", ".."]).1);
+    assert!(normalize_segments(&["a", "..", ".."]).1);
+    assert!(normalize_segments(&["/a", "..", ".."]).1);
+
+    let mut normalizer = Normalizer::new();
+    assert!(normalizer.feed("/srv"));
+    assert!(!normalizer.feed("../../etc"));
+    assert!(normalizer.feed("passwd"));
+    assert!(normalizer.has_escaped());
+    assert_eq!(normalizer.as_path(), Path::new("/etc/passwd"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        normalize_segments(&["C:\\a", "..", "b"]).0,
+        Path::new("C:\\b")
+    );
+    assert_eq!(normalize_segments(&["C:"]).0, Path::new("C:."));
+    assert_eq!(normalize_segments(&["a", "C:\\b"]).0, Path::new("a\\C:\\b"));
+}
@@ -0,0 +1,50 @@
+use std::ffi::OsStr;
+use sugar_path::windows::{is_nt_device_path, nt_to_win32};
+
+fn convert(path: &str) -> Option<String> {
+    nt_to_win32(OsStr::new(path)).map(|path| path.into_string().unwrap())
+}
+
+#[test]
+fn to_win32() {
+    assert_eq!(convert(r"\??\C:\x").as_deref(), Some(r"C:\x"));
+    assert_eq!(convert(r"\??\c:\x\").as_deref(), Some(r"C:\x\"));
+    assert_eq!(convert(r"\DosDevices\C:\x").as_deref(), Some(r"C:\x"));
+    assert_eq!(convert(r"\dosdevices\C:\x").as_deref(), Some(r"C:\x"));
+    assert_eq!(convert(r"\GLOBAL??\C:\x").as_deref(), Some(r"C:\x"));
+    assert_eq!(
+        convert(r"\??\UNC\server\share").as_deref(),
+        Some(r"\\server\share")
+    );
+    assert_eq!(
+        convert(r"\??\Volume{2eca078d-5cbc-43d3-aff8-7e8511f60d0e}\x").as_deref(),
+        Some(r"\\?\Volume{2eca078d-5cbc-43d3-aff8-7e8511f60d0e}\x")
+    );
+    // Only paths that Win32 reads the same way lose the verbatim prefix.
+    assert_eq!(convert(r"\??\C:\a\nul").as_deref(), Some(r"\\?\C:\a\nul"));
+    assert_eq!(convert(r"\??\C:\a.").as_deref(), Some(r"\\?\C:\a."));
+}
+
+#[test]
+fn not_nt_paths() {
+    for path in [
+        "",
+        r"\??\",
+        r"\??",
+        r"C:\x",
+        r"\\?\C:\x",
+        r"\Device\HarddiskVolume1\x",
+        r"??\C:\x",
+    ] {
+        assert_eq!(convert(path), None, "{:?}", path);
+    }
+}
+
+#[test]
+fn device_paths() {
+    assert!(is_nt_device_path(OsStr::new(r"\Device\HarddiskVolume1\x")));
+    assert!(is_nt_device_path(OsStr::new(r"\device\Mup\server\share")));
+    for path in ["", r"\Device\", r"\Device", r"\??\C:\x", r"C:\Device\x"] {
+        assert!(!is_nt_device_path(OsStr::new(path)), "{:?}", path);
+    }
+}
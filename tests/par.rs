@@ -0,0 +1,68 @@
+#![cfg(feature = "rayon")]
+
+use std::path::{Path, PathBuf};
+use sugar_path::{
+    par_filter_matches, par_normalize_many, par_resolve_many, with_default_platform, Pattern,
+    Platform, SugarPath,
+};
+
+fn paths() -> Vec<PathBuf> {
+    (0..2000)
+        .map(|i| {
+            PathBuf::from(format!(
+                "dir{}/./sub/../file{}.{}",
+                i % 7,
+                i,
+                if i % 3 == 0 { "rs" } else { "js" }
+            ))
+        })
+        .collect()
+}
+
+#[test]
+fn normalize_many() {
+    let paths = paths();
+    let expected = paths
+        .iter()
+        .map(|path| path.normalize())
+        .collect::<Vec<_>>();
+    assert_eq!(par_normalize_many(&paths), expected);
+    assert!(par_normalize_many::<&str>(&[]).is_empty());
+}
+
+#[test]
+fn resolve_many() {
+    let paths = paths();
+    let expected = paths.iter().map(|path| path.resolve()).collect::<Vec<_>>();
+    assert_eq!(par_resolve_many(&paths), expected);
+}
+
+#[test]
+fn filter_matches() {
+    let paths = paths();
+    let pattern = Pattern::new("dir3/*.rs").unwrap();
+    let expected = paths
+        .iter()
+        .filter(|path| pattern.matches(path))
+        .collect::<Vec<_>>();
+    assert!(!expected.is_empty());
+    assert_eq!(par_filter_matches(&paths, &pattern), expected);
+    assert_eq!(
+        par_filter_matches(&["a/b.rs", "b.rs"], &Pattern::new("*.rs").unwrap()),
+        [&"b.rs"]
+    );
+    assert_eq!(par_normalize_many(&[Path::new("")]), [Path::new(".")]);
+}
+
+#[test]
+fn default_platform() {
+    // The workers follow the default platform of the calling thread.
+    let (normalized, resolved) = with_default_platform(Platform::Windows, || {
+        (
+            par_normalize_many(&["a/./b"; 64]),
+            par_resolve_many(&["C:/x/../y"; 64]),
+        )
+    });
+    assert!(normalized.iter().all(|path| path.as_os_str() == r"a\b"));
+    assert!(resolved.iter().all(|path| path.as_os_str() == r"C:\y"));
+}
@@ -0,0 +1,41 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[test]
+fn relative() {
+    assert_eq!(Path::new("a/b/c").parts(), ["a", "b", "c"]);
+    assert_eq!(Path::new("./a//b/").parts(), ["a", "b"]);
+    assert_eq!(Path::new("../a").parts(), ["..", "a"]);
+    assert!(Path::new("").parts().is_empty());
+    assert!(Path::new("a/..").parts().is_empty());
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    assert_eq!(Path::new("/").parts(), ["/"]);
+    assert_eq!(
+        Path::new("/usr/../etc/hosts").parts(),
+        ["/", "etc", "hosts"]
+    );
+    assert_eq!(Path::new("a\\b").parts(), ["a\\b"]);
+
+    let parts = with_default_platform(Platform::Windows, || {
+        Path::new("C:/usr/../etc/hosts").parts()
+    });
+    assert_eq!(parts, [r"C:\", "etc", "hosts"]);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(Path::new("C:\\").parts(), ["C:\\"]);
+    assert_eq!(Path::new("C:foo\\bar").parts(), ["C:", "foo", "bar"]);
+    assert_eq!(Path::new("a/b").parts(), ["a", "b"]);
+    assert_eq!(
+        Path::new("\\\\server\\share\\x").parts(),
+        ["\\\\server\\share\\", "x"]
+    );
+}
@@ -0,0 +1,155 @@
+use std::path::Path;
+use sugar_path::{PathEdit, SugarPath};
+
+fn assert_round_trip(edit: &PathEdit, path: &str, expected: &str) {
+    let output = edit.apply(path).unwrap();
+    assert_eq!(output, Path::new(expected), "{}", path);
+    assert_eq!(
+        edit.invert().apply(&output).unwrap(),
+        Path::new(path).normalize(),
+        "{}",
+        path
+    );
+}
+
+#[test]
+fn empty() {
+    let edit = PathEdit::new();
+    assert_eq!(edit, edit.invert());
+    assert_round_trip(&edit, "a/./b", "a/b");
+    assert_round_trip(&edit, "", ".");
+}
+
+#[test]
+fn strip_prefix_and_push() {
+    let edit = PathEdit::new().strip_prefix("src/.").push("generated/x");
+    assert_round_trip(&edit, "src/a/b.rs", "a/b.rs/generated/x");
+    assert_round_trip(&edit, "src", "generated/x");
+    assert_eq!(edit.apply("srcs/a"), None);
+    assert_eq!(edit.invert().apply("a/b"), None);
+    assert_eq!(
+        edit.invert().apply("generated/x").unwrap(),
+        Path::new("src")
+    );
+
+    let edit = PathEdit::new().strip_prefix("");
+    assert_round_trip(&edit, "a", "a");
+}
+
+#[test]
+fn rebase() {
+    let edit = PathEdit::new().rebase("src/lib", "../out");
+    assert_round_trip(&edit, "src/lib/a/../b.js", "../out/b.js");
+    assert_round_trip(&edit, "src/lib", "../out");
+    assert_eq!(edit.apply("src/library/b.js"), None);
+    assert_eq!(edit.apply("../src/lib/b.js"), None);
+
+    let edit = PathEdit::new().rebase("", "out");
+    assert_round_trip(&edit, "a/b", "out/a/b");
+    assert_round_trip(&edit, "", "out");
+    assert_eq!(edit.apply(".."), None);
+
+    let edit = PathEdit::new().rebase("", "../..");
+    assert_round_trip(&edit, "../x", "../../../x");
+}
+
+#[test]
+fn set_extension() {
+    let edit = PathEdit::new().set_extension(".ts", "js");
+    assert_round_trip(&edit, "a/b.ts", "a/b.js");
+    assert_round_trip(&edit, "a/b.d.ts", "a/b.d.js");
+    assert_eq!(edit.apply("a/b.tsx"), None);
+    assert_eq!(edit.apply("a/b.TS"), None);
+    assert_eq!(edit.apply("a/.ts"), None);
+    assert_eq!(edit.apply("a/ts"), None);
+    assert_eq!(edit.apply(".."), None);
+
+    let edit = PathEdit::new().set_extension("tar.gz", "tgz");
+    assert_round_trip(&edit, "dist/app.tar.gz", "dist/app.tgz");
+
+    let edit = PathEdit::new().set_extension("", "map");
+    assert_round_trip(&edit, "app.js", "app.js.map");
+    assert_round_trip(&edit, "README", "README.map");
+    assert_eq!(edit.apply(""), None);
+
+    let edit = PathEdit::new().set_extension("gz", "");
+    assert_round_trip(&edit, "a.tar.gz", "a.tar");
+}
+
+#[test]
+fn insert_suffix() {
+    let edit = PathEdit::new().insert_suffix(".min");
+    assert_round_trip(&edit, "dist/app.js", "dist/app.min.js");
+    assert_round_trip(&edit, "dist/app.min.js", "dist/app.min.min.js");
+    assert_round_trip(&edit, "dist/.env.js", "dist/.env.min.js");
+    assert_eq!(edit.apply("dist/app"), None);
+    assert_eq!(edit.apply("dist/.env"), None);
+    assert_eq!(edit.invert().apply("dist/app.js"), None);
+    assert_eq!(edit.invert().apply("dist/.min.js"), None);
+
+    let edit = PathEdit::new().insert_suffix("-ü");
+    assert_round_trip(&edit, "a/b.c", "a/b-ü.c");
+}
+
+#[test]
+fn build_outputs() {
+    let edit = PathEdit::new()
+        .rebase("src", "dist/esm")
+        .set_extension("ts", "js")
+        .insert_suffix(".min");
+    let sources = ["src/index.ts", "src/a/b.ts", "src/a/../c.d.ts"];
+    let outputs = sources
+        .iter()
+        .map(|source| edit.apply(source).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        outputs,
+        [
+            Path::new("dist/esm/index.min.js"),
+            Path::new("dist/esm/a/b.min.js"),
+            Path::new("dist/esm/c.d.min.js"),
+        ]
+    );
+    let inverse = edit.invert();
+    assert_eq!(inverse.invert(), edit);
+    for (source, output) in sources.iter().zip(&outputs) {
+        assert_eq!(
+            inverse.apply(output).unwrap(),
+            Path::new(source).normalize()
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "only plain names can be pushed")]
+fn push_parent() {
+    let _ = PathEdit::new().push("a/../..");
+}
+
+#[test]
+#[should_panic(expected = "a suffix can't contain a separator")]
+fn suffix_with_separator() {
+    let _ = PathEdit::new().insert_suffix("a/b");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let edit = PathEdit::new().rebase("/home/me/src", "/tmp/build");
+    assert_round_trip(&edit, "/home/me/src/a.c", "/tmp/build/a.c");
+    assert_eq!(edit.apply("home/me/src/a.c"), None);
+
+    let edit = PathEdit::new().rebase("", "out");
+    assert_eq!(edit.apply("/a"), None);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let edit = PathEdit::new().rebase("C:/src", "D:\\build");
+    assert_round_trip(&edit, "C:\\src\\a.c", "D:\\build\\a.c");
+
+    let edit = PathEdit::new().rebase("", "out");
+    assert_eq!(edit.apply("C:a"), None);
+    assert_eq!(edit.apply("\\a"), None);
+}
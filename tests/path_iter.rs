@@ -0,0 +1,82 @@
+use std::path::{Path, PathBuf};
+use sugar_path::{SugarPath, SugarPathIter};
+
+#[test]
+fn normalize_all() {
+    let paths = vec![
+        PathBuf::from("a/./b"),
+        PathBuf::from(""),
+        PathBuf::from("a/../.."),
+    ];
+    assert_eq!(
+        paths.into_iter().normalize_all().collect::<Vec<_>>(),
+        [Path::new("a/b"), Path::new("."), Path::new("..")]
+    );
+}
+
+#[test]
+fn resolve_all() {
+    let paths = ["a", "b/../c"];
+    assert_eq!(
+        paths.iter().resolve_all().collect::<Vec<_>>(),
+        [Path::new("a").resolve(), Path::new("c").resolve()]
+    );
+}
+
+#[test]
+fn filter_descendants_of() {
+    let paths = [
+        "src",
+        "src/",
+        "src/a",
+        "./src/b/c",
+        "src2/a",
+        "src/../d",
+        "a/../src/e",
+    ];
+    assert_eq!(
+        paths
+            .iter()
+            .filter_descendants_of("./src/")
+            .copied()
+            .collect::<Vec<_>>(),
+        ["src/a", "./src/b/c", "a/../src/e"]
+    );
+    let paths = [".", "a", "../a", ""];
+    assert_eq!(
+        paths
+            .iter()
+            .filter_descendants_of("")
+            .copied()
+            .collect::<Vec<_>>(),
+        ["a"]
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let paths = ["/a/b", "a/b", "/a", "//a/./c"];
+    assert_eq!(
+        paths
+            .iter()
+            .filter_descendants_of("/a")
+            .copied()
+            .collect::<Vec<_>>(),
+        ["/a/b", "//a/./c"]
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let paths = ["C:\\a\\b", "c:\\A\\c", "D:\\a\\b", "C:\\a"];
+    assert_eq!(
+        paths
+            .iter()
+            .filter_descendants_of("C:\\a")
+            .copied()
+            .collect::<Vec<_>>(),
+        ["C:\\a\\b", "c:\\A\\c"]
+    );
+}
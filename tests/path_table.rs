@@ -0,0 +1,102 @@
+use std::path::{Path, PathBuf};
+use sugar_path::{PathTable, SugarPath};
+
+#[test]
+fn insert_and_get() {
+    let mut table = PathTable::new();
+    assert!(table.is_empty());
+    let a = table.insert("a/b/c");
+    let b = table.insert("a/b");
+    assert_ne!(a, b);
+    assert_eq!(table.insert("a/./b/x/../c/"), a);
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.get("a/b/c"), Some(a));
+    assert_eq!(table.get("a/b/"), Some(b));
+    assert_eq!(table.get("a"), None);
+    assert_eq!(table.get("a/b/c/d"), None);
+    assert_eq!(table.get("x"), None);
+    assert!(table.contains("a/b/c"));
+    assert_eq!(table.path(a), Path::new("a/b/c"));
+    assert_eq!(table.path(b), Path::new("a/b"));
+
+    let dot = table.insert("");
+    assert_eq!(table.path(dot).as_os_str(), ".");
+    let up = table.insert("../x");
+    assert_eq!(table.path(up), Path::new("../x"));
+    assert_eq!(table.len(), 4);
+}
+
+#[test]
+fn sorted_iteration() {
+    let paths = [
+        "src/lib.rs",
+        "src/a/b.rs",
+        "src-old/x",
+        "src",
+        "b",
+        "../up",
+        "a.b/c",
+        "a/c",
+        ".",
+    ];
+    let table = paths.iter().collect::<PathTable>();
+    let mut expected = paths.map(|path| Path::new(path).normalize());
+    expected.sort();
+    let actual = table.iter().map(|(_, path)| path).collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+    for (id, path) in table.iter() {
+        assert_eq!(table.get(&path), Some(id));
+    }
+}
+
+#[test]
+fn shares_prefixes() {
+    let mut table = PathTable::new();
+    table.insert("repo/crates/core/src/lib.rs");
+    let before = table.memory_usage();
+    assert!(before > 0);
+    let mut paths = vec![];
+    for i in 0..1000 {
+        paths.push(PathBuf::from(format!(
+            "repo/crates/core/src/lib.rs/{}",
+            i % 10
+        )));
+    }
+    table.extend(&paths);
+    assert_eq!(table.len(), 11);
+    assert!(table.memory_usage() < before * 10);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let table = ["/usr/lib", "usr/lib", "/a", "//x/../usr/bin"]
+        .into_iter()
+        .collect::<PathTable>();
+    assert_eq!(
+        table.iter().map(|(_, path)| path).collect::<Vec<_>>(),
+        [
+            Path::new("/a"),
+            Path::new("/usr/bin"),
+            Path::new("/usr/lib"),
+            Path::new("usr/lib")
+        ]
+    );
+    assert_ne!(table.get("/usr/lib"), table.get("usr/lib"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let table = ["C:\\a", "c:/b", "\\a", "a"]
+        .into_iter()
+        .collect::<PathTable>();
+    assert_eq!(table.len(), 4);
+    assert_eq!(table.path(table.get("C:\\a").unwrap()), Path::new("C:\\a"));
+    let mut expected = ["C:\\a", "c:\\b", "\\a", "a"].map(PathBuf::from);
+    expected.sort();
+    assert_eq!(
+        table.iter().map(|(_, path)| path).collect::<Vec<_>>(),
+        expected
+    );
+}
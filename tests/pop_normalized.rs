@@ -0,0 +1,51 @@
+use std::path::{Path, PathBuf};
+use sugar_path::SugarPathBuf;
+
+fn pop(path: &str) -> Option<PathBuf> {
+    let mut path = PathBuf::from(path);
+    path.pop_normalized().then_some(path)
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    assert_eq!(pop("a/b/..").unwrap(), Path::new("."));
+    assert_eq!(pop("/a/b/../c/").unwrap(), Path::new("/a"));
+    assert_eq!(pop("/a").unwrap(), Path::new("/"));
+    assert_eq!(pop("a/./b").unwrap(), Path::new("a"));
+    assert_eq!(pop("a").unwrap().as_os_str(), ".");
+    assert_eq!(pop("").unwrap().as_os_str(), "..");
+    assert_eq!(pop(".").unwrap().as_os_str(), "..");
+    assert_eq!(pop("../..").unwrap().as_os_str(), "../../..");
+    assert_eq!(pop("a/../..").unwrap().as_os_str(), "../..");
+    assert_eq!(pop("/"), None);
+    assert_eq!(pop("/.."), None);
+
+    let mut path = PathBuf::from("/..");
+    path.pop_normalized();
+    assert_eq!(path.as_os_str(), "/..");
+
+    with_default_platform(Platform::Windows, || {
+        assert_eq!(pop(r"C:\a\b").unwrap().as_os_str(), r"C:\a");
+        assert_eq!(pop(r"C:\a/").unwrap().as_os_str(), r"C:\");
+        assert_eq!(pop(r"a\..").unwrap().as_os_str(), "..");
+        assert_eq!(pop(r"C:\"), None);
+        assert_eq!(pop("//server/share"), None);
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(pop("C:\\a\\b\\..").unwrap(), Path::new("C:\\"));
+    assert_eq!(pop("C:\\"), None);
+    assert_eq!(
+        pop("\\\\server\\share\\a").unwrap(),
+        Path::new("\\\\server\\share\\")
+    );
+    assert_eq!(pop("\\\\server\\share\\"), None);
+    assert_eq!(pop("C:a").unwrap(), Path::new("C:."));
+    assert_eq!(pop("C:").unwrap(), Path::new("C:.."));
+}
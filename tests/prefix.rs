@@ -0,0 +1,64 @@
+use std::ffi::OsStr;
+use sugar_path::windows::{parse_prefix, PrefixKind};
+
+fn parse(path: &str) -> Option<(PrefixKind<'_>, &str)> {
+    parse_prefix(OsStr::new(path)).map(|(kind, rest)| (kind, rest.to_str().unwrap()))
+}
+
+fn os(s: &str) -> &OsStr {
+    OsStr::new(s)
+}
+
+#[test]
+fn prefix() {
+    assert_eq!(parse("C:\\foo"), Some((PrefixKind::Disk(b'C'), "\\foo")));
+    assert_eq!(parse("c:foo"), Some((PrefixKind::Disk(b'C'), "foo")));
+    assert_eq!(parse("c:"), Some((PrefixKind::Disk(b'C'), "")));
+    assert_eq!(
+        parse("\\\\server\\share\\foo"),
+        Some((PrefixKind::UNC(os("server"), os("share")), "\\foo"))
+    );
+    assert_eq!(
+        parse("//server/share"),
+        Some((PrefixKind::UNC(os("server"), os("share")), ""))
+    );
+    assert_eq!(
+        parse("\\\\server"),
+        Some((PrefixKind::UNC(os("server"), os("")), ""))
+    );
+    assert_eq!(
+        parse("\\\\?\\C:\\foo"),
+        Some((PrefixKind::VerbatimDisk(b'C'), "\\foo"))
+    );
+    assert_eq!(
+        parse("\\\\?\\UNC\\server\\share\\foo"),
+        Some((PrefixKind::VerbatimUNC(os("server"), os("share")), "\\foo"))
+    );
+    assert_eq!(
+        parse("\\\\?\\Volume{abc}\\foo"),
+        Some((PrefixKind::Verbatim(os("Volume{abc}")), "\\foo"))
+    );
+    // Verbatim paths don't treat `/` as a separator.
+    assert_eq!(
+        parse("\\\\?\\C:/foo"),
+        Some((PrefixKind::Verbatim(os("C:/foo")), ""))
+    );
+    assert_eq!(
+        parse("\\\\.\\COM1\\foo"),
+        Some((PrefixKind::DeviceNS(os("COM1")), "\\foo"))
+    );
+    assert_eq!(
+        parse("//./pipe/name"),
+        Some((PrefixKind::DeviceNS(os("pipe")), "/name"))
+    );
+
+    assert_eq!(parse("\\foo"), None);
+    assert_eq!(parse("/foo"), None);
+    assert_eq!(parse("foo"), None);
+    assert_eq!(parse("1:\\foo"), None);
+    assert_eq!(parse("\\\\\\foo"), None);
+    assert_eq!(parse(""), None);
+
+    assert!(parse("\\\\?\\C:\\").unwrap().0.is_verbatim());
+    assert!(!parse("C:\\").unwrap().0.is_verbatim());
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "fs")]
+
+use std::{
+    cell::Cell,
+    io,
+    path::{Path, PathBuf},
+};
+use sugar_path::{find_project_root, FileSystem, Metadata, ProjectRoots, SugarPath, VirtualTree};
+
+/// Counts the `metadata` calls made to a tree.
+struct Counting {
+    tree: VirtualTree,
+    calls: Cell<usize>,
+}
+
+impl FileSystem for Counting {
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        self.calls.set(self.calls.get() + 1);
+        self.tree.metadata(path)
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        self.tree.read_link(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        self.tree.read_dir(path)
+    }
+}
+
+fn tree() -> VirtualTree {
+    [
+        "/repo/.git/HEAD",
+        "/repo/README.md",
+        "/repo/web/package.json",
+        "/repo/web/src/index.js",
+        "/repo/tools/x.py",
+        "/other/file",
+    ]
+    .into_iter()
+    .map(|path| Path::new(path).resolve())
+    .collect()
+}
+
+fn abs(path: &str) -> PathBuf {
+    Path::new(path).resolve()
+}
+
+#[test]
+fn find() {
+    let tree = tree();
+    let mut roots = ProjectRoots::with_file_system(&tree);
+    assert_eq!(
+        roots.find(abs("/repo/web/src/index.js")),
+        Some(abs("/repo/web"))
+    );
+    assert_eq!(roots.find(abs("/repo/web")), Some(abs("/repo/web")));
+    assert_eq!(roots.find(abs("/repo/tools/x.py")), Some(abs("/repo")));
+    assert_eq!(
+        roots.find(abs("/repo/web/../README.md")),
+        Some(abs("/repo"))
+    );
+    assert_eq!(roots.find(abs("/other/file")), None);
+    // Paths don't have to exist.
+    assert_eq!(
+        roots.find(abs("/repo/web/new/file.js")),
+        Some(abs("/repo/web"))
+    );
+
+    assert_eq!(
+        roots.project_relative(abs("/repo/web/src/./index.js")),
+        Some(PathBuf::from("src/index.js"))
+    );
+    assert_eq!(roots.project_relative(abs("/other/file")), None);
+
+    let mut roots = ProjectRoots::with_file_system(&tree).markers(["README.md"]);
+    assert_eq!(
+        roots.find(abs("/repo/web/src/index.js")),
+        Some(abs("/repo"))
+    );
+    let mut roots = ProjectRoots::with_file_system(&tree).markers(Vec::<&str>::new());
+    assert_eq!(roots.find(abs("/repo/web/src/index.js")), None);
+}
+
+#[test]
+fn cache() {
+    let fs = Counting {
+        tree: tree(),
+        calls: Cell::new(0),
+    };
+    let mut roots = ProjectRoots::with_file_system(&fs).markers([".git"]);
+    assert_eq!(
+        roots.find(abs("/repo/web/src/index.js")),
+        Some(abs("/repo"))
+    );
+    let calls = fs.calls.get();
+    assert!(calls > 0);
+    assert_eq!(
+        roots.find(abs("/repo/web/src/other.js")),
+        Some(abs("/repo"))
+    );
+    assert_eq!(roots.find(abs("/repo/web")), Some(abs("/repo")));
+    assert_eq!(fs.calls.get(), calls + 1);
+
+    roots.clear_cache();
+    assert_eq!(roots.find(abs("/repo/web")), Some(abs("/repo")));
+    assert!(fs.calls.get() > calls + 1);
+}
+
+#[test]
+fn real_file_system() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    assert_eq!(
+        find_project_root(manifest_dir.join("src/lib.rs")).as_deref(),
+        Some(manifest_dir)
+    );
+    assert_eq!(
+        ProjectRoots::new().project_relative(manifest_dir.join("tests")),
+        Some(PathBuf::from("tests"))
+    );
+}
@@ -0,0 +1,89 @@
+use std::path::{Path, PathBuf};
+use sugar_path::{SugarPath, SugarPathBuf};
+
+fn push(base: &str, path: &str) -> PathBuf {
+    let mut base = Path::new(base).normalize();
+    base.push_normalized(path);
+    base
+}
+
+fn push_allow_absolute(base: &str, path: &str) -> PathBuf {
+    let mut base = Path::new(base).normalize();
+    base.push_normalized_allow_absolute(path);
+    base
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    // Pushing onto a normalized path gives the same result as normalizing the joined path.
+    let cases = [
+        ("a/b", "../c"),
+        ("a/b", "../../../c"),
+        ("/a/b", "../../../c"),
+        ("/a/b", "./c//d/."),
+        ("", "a"),
+        ("", ".."),
+        ("a", ".."),
+        ("a", "../.."),
+        ("..", "../a/../b"),
+        ("/", ".."),
+        ("a/b", ""),
+        ("a/b", "c/../.."),
+    ];
+    for (base, path) in cases {
+        assert_eq!(
+            push(base, path).as_os_str(),
+            Path::new(base).join(path).normalize().as_os_str(),
+            "{:?} {:?}",
+            base,
+            path
+        );
+    }
+
+    assert_eq!(push("a/b", "/etc/passwd"), Path::new("a/b/etc/passwd"));
+    assert_eq!(push("/srv", "/../etc"), Path::new("/etc"));
+
+    assert_eq!(push_allow_absolute("a/b", "/etc/../x"), Path::new("/x"));
+    assert_eq!(push_allow_absolute("a/b", "c/../d"), Path::new("a/b/d"));
+
+    let mut path = PathBuf::new();
+    path.push_normalized(".");
+    assert_eq!(path.as_os_str(), "");
+    path.push_normalized("a/..");
+    assert_eq!(path.as_os_str(), ".");
+    path.push_normalized("b");
+    assert_eq!(path.as_os_str(), "b");
+
+    with_default_platform(Platform::Windows, || {
+        assert_eq!(push(r"C:\a\b", r"..\c").as_os_str(), r"C:\a\c");
+        assert_eq!(push(r"C:\a\b", r"D:\etc").as_os_str(), r"C:\a\b\etc");
+        assert_eq!(push(r"C:\a\b", "").as_os_str(), r"C:\a\b\");
+        assert_eq!(
+            push_allow_absolute(r"C:\a\b", r"\etc").as_os_str(),
+            r"C:\etc"
+        );
+        assert_eq!(
+            push_allow_absolute(r"C:\a\b", "D:/x/").as_os_str(),
+            r"D:\x\"
+        );
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(push("C:\\a\\b", "..\\c"), Path::new("C:\\a\\c"));
+    assert_eq!(push("C:\\a", "D:\\x"), Path::new("C:\\a\\x"));
+    assert_eq!(push("C:\\a", "\\x"), Path::new("C:\\a\\x"));
+    assert_eq!(push("C:a", ".."), Path::new("C:."));
+    assert_eq!(push("C:a", "..\\.."), Path::new("C:.."));
+
+    assert_eq!(
+        push_allow_absolute("C:\\a", "D:\\x\\..\\y"),
+        Path::new("D:\\y")
+    );
+    assert_eq!(push_allow_absolute("C:\\a", "\\x"), Path::new("C:\\x"));
+}
@@ -0,0 +1,129 @@
+use sugar_path::{CaseSensitivity, Query, QueryError};
+
+#[test]
+fn builder() {
+    let query = Query::new();
+    assert!(query.matches(""));
+    assert!(query.matches("a/b/c"));
+
+    let query = Query::new().depth_at_least(2).depth_at_most(3);
+    assert!(!query.matches("a"));
+    assert!(query.matches("a/b"));
+    assert!(query.matches("a/b/c"));
+    assert!(!query.matches("a/b/c/d"));
+    assert!(query.matches("a/b/c/d/.."));
+    assert!(query.matches("../a"));
+
+    let query = Query::new()
+        .depth_at_least(3)
+        .depth_at_least(1)
+        .depth_at_most(5)
+        .depth_at_most(4);
+    assert_eq!(query, Query::new().depth_at_least(3).depth_at_most(4));
+
+    let query = Query::new().component_eq(0, "src").component_eq(1, "bin");
+    assert!(query.matches("src/bin/main.rs"));
+    assert!(query.matches("./src/x/../bin"));
+    assert!(!query.matches("src"));
+    assert!(!query.matches("Src/bin"));
+    assert!(query
+        .clone()
+        .case_sensitivity(CaseSensitivity::AsciiInsensitive)
+        .matches("Src/BIN"));
+
+    let query = Query::new().extension_in([".rs", "tar.gz"]);
+    assert!(query.matches("a/b.RS"));
+    assert!(query.matches("b.tar.gz"));
+    assert!(!query.matches("b.gz"));
+    assert!(!query.matches(".rs"));
+    assert!(!query.matches("a.rs/.."));
+    assert!(!query.clone().extension_in(["gz"]).matches("x.gz"));
+    assert!(query.clone().extension_in(["RS"]).matches("x.rs"));
+    assert!(!Query::new()
+        .extension_in(Vec::<String>::new())
+        .matches("x.rs"));
+}
+
+#[test]
+fn parse() {
+    let parse = |s: &str| s.parse::<Query>();
+    assert_eq!(
+        parse("depth>=1&&depth<=2").unwrap(),
+        Query::new().depth_at_least(1).depth_at_most(2)
+    );
+    assert_eq!(
+        parse("depth = 2").unwrap(),
+        Query::new().depth_at_least(2).depth_at_most(2)
+    );
+    assert_eq!(parse("depth == 2").unwrap(), parse("depth = 2").unwrap());
+    assert_eq!(
+        parse(" component[1] == my dir ").unwrap(),
+        Query::new().component_eq(1, "my dir")
+    );
+    assert_eq!(
+        parse("extension in rs | .toml").unwrap(),
+        Query::new().extension_in(["rs", "toml"])
+    );
+    assert_eq!(
+        parse("extension = md").unwrap(),
+        Query::new().extension_in(["md"])
+    );
+
+    for invalid in [
+        "depth > 2",
+        "depth >= x",
+        "component[x] = a",
+        "component[0] =",
+        "component[0 = a",
+        "extension in rs||md",
+        "extension rs",
+        "name = a",
+        "depth >= 1 &&",
+    ] {
+        assert_eq!(
+            parse(invalid),
+            Err(QueryError::InvalidClause(
+                invalid.rsplit("&&").next().unwrap().trim().to_string()
+            )),
+            "{}",
+            invalid
+        );
+    }
+    assert_eq!(parse(" "), Err(QueryError::Empty));
+}
+
+#[test]
+fn display() {
+    for query in [
+        Query::new(),
+        Query::new().depth_at_most(0),
+        Query::new().depth_at_least(1).depth_at_most(3),
+        Query::new()
+            .component_eq(2, "x")
+            .extension_in(["rs", "toml"]),
+    ] {
+        assert_eq!(query.to_string().parse::<Query>().unwrap(), query);
+    }
+    assert_eq!(Query::new().to_string(), "depth >= 0");
+    assert_eq!(Query::new().depth_at_most(2).to_string(), "depth <= 2");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let query = Query::new().component_eq(0, "src").depth_at_most(2);
+    assert!(query.matches("/src/lib.rs"));
+    assert!(query.matches("//src/lib.rs"));
+    assert!(!query.matches("/srv/src/lib.rs"));
+    assert!(Query::new().depth_at_most(0).matches("/"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let query = Query::new().component_eq(0, "src").depth_at_most(2);
+    assert!(query.matches("C:\\src\\lib.rs"));
+    assert!(query.matches("\\\\server\\share\\src\\lib.rs"));
+    assert!(query.matches("C:src\\lib.rs"));
+    assert!(!query.matches("C:\\srv\\src\\lib.rs"));
+}
@@ -0,0 +1,67 @@
+use std::path::Path;
+use sugar_path::Redactor;
+
+#[test]
+fn redact() {
+    let redactor = Redactor::empty();
+    assert_eq!(redactor.redact("a/./b/../c/"), "a/c");
+    assert_eq!(redactor.redact(""), ".");
+    assert_eq!(redactor.redact("../x"), "../x");
+
+    let redactor = Redactor::empty().username("Alice");
+    assert_eq!(redactor.redact("x/alice/y"), "x/<user>/y");
+    assert_eq!(redactor.redact("x/ALICE"), "x/<user>");
+    assert_eq!(redactor.redact("x/alice2"), "x/alice2");
+
+    let redactor = Redactor::empty().hash_names(true);
+    let hashed = redactor.redact("secret/secret.txt");
+    let (a, b) = hashed.split_once('/').unwrap();
+    assert_eq!(a.len(), 8);
+    assert_ne!(a, b);
+    assert_eq!(redactor.redact("secret"), a);
+    assert_eq!(redactor.redact("../secret/."), format!("../{}", a));
+    assert!(!hashed.contains("secret"));
+
+    assert_eq!(Redactor::empty().username(""), Redactor::empty());
+    assert_eq!(
+        Redactor::empty().username("a").username("a"),
+        Redactor::empty().username("a")
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let redactor = Redactor::empty().home("/home/alice/");
+    assert_eq!(redactor.redact("/home/alice"), "~");
+    assert_eq!(redactor.redact("/home/alice/x/../.ssh/id"), "~/.ssh/id");
+    assert_eq!(redactor.redact("/home/alicex/a"), "/home/alicex/a");
+    assert_eq!(redactor.redact("/home/bob/alice"), "/home/bob/<user>");
+    assert_eq!(redactor.redact("home/alice/a"), "home/<user>/a");
+
+    let redactor = redactor.username("bob").hash_names(true);
+    let hashed = redactor.redact("/srv/bob/data");
+    assert!(hashed.starts_with('/'));
+    assert!(hashed.contains("/<user>/"));
+    assert!(!hashed.contains("srv") && !hashed.contains("data"));
+
+    if let Some(home) = std::env::var_os("HOME").filter(|home| !home.is_empty()) {
+        let path = Path::new(&home).join("project");
+        assert_eq!(Redactor::new().redact(&path), "~/project");
+    }
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let redactor = Redactor::empty().home("C:\\Users\\Alice");
+    assert_eq!(
+        redactor.redact("C:\\Users\\Alice\\Desktop\\x.txt"),
+        "~/Desktop/x.txt"
+    );
+    assert_eq!(redactor.redact("D:\\alice\\x"), "D:/<user>/x");
+    assert_eq!(
+        redactor.redact("\\\\server\\share\\Alice"),
+        "//server/share/<user>"
+    );
+}
@@ -66,6 +66,9 @@ fn windows() {
         ("C:\\baz", "\\\\foo\\bar\\baz", "\\\\foo\\bar\\baz"),
         ("C:\\baz-quux", "C:\\baz", "..\\baz"),
         ("C:\\baz", "C:\\baz-quux", "..\\baz-quux"),
+        ("c:\\x", "C:\\x\\y", "y"),
+        ("C:\\x\\y", "c:\\x", ".."),
+        ("\\\\Server\\Share\\x", "\\\\server\\share\\y", "..\\y"),
     ];
     cases.into_iter().for_each(|(base, target, right)| {
         assert_eq!(
@@ -102,3 +105,28 @@ fn windows_unc() {
         );
     });
 }
+
+#[test]
+fn trailing_separator_is_dropped() {
+    let base = Path::new("base").resolve();
+    assert_eq!(
+        base.join("a/b/").relative(&base).as_os_str(),
+        Path::new("a/b").as_os_str()
+    );
+    assert_eq!(
+        base.join("a/").relative(base.join("a/b/")).as_os_str(),
+        ".."
+    );
+    assert_eq!(base.join("a/").relative(base.join("a")).as_os_str(), "");
+}
+
+#[test]
+fn relative_into() {
+    let base = Path::new("base").resolve();
+    let mut buf = std::path::PathBuf::from("stale/content");
+    for (path, to) in [("a/b", "a"), ("a", "a/b/c"), ("a", "a"), ("x/y", "a/b")] {
+        let (path, to) = (base.join(path), base.join(to));
+        path.relative_into(&to, &mut buf);
+        assert_eq!(buf.as_os_str(), path.relative(&to).as_os_str());
+    }
+}
@@ -0,0 +1,62 @@
+use std::path::Path;
+use sugar_path::{RelativeCache, SugarPath};
+
+#[test]
+fn matches_relative() {
+    let root = Path::new("root").resolve();
+    let mut cache = RelativeCache::new(2);
+    let cases = [
+        ("src/lib/a.js", "src/pages"),
+        ("src/lib/b.js", "src/pages"),
+        ("src/pages/c.js", "src/pages"),
+        ("src/lib/../d.js", "src/pages"),
+        ("e.js", "src/pages/deep"),
+        ("src/lib/f.js", "src/pages"),
+        ("src/lib/..", "src"),
+        ("src/lib/g.js", "src/lib/g.js"),
+        ("src/lib/g.js", "src/lib/g.js/deep"),
+        ("src/lib/h.js", "src/lib/g.js/deep"),
+        ("src/lib/k.js", "src/lib/g.js"),
+    ];
+    for _ in 0..2 {
+        for (path, to) in cases {
+            let (path, to) = (root.join(path), root.join(to));
+            assert_eq!(
+                cache.relative(&path, &to),
+                path.relative(&to),
+                "{:?} {:?}",
+                path,
+                to
+            );
+        }
+    }
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.relative("a.js", "."), Path::new("a.js"));
+}
+
+#[test]
+fn evicts_least_recently_used() {
+    let mut cache = RelativeCache::new(2);
+    let base = Path::new("base").resolve();
+    cache.relative(base.join("a/x"), &base);
+    cache.relative(base.join("b/x"), &base);
+    // Touching `a` makes `b` the least recently used pair.
+    cache.relative(base.join("a/y"), &base);
+    cache.relative(base.join("c/x"), &base);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.relative(base.join("b/z"), &base), Path::new("b/z"));
+    assert_eq!(cache.relative(base.join("c/z"), &base), Path::new("c/z"));
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.relative(base.join("a/x"), &base), Path::new("a/x"));
+}
+
+#[test]
+fn zero_capacity() {
+    let mut cache = RelativeCache::new(0);
+    let base = Path::new("base").resolve();
+    assert_eq!(cache.relative(base.join("a/x"), &base), Path::new("a/x"));
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "relative-path")]
+
+use relative_path::{FromPathErrorKind, RelativePath};
+use std::path::Path;
+use sugar_path::{NormalizedPathBuf, RelativePathBuf, SugarPath, SugarRelativePath};
+
+#[test]
+fn resolve_on() {
+    let path = RelativePath::new("a/./b/../c/");
+    assert_eq!(path.resolve_on("base"), Path::new("base/a/c/"));
+    assert_eq!(path.resolve_on(""), Path::new("a/c/"));
+    assert_eq!(
+        RelativePath::new("../../x").resolve_on("a"),
+        Path::new("../x")
+    );
+    assert_eq!(RelativePath::new("").resolve_on("a/.."), Path::new("."));
+}
+
+#[test]
+fn to_sugar_path() {
+    let path = RelativePathBuf::try_from(RelativePath::new("a//./b/../c")).unwrap();
+    assert_eq!(path.as_path(), Path::new("a/c"));
+    let path = RelativePathBuf::try_from(relative_path::RelativePathBuf::from("../a")).unwrap();
+    assert_eq!(path.as_path(), Path::new("../a"));
+    let path = NormalizedPathBuf::from(RelativePath::new("a/../.."));
+    assert_eq!(path.as_path(), Path::new(".."));
+}
+
+#[test]
+fn from_sugar_path() {
+    let path = RelativePathBuf::new("a/./b/../c").unwrap();
+    assert_eq!(
+        relative_path::RelativePathBuf::try_from(&path).unwrap(),
+        RelativePath::new("a/c")
+    );
+    let path = NormalizedPathBuf::new("../a");
+    assert_eq!(
+        relative_path::RelativePathBuf::try_from(&path).unwrap(),
+        RelativePath::new("../a")
+    );
+    let path = NormalizedPathBuf::new("x").as_path().resolve();
+    assert_eq!(
+        relative_path::RelativePathBuf::try_from(&NormalizedPathBuf::new(path))
+            .unwrap_err()
+            .kind(),
+        FromPathErrorKind::NonRelative
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    assert_eq!(
+        RelativePath::new("/a/../..").resolve_on("/b"),
+        Path::new("/")
+    );
+    assert_eq!(
+        RelativePath::new("a\\b").resolve_on("c"),
+        Path::new("c/a\\b")
+    );
+
+    let path = RelativePathBuf::new(OsStr::from_bytes(b"a/\xff")).unwrap();
+    assert_eq!(
+        relative_path::RelativePathBuf::try_from(&path)
+            .unwrap_err()
+            .kind(),
+        FromPathErrorKind::NonUtf8
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert!(RelativePathBuf::try_from(RelativePath::new("C:/a")).is_err());
+    assert_eq!(
+        RelativePath::new("C:/a").resolve_on("D:\\b"),
+        Path::new("D:\\b\\a")
+    );
+    let path = RelativePathBuf::new("a\\b").unwrap();
+    assert_eq!(
+        relative_path::RelativePathBuf::try_from(&path).unwrap(),
+        RelativePath::new("a/b")
+    );
+}
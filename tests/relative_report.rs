@@ -0,0 +1,71 @@
+use std::path::Path;
+use sugar_path::{CaseSensitivity, SugarPath};
+
+#[test]
+fn relative_with_report() {
+    let (path, report) = Path::new("a/b/c").relative_with_report("a/x");
+    assert_eq!(path, Path::new("../b/c"));
+    assert_eq!(report.common_ancestor.unwrap(), Path::new("a").resolve());
+    assert_eq!((report.ascended, report.descended), (1, 2));
+    assert_eq!(report.case, CaseSensitivity::platform());
+
+    let (path, report) = Path::new("a/b").relative_with_report("a/b/.");
+    assert_eq!(path, Path::new(""));
+    assert_eq!(report.common_ancestor.unwrap(), Path::new("a/b").resolve());
+    assert_eq!((report.ascended, report.descended), (0, 0));
+    assert!(!report.case_folded);
+
+    let (path, report) = Path::new("a").relative_with_report("a/b/c");
+    assert_eq!(path, Path::new("../.."));
+    assert_eq!((report.ascended, report.descended), (2, 0));
+
+    let (path, report) = Path::new("a/b/c").relative_with_report("a");
+    assert_eq!(path, Path::new("b/c"));
+    assert_eq!((report.ascended, report.descended), (0, 2));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    let (path, report) = Path::new("/a/B/c").relative_with_report("/a/b");
+    assert_eq!(path, Path::new("../B/c"));
+    assert_eq!(report.common_ancestor.unwrap(), Path::new("/a"));
+    assert_eq!((report.ascended, report.descended), (1, 2));
+    assert_eq!(report.case, CaseSensitivity::Sensitive);
+    assert!(!report.case_folded);
+
+    let (path, report) = Path::new("/x").relative_with_report("/");
+    assert_eq!(path, Path::new("x"));
+    assert_eq!(report.common_ancestor.unwrap(), Path::new("/"));
+
+    with_default_platform(Platform::Windows, || {
+        let (path, report) = Path::new("C:\\a\\B\\c").relative_with_report("c:\\A\\b\\d");
+        assert_eq!(path.as_os_str(), "..\\c");
+        assert_eq!(report.common_ancestor.unwrap().as_os_str(), "C:\\a\\B");
+        assert_eq!((report.ascended, report.descended), (1, 1));
+        assert_eq!(report.case, CaseSensitivity::AsciiInsensitive);
+        assert!(report.case_folded);
+
+        let (path, report) = Path::new("C:\\a").relative_with_report("D:\\a");
+        assert_eq!(path.as_os_str(), "C:\\a");
+        assert_eq!(report.common_ancestor, None);
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let (path, report) = Path::new("C:\\a\\B\\c").relative_with_report("c:\\A\\b");
+    assert_eq!(path, Path::new("c"));
+    assert_eq!(report.common_ancestor.unwrap(), Path::new("C:\\a\\B"));
+    assert_eq!((report.ascended, report.descended), (0, 1));
+    assert_eq!(report.case, CaseSensitivity::AsciiInsensitive);
+    assert!(report.case_folded);
+
+    let (path, report) = Path::new("C:\\a").relative_with_report("D:\\a");
+    assert_eq!(path, Path::new("C:\\a"));
+    assert_eq!(report.common_ancestor, None);
+    assert_eq!((report.ascended, report.descended), (0, 0));
+}
@@ -0,0 +1,53 @@
+use std::path::{Path, PathBuf};
+use sugar_path::{relativize_set, SugarPath};
+
+#[test]
+fn empty() {
+    let files: [&str; 0] = [];
+    assert_eq!(relativize_set(files), (PathBuf::new(), vec![]));
+}
+
+#[test]
+fn relative_files_are_resolved() {
+    let (base, files) = relativize_set(["src/a.rs", "src/b/c.rs"]);
+    assert_eq!(base, Path::new("src").resolve());
+    assert_eq!(files, [Path::new("a.rs"), Path::new("b/c.rs")]);
+    for file in ["src/a.rs", "src/b/c.rs"] {
+        let relative = Path::new(file).resolve().relative(&base);
+        assert!(files.contains(&relative));
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let (base, files) = relativize_set(["/a/b/c.js"]);
+    assert_eq!(base, Path::new("/a/b"));
+    assert_eq!(files, [Path::new("c.js")]);
+
+    let (base, files) = relativize_set(["/a/b/../x.js", "/a/y.js", "/a/c/d/z.js"]);
+    assert_eq!(base, Path::new("/a"));
+    assert_eq!(
+        files,
+        [Path::new("x.js"), Path::new("y.js"), Path::new("c/d/z.js")]
+    );
+
+    let (base, files) = relativize_set(["/a/x.js", "/b/y.js"]);
+    assert_eq!(base, Path::new("/"));
+    assert_eq!(files, [Path::new("a/x.js"), Path::new("b/y.js")]);
+
+    // Directories are compared component-wise, not as strings.
+    let (base, _) = relativize_set(["/app/foo/a.js", "/app/foobar/b.js"]);
+    assert_eq!(base, Path::new("/app"));
+
+    let (base, _) = relativize_set(["/App/a.js", "/app/b.js"]);
+    assert_eq!(base, Path::new("/"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let (base, files) = relativize_set(["C:\\App\\a.js", "c:\\app\\lib\\b.js"]);
+    assert_eq!(base, Path::new("C:\\App"));
+    assert_eq!(files, [Path::new("a.js"), Path::new("lib\\b.js")]);
+}
@@ -0,0 +1,39 @@
+use std::{ffi::OsStr, path::Path};
+use sugar_path::{windows::is_reserved_name, SugarPath};
+
+#[test]
+fn reserved_name() {
+    for name in [
+        "CON",
+        "con",
+        "Prn",
+        "aux",
+        "NUL",
+        "nul.txt",
+        "nul.tar.gz",
+        "COM1",
+        "com9.log",
+        "LPT3",
+        "lpt¹",
+        "CON ",
+        "con .txt",
+        "CONIN$",
+        "conout$.x",
+    ] {
+        assert!(is_reserved_name(OsStr::new(name)), "{}", name);
+    }
+    for name in [
+        "", "CONSOLE", "COM", "COM0", "COM10", "LPT", "nul_", "xnul", ".nul", " CON", "file.con",
+    ] {
+        assert!(!is_reserved_name(OsStr::new(name)), "{}", name);
+    }
+}
+
+#[test]
+fn reserved_component() {
+    assert!(Path::new("nul").has_reserved_component());
+    assert!(Path::new("/a/b/Con.js/c").has_reserved_component());
+    assert!(Path::new("a/lpt1").has_reserved_component());
+    assert!(!Path::new("/a/b/c").has_reserved_component());
+    assert!(!Path::new("").has_reserved_component());
+}
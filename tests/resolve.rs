@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use sugar_path::SugarPath;
 
@@ -19,6 +19,17 @@ macro_rules! path_buf {
       }
     };
 }
+#[test]
+fn empty() {
+    // The current directory as it is, without a trailing separator.
+    assert_eq!(Path::new("").resolve().as_os_str(), get_cwd().as_os_str());
+    assert_eq!(Path::new(".").resolve().as_os_str(), get_cwd().as_os_str());
+    assert_eq!(
+        Path::new("").resolve_with(get_cwd()).as_os_str(),
+        get_cwd().as_os_str()
+    );
+}
+
 #[cfg(target_family = "unix")]
 #[test]
 fn unix() {
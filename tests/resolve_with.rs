@@ -0,0 +1,101 @@
+use std::path::Path;
+
+use sugar_path::{with_default_platform, Platform, SugarPath};
+
+#[test]
+fn relative_base() {
+    let cwd = std::env::current_dir().unwrap();
+    assert_eq!(
+        Path::new("a/../b").resolve_with("src/./x"),
+        cwd.join("src/x/b")
+    );
+    assert_eq!(
+        Path::new("../..").resolve_with("src"),
+        cwd.join("..").normalize()
+    );
+    assert_eq!(Path::new("").resolve_with(".").as_os_str(), cwd.as_os_str());
+    assert_eq!(
+        Path::new("").resolve_with("src").as_os_str(),
+        cwd.join("src").as_os_str()
+    );
+    assert_eq!(
+        Path::new("file").resolve_with(&cwd),
+        Path::new("file").resolve()
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(
+        Path::new("./dep.js").resolve_with("/app/src/"),
+        Path::new("/app/src/dep.js")
+    );
+    assert_eq!(
+        Path::new("../../../../x").resolve_with("/app/src"),
+        Path::new("/x")
+    );
+    assert_eq!(
+        Path::new("/etc/./hosts").resolve_with("/app"),
+        Path::new("/etc/hosts")
+    );
+    assert_eq!(Path::new("").resolve_with("/app").as_os_str(), "/app");
+    assert_eq!(Path::new("").resolve_with("/app/").as_os_str(), "/app");
+    assert_eq!(Path::new("").resolve_with("/").as_os_str(), "/");
+    assert_eq!(
+        Path::new("dir/").resolve_with("/app").as_os_str(),
+        "/app/dir/"
+    );
+
+    let resolved = with_default_platform(Platform::Windows, || {
+        Path::new("..\\lib").resolve_with("C:\\app\\src")
+    });
+    assert_eq!(resolved.as_os_str(), "C:\\app\\lib");
+    let resolved =
+        with_default_platform(Platform::Windows, || Path::new("").resolve_with("C:\\app"));
+    assert_eq!(resolved.as_os_str(), "C:\\app");
+    with_default_platform(Platform::Windows, || {
+        // A rooted path stays on the drive or share of the base.
+        assert_eq!(
+            Path::new("\\foo").resolve_with("C:\\x").as_os_str(),
+            "C:\\foo"
+        );
+        assert_eq!(
+            Path::new("/foo/../bar")
+                .resolve_with("\\\\server\\share\\dir")
+                .as_os_str(),
+            "\\\\server\\share\\bar"
+        );
+    });
+    with_default_platform(Platform::Windows, || {
+        assert_eq!(
+            Path::new("x").resolve_with("src"),
+            Path::new("src\\x").resolve()
+        );
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("..\\utils/fmt.ts").resolve_with("C:\\app\\src"),
+        Path::new("C:\\app\\utils\\fmt.ts")
+    );
+    assert_eq!(
+        Path::new("D:\\x\\..\\y").resolve_with("C:\\app"),
+        Path::new("D:\\y")
+    );
+    assert_eq!(
+        Path::new("a").resolve_with("\\\\server\\share\\dir"),
+        Path::new("\\\\server\\share\\dir\\a")
+    );
+
+    assert_eq!(Path::new("").resolve_with("C:\\app").as_os_str(), "C:\\app");
+    assert_eq!(Path::new("").resolve_with("C:\\").as_os_str(), "C:\\");
+
+    let resolved = with_default_platform(Platform::Posix, || {
+        Path::new("../lib").resolve_with("/app/src")
+    });
+    assert_eq!(resolved.as_os_str(), "/app/lib");
+}
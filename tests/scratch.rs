@@ -0,0 +1,107 @@
+use std::path::Path;
+use sugar_path::{with_scratch, Scratch, SugarPath};
+
+const PATHS: [&str; 12] = [
+    "",
+    ".",
+    "./",
+    "..",
+    "a",
+    "a/",
+    "a//b/../c",
+    "../a/..",
+    "a/../..",
+    "/",
+    "/a/../..",
+    "//a/./b/",
+];
+
+#[test]
+fn normalize() {
+    let mut scratch = Scratch::new();
+    for path in PATHS {
+        assert_eq!(
+            scratch.normalize(path).as_os_str(),
+            Path::new(path).normalize().as_os_str(),
+            "{:?}",
+            path
+        );
+    }
+}
+
+#[test]
+fn resolve() {
+    let mut scratch = Scratch::new();
+    for path in PATHS {
+        assert_eq!(
+            scratch.resolve(path).as_os_str(),
+            Path::new(path).resolve().as_os_str(),
+            "{:?}",
+            path
+        );
+    }
+    let cwd = std::env::current_dir().unwrap();
+    assert_eq!(scratch.resolve("").as_os_str(), cwd.as_os_str());
+}
+
+#[test]
+fn reentrant() {
+    let (outer, inner) = with_scratch(|outer| {
+        let inner = with_scratch(|inner| inner.normalize("b/./c").to_path_buf());
+        (outer.normalize("a/../d").to_path_buf(), inner)
+    });
+    assert_eq!(outer, Path::new("d"));
+    assert_eq!(inner, Path::new("b/c"));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    let mut scratch = Scratch::new();
+    assert_eq!(scratch.resolve("/x/../y"), Path::new("/y"));
+
+    with_default_platform(Platform::Windows, || {
+        for path in ["//server/share/../x", "C:\\a\\..\\b", "a/b"] {
+            assert_eq!(
+                scratch.normalize(path).as_os_str(),
+                Path::new(path).normalize().as_os_str(),
+                "{:?}",
+                path
+            );
+            assert_eq!(
+                scratch.resolve(path).as_os_str(),
+                Path::new(path).resolve().as_os_str(),
+                "{:?}",
+                path
+            );
+        }
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let mut scratch = Scratch::new();
+    for path in [
+        "C:\\a\\..\\b",
+        "C:a",
+        "\\a",
+        "C:",
+        "\\\\server\\share\\a\\..",
+    ] {
+        assert_eq!(
+            scratch.resolve(path),
+            Path::new(path).resolve(),
+            "{:?}",
+            path
+        );
+        assert_eq!(
+            scratch.normalize(path),
+            Path::new(path).normalize(),
+            "{:?}",
+            path
+        );
+    }
+}
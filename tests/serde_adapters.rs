@@ -0,0 +1,96 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use std::path::{Path, PathBuf};
+use sugar_path::{
+    BaseDir, CurrentDir, NormalizeOnDeserialize, RequireRelative, ResolveAgainst, SugarPath,
+};
+
+struct Base;
+
+impl BaseDir for Base {
+    fn base_dir() -> PathBuf {
+        Path::new("base").resolve()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+struct Config {
+    #[serde_as(as = "NormalizeOnDeserialize")]
+    normalized: PathBuf,
+    #[serde_as(as = "Option<NormalizeOnDeserialize<RequireRelative>>")]
+    relative: Option<PathBuf>,
+    #[serde_as(as = "ResolveAgainst<Base>")]
+    resolved: PathBuf,
+    #[serde_as(as = "Vec<ResolveAgainst<CurrentDir>>")]
+    cwd: Vec<PathBuf>,
+}
+
+fn parse(json: &str) -> Result<Config, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+#[test]
+fn deserialize() {
+    let config = parse(
+        r#"{ "normalized": "a//./b/..", "relative": "./c/", "resolved": "../d", "cwd": ["e", ""] }"#,
+    )
+    .unwrap();
+    assert_eq!(config.normalized, Path::new("a"));
+    assert_eq!(config.relative.as_deref(), Some(Path::new("c/")));
+    assert_eq!(config.resolved, Path::new("d").resolve());
+    assert_eq!(
+        config.cwd,
+        [Path::new("e").resolve(), Path::new(".").resolve()]
+    );
+
+    let config =
+        parse(r#"{ "normalized": "", "relative": null, "resolved": "", "cwd": [] }"#).unwrap();
+    assert_eq!(config.normalized, Path::new("."));
+    assert_eq!(config.relative, None);
+    assert_eq!(config.resolved, Path::new("base").resolve());
+}
+
+#[test]
+fn serialize_unchanged() {
+    let config = Config {
+        normalized: "a/../b".into(),
+        relative: Some("c".into()),
+        resolved: "d".into(),
+        cwd: vec![],
+    };
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"normalized":"a/../b","relative":"c","resolved":"d","cwd":[]}"#
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let err =
+        parse(r#"{ "normalized": "", "relative": "/a", "resolved": "", "cwd": [] }"#).unwrap_err();
+    assert!(
+        err.to_string().contains("\"/a\" is not a relative path"),
+        "{}",
+        err
+    );
+    let config = parse(r#"{ "normalized": "", "resolved": "/x/../y", "cwd": [] }"#).unwrap();
+    assert_eq!(config.resolved, Path::new("/y"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    for path in [r#""C:\\a""#, r#""C:a""#, r#""\\a""#] {
+        let json = format!(
+            r#"{{ "normalized": "", "relative": {}, "resolved": "", "cwd": [] }}"#,
+            path
+        );
+        assert!(parse(&json).is_err(), "{}", path);
+    }
+    let config = parse(r#"{ "normalized": "", "resolved": "C:\\x\\..\\y", "cwd": [] }"#).unwrap();
+    assert_eq!(config.resolved, Path::new("C:\\y"));
+}
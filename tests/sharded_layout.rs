@@ -0,0 +1,84 @@
+use std::path::Path;
+use sugar_path::{DigestError, ShardedLayout};
+
+#[test]
+fn path_for() {
+    let layout = ShardedLayout::new("objects");
+    assert_eq!(
+        layout.path_for("abcdef").unwrap(),
+        Path::new("objects/ab/abcdef")
+    );
+    let layout = ShardedLayout::new("./cache/../objects/").width(3).depth(2);
+    assert_eq!(layout.root(), Path::new("objects"));
+    assert_eq!(
+        layout.path_for("abcdefg").unwrap(),
+        Path::new("objects/abc/def/abcdefg")
+    );
+    assert_eq!(layout.path_for("abcdef"), Err(DigestError::TooShort));
+    assert_eq!(layout.path_for(""), Err(DigestError::TooShort));
+    assert_eq!(
+        layout.path_for("ab/../../x"),
+        Err(DigestError::InvalidChar('/'))
+    );
+    assert_eq!(
+        layout.path_for("abc.defgh"),
+        Err(DigestError::InvalidChar('.'))
+    );
+
+    let flat = ShardedLayout::new("").depth(0).extension("tar.gz");
+    assert_eq!(flat.path_for("x").unwrap().as_os_str(), "x.tar.gz");
+    assert_eq!(flat.digest_of("./x.tar.gz").as_deref(), Some("x"));
+}
+
+#[test]
+fn digest_of() {
+    let layout = ShardedLayout::new("cache").depth(2).extension("o");
+    let digest = "0123456789abcdef";
+    let path = layout.path_for(digest).unwrap();
+    assert_eq!(layout.digest_of(&path).as_deref(), Some(digest));
+    assert_eq!(
+        layout
+            .digest_of("cache/x/../01/23/0123456789abcdef.o")
+            .as_deref(),
+        Some(digest)
+    );
+    for path in [
+        "cache/01/23/0123456789abcdef",
+        "cache/01/23/0123456789abcdef.obj",
+        "cache/01/0123456789abcdef.o",
+        "cache/01/24/0123456789abcdef.o",
+        "cache/01/23/45/0123456789abcdef.o",
+        "other/01/23/0123456789abcdef.o",
+        "cache/01/23/.o",
+        "cache/01/23/0123.o",
+        "cache",
+    ] {
+        assert_eq!(layout.digest_of(path), None, "{}", path);
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let layout = ShardedLayout::new("/var/cache/");
+    assert_eq!(
+        layout.path_for("abc").unwrap().as_os_str(),
+        "/var/cache/ab/abc"
+    );
+    assert_eq!(
+        layout.digest_of("/var/cache/ab/abc").as_deref(),
+        Some("abc")
+    );
+    assert_eq!(layout.digest_of("var/cache/ab/abc"), None);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let layout = ShardedLayout::new("C:/cache");
+    assert_eq!(
+        layout.path_for("abc").unwrap().as_os_str(),
+        "C:\\cache\\ab\\abc"
+    );
+    assert_eq!(layout.digest_of("C:/cache/ab/abc").as_deref(), Some("abc"));
+}
@@ -0,0 +1,51 @@
+use std::{borrow::Cow, ffi::OsStr};
+use sugar_path::windows::simplify_verbatim;
+
+fn simplify(path: &str) -> String {
+    simplify_verbatim(OsStr::new(path))
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn simplifies() {
+    assert_eq!(simplify(r"\\?\C:\"), r"C:\");
+    assert_eq!(simplify(r"\\?\c:\a\b.txt"), r"C:\a\b.txt");
+    assert_eq!(simplify(r"\\?\C:\a\b\"), r"C:\a\b\");
+    assert_eq!(simplify(r"\\?\UNC\server\share"), r"\\server\share");
+    assert_eq!(simplify(r"\\?\UNC\server\share\a"), r"\\server\share\a");
+    assert_eq!(simplify(r"\\?\C:\a.b\.c\c d"), r"C:\a.b\.c\c d");
+}
+
+#[test]
+fn keeps_unsafe_paths() {
+    for path in [
+        r"\\?\C:",
+        r"\\?\C:foo",
+        r"\\?\C:\a\..\b",
+        r"\\?\C:\a\.\b",
+        r"\\?\C:\a\\b",
+        r"\\?\C:\a/b",
+        r"\\?\C:\trailing.",
+        r"\\?\C:\trailing ",
+        r"\\?\C:\dir\CON",
+        r"\\?\C:\dir\com1.txt",
+        r"\\?\UNC\server",
+        r"\\?\UNC\server\aux",
+        r"\\?\Volume{b75e2c83-0000-0000-0000-602f00000000}\x",
+        r"\\.\COM1",
+        r"C:\already\plain",
+        r"relative",
+    ] {
+        assert!(matches!(
+            simplify_verbatim(OsStr::new(path)),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    let long = format!(r"\\?\C:\{}", "a".repeat(256));
+    assert_eq!(simplify(&long), r"C:\".to_string() + &"a".repeat(256));
+    let too_long = format!(r"\\?\C:\{}", "a".repeat(257));
+    assert_eq!(simplify(&too_long), too_long);
+}
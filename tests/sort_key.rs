@@ -0,0 +1,65 @@
+use std::path::Path;
+use sugar_path::{CaseSensitivity, SugarPath};
+
+fn key(path: &str, case: CaseSensitivity) -> Vec<u8> {
+    Path::new(path).sort_key(case)
+}
+
+#[test]
+fn sort_key() {
+    use CaseSensitivity::*;
+    assert_eq!(key("a/b/../C/", Sensitive), b"a/C");
+    assert_eq!(key("a/b/../C/", AsciiInsensitive), b"a/c");
+    assert_eq!(key("", Sensitive), b".");
+    assert_eq!(key("a/..", Sensitive), b".");
+    assert_eq!(key("../../a", Sensitive), b"../../a");
+    assert_eq!(key("Ä/ß", AsciiInsensitive), "Ä/ß".as_bytes());
+    assert_eq!(
+        key("ä/x", UnicodeInsensitive),
+        key("Ä/X", UnicodeInsensitive)
+    );
+}
+
+#[test]
+fn order() {
+    let mut paths = ["b", "a/b", "a-b", "A/c", "./a/x/../a", "a b", "..", "a"];
+    paths.sort_by_key(|path| key(path, CaseSensitivity::AsciiInsensitive));
+    assert_eq!(
+        paths,
+        ["..", "a", "a b", "a-b", "./a/x/../a", "a/b", "A/c", "b"]
+    );
+    // Sorting is stable across platforms, so every separator is written as `/`.
+    #[cfg(target_family = "windows")]
+    assert_eq!(key("a\\b", CaseSensitivity::Sensitive), b"a/b");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    assert_eq!(key("/usr//lib/", CaseSensitivity::Sensitive), b"/usr/lib");
+    assert_eq!(key("/..", CaseSensitivity::Sensitive), b"/");
+    assert_eq!(key("a\\b", CaseSensitivity::Sensitive), b"a\\b");
+    // Names that aren't UTF-8 are kept byte for byte.
+    let path = Path::new(OsStr::from_bytes(b"x/\xff\xfeA"));
+    assert_eq!(path.sort_key(CaseSensitivity::Sensitive), b"x/\xff\xfeA");
+    assert_eq!(
+        path.sort_key(CaseSensitivity::UnicodeInsensitive),
+        b"X/\xff\xfeA"
+    );
+    assert_ne!(
+        Path::new(OsStr::from_bytes(b"\xff")).sort_key(CaseSensitivity::Sensitive),
+        Path::new(OsStr::from_bytes(b"\xfe")).sort_key(CaseSensitivity::Sensitive)
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    use CaseSensitivity::*;
+    assert_eq!(key("C:\\Foo\\..\\Bar", Sensitive), b"C:/Bar");
+    assert_eq!(key("C:\\Foo\\..\\Bar", AsciiInsensitive), b"c:/bar");
+    assert_eq!(key("\\\\Server\\Share\\x", Sensitive), b"//Server/Share/x");
+    assert_eq!(key("C:", Sensitive), b"C:.");
+}
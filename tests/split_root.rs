@@ -0,0 +1,50 @@
+use std::path::{Path, PathBuf};
+use sugar_path::SugarPath;
+
+fn split(path: &str) -> (PathBuf, PathBuf) {
+    Path::new(path).split_root()
+}
+
+fn pair(root: &str, rest: &str) -> (PathBuf, PathBuf) {
+    (PathBuf::from(root), PathBuf::from(rest))
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    assert_eq!(split("/foo/../bar/baz"), pair("/", "bar/baz"));
+    assert_eq!(split("///..//./foo/.//bar"), pair("/", "foo/bar"));
+    assert_eq!(split("/"), pair("/", ""));
+    assert_eq!(split("foo/./bar"), pair("", "foo/bar"));
+    assert_eq!(split("../foo"), pair("", "../foo"));
+    assert_eq!(split(""), pair("", ""));
+    assert_eq!(split("a/.."), pair("", ""));
+
+    with_default_platform(Platform::Windows, || {
+        for (path, root, rest) in [
+            (r"C:\foo\..\bar\baz", r"C:\", r"bar\baz"),
+            ("//server/share/x", r"\\server\share\", "x"),
+            ("a/./b", "", r"a\b"),
+        ] {
+            let (actual_root, actual_rest) = split(path);
+            assert_eq!(actual_root.as_os_str(), root, "{:?}", path);
+            assert_eq!(actual_rest.as_os_str(), rest, "{:?}", path);
+        }
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(split("C:\\foo\\..\\bar"), pair("C:\\", "bar"));
+    assert_eq!(split("C:foo"), pair("C:", "foo"));
+    assert_eq!(split("\\foo"), pair("\\", "foo"));
+    assert_eq!(
+        split("\\\\server\\share\\foo"),
+        pair("\\\\server\\share\\", "foo")
+    );
+    assert_eq!(split("C:"), pair("C:", ""));
+    assert_eq!(split("foo\\bar"), pair("", "foo\\bar"));
+}
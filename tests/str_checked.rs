@@ -0,0 +1,67 @@
+use std::path::Path;
+use sugar_path::{NormalizedPathBuf, SugarPath};
+
+#[test]
+fn valid() {
+    let path = Path::new("src/app.test.ts");
+    assert_eq!(path.to_str_checked().unwrap(), "src/app.test.ts");
+    assert_eq!(path.file_name_str().unwrap(), Some("app.test.ts"));
+    assert_eq!(path.extension_str().unwrap(), Some("ts"));
+
+    assert_eq!(Path::new("").to_str_checked().unwrap(), "");
+    assert_eq!(Path::new("..").file_name_str().unwrap(), None);
+    assert_eq!(Path::new(".bashrc").extension_str().unwrap(), None);
+    assert_eq!(
+        Path::new("héllo/wörld.txt").file_name_str().unwrap(),
+        Some("wörld.txt")
+    );
+}
+
+#[test]
+fn typed() {
+    let path = NormalizedPathBuf::new("docs/./guide.md");
+    assert_eq!(path.to_str_checked().unwrap(), "docs/guide.md");
+    assert_eq!(path.extension_str().unwrap(), Some("md"));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let path = Path::new(OsStr::from_bytes(b"d\xe9j\xe0/vu.t\xfft"));
+    let err = path.to_str_checked().unwrap_err();
+    assert_eq!(err.path, path);
+    assert_eq!(err.valid_up_to, 1);
+    assert_eq!(
+        err.to_string(),
+        r#""d\xE9j\xE0/vu.t\xFFt" is not valid UTF-8 from byte 1"#
+    );
+
+    // Offsets are within the whole path.
+    assert_eq!(path.file_name_str().unwrap_err().valid_up_to, 9);
+    assert_eq!(path.extension_str().unwrap_err().valid_up_to, 9);
+
+    // Only the part asked for has to be valid.
+    let path = Path::new(OsStr::from_bytes(b"\xff/name.rs"));
+    assert!(path.to_str_checked().is_err());
+    assert_eq!(path.file_name_str().unwrap(), Some("name.rs"));
+    assert_eq!(path.extension_str().unwrap(), Some("rs"));
+    let path = Path::new(OsStr::from_bytes(b"dir/\xffname.rs"));
+    assert_eq!(path.file_name_str().unwrap_err().valid_up_to, 4);
+    assert_eq!(path.extension_str().unwrap(), Some("rs"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+
+    // `a\b` followed by a lone surrogate.
+    let path = OsString::from_wide(&[0x61, 0x5c, 0x62, 0xd800]);
+    let path = Path::new(&path);
+    let err = path.to_str_checked().unwrap_err();
+    assert_eq!(err.valid_up_to, 3);
+    assert_eq!(path.file_name_str().unwrap_err().valid_up_to, 3);
+    assert_eq!(path.extension_str().unwrap(), None);
+}
@@ -0,0 +1,51 @@
+use std::path::{Path, PathBuf};
+use sugar_path::{StripComponentsError, SugarPath};
+
+#[test]
+fn strip_components() {
+    assert_eq!(
+        Path::new("a/b/c").strip_components(0).unwrap(),
+        Path::new("a/b/c")
+    );
+    assert_eq!(
+        Path::new("./a/./b/../c/d").strip_components(1).unwrap(),
+        Path::new("c/d")
+    );
+    assert_eq!(
+        Path::new("a/b").strip_components(2).unwrap(),
+        PathBuf::new()
+    );
+    assert_eq!(
+        Path::new("a/b").strip_components(3),
+        Err(StripComponentsError {
+            depth: 2,
+            requested: 3
+        })
+    );
+    assert_eq!(
+        Path::new("a/..").strip_components(1),
+        Err(StripComponentsError {
+            depth: 0,
+            requested: 1
+        })
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(
+        Path::new("/pkg/lib/a.js").strip_components(1).unwrap(),
+        Path::new("lib/a.js")
+    );
+    assert_eq!(Path::new("/").strip_components(0).unwrap(), PathBuf::new());
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("C:\\pkg\\lib\\a.js").strip_components(1).unwrap(),
+        Path::new("lib\\a.js")
+    );
+}
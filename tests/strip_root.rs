@@ -0,0 +1,53 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+fn strip(path: &str) -> String {
+    Path::new(path).strip_root_with_separator('/')
+}
+
+#[test]
+fn strip_root() {
+    assert_eq!(Path::new("a/./b/").strip_root(), Path::new("a/b"));
+    assert_eq!(Path::new("../a").strip_root(), Path::new("../a"));
+    assert_eq!(Path::new("a/..").strip_root(), Path::new(""));
+    assert_eq!(Path::new("").strip_root(), Path::new(""));
+
+    assert_eq!(strip("a/./b"), "a/b");
+    assert_eq!(strip("a/b/"), "a/b/");
+    assert_eq!(strip("a/../"), "");
+    assert_eq!(strip("./"), "");
+    assert_eq!(strip("../a"), "../a");
+    assert_eq!(Path::new("a/b/").strip_root_with_separator('\\'), "a\\b\\");
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    assert_eq!(Path::new("/").strip_root(), Path::new(""));
+    assert_eq!(Path::new("//a/../b").strip_root(), Path::new("b"));
+    assert_eq!(Path::new("/../a").strip_root(), Path::new("a"));
+    assert_eq!(strip("/"), "");
+    assert_eq!(strip("/usr/lib/"), "usr/lib/");
+    // A backslash is part of a name.
+    assert_eq!(strip("/a\\b/c"), "a\\b/c");
+    assert_eq!(strip(""), "");
+    assert_eq!(
+        Path::new(OsStr::from_bytes(b"/a/\xffb")).strip_root_with_separator('/'),
+        "a/\u{fffd}b"
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(Path::new("C:\\a\\b").strip_root(), Path::new("a\\b"));
+    assert_eq!(Path::new("C:a").strip_root(), Path::new("a"));
+    assert_eq!(Path::new("\\a").strip_root(), Path::new("a"));
+    assert_eq!(Path::new("\\\\?\\C:\\a").strip_root(), Path::new("a"));
+    assert_eq!(strip("\\\\server\\share\\a\\b\\"), "a/b/");
+    assert_eq!(strip("\\\\server\\share"), "");
+    assert_eq!(strip("C:\\"), "");
+    assert_eq!(strip("C:/x/../y"), "y");
+}
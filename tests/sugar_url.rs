@@ -0,0 +1,127 @@
+#![cfg(feature = "url")]
+
+use std::path::Path;
+use sugar_path::{url_from_path, FileUrlError, SugarPath, SugarUrl};
+use url::Url;
+
+fn url(s: &str) -> Url {
+    Url::parse(s).unwrap()
+}
+
+#[test]
+fn round_trip() {
+    for path in ["a/b", "a b/c%d.rs", "dir/", "../x/./y"] {
+        let url = url_from_path(path).unwrap();
+        assert_eq!(url.to_normalized_path().unwrap(), Path::new(path).resolve());
+        assert_eq!(url.path().ends_with('/'), path.ends_with('/'));
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(
+        url("file:///home/me/My%20Docs/a.txt")
+            .to_normalized_path()
+            .unwrap(),
+        Path::new("/home/me/My Docs/a.txt")
+    );
+    assert_eq!(
+        url("file://localhost/a/b").to_normalized_path().unwrap(),
+        Path::new("/a/b")
+    );
+    assert_eq!(
+        url("file://server/a").to_normalized_path(),
+        Err(FileUrlError::UnsupportedHost("server".to_string()))
+    );
+    assert_eq!(
+        url("https://example.com/a").to_normalized_path(),
+        Err(FileUrlError::NotFileUrl)
+    );
+
+    let document = url("file:///project/src/lib.rs");
+    let workspace = url("file:///project/");
+    assert_eq!(
+        document.resolve_path("./util/mod.rs").unwrap().as_str(),
+        "file:///project/src/util/mod.rs"
+    );
+    assert_eq!(
+        workspace.resolve_path("src/../tests/").unwrap().as_str(),
+        "file:///project/tests/"
+    );
+    assert_eq!(
+        document.resolve_path("/etc/hosts").unwrap().as_str(),
+        "file:///etc/hosts"
+    );
+    assert_eq!(
+        document.resolve_path("../../../..").unwrap().as_str(),
+        "file:///"
+    );
+    assert_eq!(
+        document.resolve_path("a b#c").unwrap().as_str(),
+        "file:///project/src/a%20b%23c"
+    );
+
+    assert_eq!(
+        document.relative(&workspace).unwrap(),
+        Path::new("src/lib.rs")
+    );
+    assert_eq!(workspace.relative(&document).unwrap(), Path::new(".."));
+    assert_eq!(
+        url("file:///project/tests/a.rs")
+            .relative(&document)
+            .unwrap(),
+        Path::new("../tests/a.rs")
+    );
+    assert_eq!(document.relative(&document).unwrap(), Path::new("lib.rs"));
+    assert!(document.relative(&url("file://server/x")).is_err());
+
+    // Resolving the relative path gets back to the URL.
+    let other = url("file:///project/tests/fixtures/a.rs");
+    assert_eq!(
+        document
+            .resolve_path(other.relative(&document).unwrap())
+            .unwrap(),
+        other
+    );
+
+    assert_eq!(url_from_path("/a/./b/..").unwrap().as_str(), "file:///a");
+    assert_eq!(url_from_path("/").unwrap().as_str(), "file:///");
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        url("file:///C:/Users/me/a.txt")
+            .to_normalized_path()
+            .unwrap(),
+        Path::new("C:\\Users\\me\\a.txt")
+    );
+    assert_eq!(
+        url("file://server/share/x").to_normalized_path().unwrap(),
+        Path::new("\\\\server\\share\\x")
+    );
+
+    let document = url("file:///C:/project/src/lib.rs");
+    assert_eq!(
+        document.resolve_path("..\\tests\\a.rs").unwrap().as_str(),
+        "file:///C:/project/tests/a.rs"
+    );
+    assert_eq!(
+        document.resolve_path("D:\\x").unwrap().as_str(),
+        "file:///D:/x"
+    );
+    assert_eq!(
+        document.relative(&url("file:///C:/project/")).unwrap(),
+        Path::new("src\\lib.rs")
+    );
+    assert_eq!(
+        document.relative(&url("file:///D:/x/")).unwrap(),
+        Path::new("C:\\project\\src\\lib.rs")
+    );
+    assert_eq!(
+        url_from_path("C:\\a\\.\\b\\..").unwrap().as_str(),
+        "file:///C:/a"
+    );
+}
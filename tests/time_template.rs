@@ -0,0 +1,74 @@
+#![cfg(feature = "chrono")]
+
+use std::path::Path;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sugar_path::{expand_time_template, parse_time_template, SugarPath, TimeTemplateError};
+
+fn at(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, s)
+        .unwrap()
+}
+
+#[test]
+fn expand() {
+    let time = at(2024, 3, 9, 17, 5, 42);
+    assert_eq!(
+        expand_time_template("logs/%Y/%m/%d/app.log", &time).unwrap(),
+        Path::new("logs/2024/03/09/app.log").normalize()
+    );
+    assert_eq!(
+        expand_time_template("lake/./year=%Y/../year=%Y/month=%m/%H%M%S.parquet", &time).unwrap(),
+        Path::new("lake/year=2024/month=03/170542.parquet").normalize()
+    );
+    assert_eq!(
+        expand_time_template("logs/%Q", &time),
+        Err(TimeTemplateError::InvalidTemplate)
+    );
+    assert_eq!(
+        expand_time_template("logs/%z.log", &time),
+        Err(TimeTemplateError::InvalidTemplate)
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!(
+        parse_time_template("logs/%Y/%m/%d/app.log", "logs/2024/03/09/app.log").unwrap(),
+        at(2024, 3, 9, 0, 0, 0)
+    );
+    assert_eq!(
+        parse_time_template("logs/%Y/%m/%d/%H.log", "logs//2024/03/./09/17.log").unwrap(),
+        at(2024, 3, 9, 17, 0, 0)
+    );
+    assert_eq!(
+        parse_time_template("year=%Y/month=%m", "year=2024/month=11").unwrap(),
+        at(2024, 11, 1, 0, 0, 0)
+    );
+    assert_eq!(
+        parse_time_template("%Y", "2024").unwrap(),
+        at(2024, 1, 1, 0, 0, 0)
+    );
+    assert_eq!(
+        parse_time_template("%Y-%j", "2024-060").unwrap(),
+        at(2024, 2, 29, 0, 0, 0)
+    );
+    assert!(matches!(
+        parse_time_template("logs/%Y/%m/%d/app.log", "logs/2024/13/09/app.log"),
+        Err(TimeTemplateError::Parse(_))
+    ));
+    assert!(matches!(
+        parse_time_template("logs/%Y/%m/%d/app.log", "other/2024/03/09/app.log"),
+        Err(TimeTemplateError::Parse(_))
+    ));
+}
+
+#[test]
+fn round_trip() {
+    let time = at(1999, 12, 31, 23, 59, 58);
+    let template = "archive/%Y/%m/%d/%H-%M-%S.tar";
+    let path = expand_time_template(template, &time).unwrap();
+    assert_eq!(parse_time_template(template, path).unwrap(), time);
+}
@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+use sugar_path::{Normalizer, SugarPath, SugarPathBuf};
+
+#[test]
+fn is_dir_like() {
+    for path in ["a/", "a/.", "a/..", ".", "..", "", "./"] {
+        assert!(Path::new(path).is_dir_like(), "{:?}", path);
+    }
+    for path in ["a", "a/b", "a.", "a/.b", "..."] {
+        assert!(!Path::new(path).is_dir_like(), "{:?}", path);
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let keeps = |path: &str, expected: &str| {
+        let normalized = Path::new(path).normalize();
+        assert_eq!(normalized.as_os_str(), expected, "{:?}", path);
+    };
+    keeps("a/b/", "a/b/");
+    keeps("a//b//", "a/b/");
+    keeps("a/b/../", "a/");
+    keeps("a/../", "./");
+    keeps("a/b/..", "a");
+    keeps("/a/", "/a/");
+    keeps("/", "/");
+    keeps("//", "/");
+    keeps("", ".");
+
+    assert!(Path::new("/a/b/").resolve().is_dir_like());
+    assert!(!Path::new("/a/b").resolve().is_dir_like());
+    assert!(Path::new("/").is_dir_like());
+
+    let mut normalizer = Normalizer::new();
+    normalizer.feed("a");
+    normalizer.feed("b/");
+    assert_eq!(normalizer.finish().as_os_str(), "a/b/");
+
+    let mut path = PathBuf::from("a/");
+    path.push_normalized("b");
+    assert_eq!(path.as_os_str(), "a/b");
+    path.push_normalized("c/");
+    assert_eq!(path.as_os_str(), "a/b/c/");
+    path.push_normalized(".");
+    assert_eq!(path.as_os_str(), "a/b/c");
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let keeps = |path: &str, expected: &str| {
+        let normalized = Path::new(path).normalize();
+        assert_eq!(normalized.as_os_str(), expected, "{:?}", path);
+    };
+    keeps("C:/a/b/", "C:\\a\\b\\");
+    keeps("C:\\a\\b\\..\\", "C:\\a\\");
+    keeps("C:\\", "C:\\");
+    keeps("C:a/", "C:a\\");
+    assert!(Path::new("C:\\").is_dir_like());
+    assert!(Path::new("a\\").is_dir_like());
+    assert!(Path::new("a\\.").is_dir_like());
+}
@@ -0,0 +1,82 @@
+use std::{cmp::Ordering, ffi::OsStr, path::Path};
+use sugar_path::{natural_cmp, DirPlacement, TreeOrder};
+
+fn cmp(a: &str, b: &str) -> Ordering {
+    natural_cmp(OsStr::new(a), OsStr::new(b))
+}
+
+#[test]
+fn natural() {
+    assert_eq!(cmp("a2", "a10"), Ordering::Less);
+    assert_eq!(cmp("a10", "a2"), Ordering::Greater);
+    assert_eq!(cmp("a", "a"), Ordering::Equal);
+    assert_eq!(cmp("a", "ab"), Ordering::Less);
+    assert_eq!(cmp("B", "a"), Ordering::Greater);
+    assert_eq!(cmp("A", "a"), Ordering::Less);
+    assert_eq!(cmp("a1", "a01"), Ordering::Less);
+    assert_eq!(cmp("a01", "a1b"), Ordering::Less);
+    assert_eq!(cmp("a01b", "a1c"), Ordering::Less);
+    assert_eq!(
+        cmp("x99999999999999999999999", "x100000000000000000000000"),
+        Ordering::Less
+    );
+    assert_eq!(cmp("1.2.10", "1.2.9"), Ordering::Greater);
+    assert_eq!(cmp("a", "1"), Ordering::Greater);
+}
+
+fn sorted<'a>(order: &TreeOrder<impl Fn(&Path) -> bool>, paths: &[&'a str]) -> Vec<&'a str> {
+    let mut paths = paths.to_vec();
+    paths.sort_by(|a, b| order.compare(Path::new(a), Path::new(b)));
+    paths
+}
+
+#[test]
+fn tree_order() {
+    let is_dir = |path: &Path| {
+        ["a", "a/b", "docs", "empty"]
+            .iter()
+            .any(|dir| path == Path::new(dir))
+    };
+    let paths = ["b.txt", "a/x", "docs", "c", "a", "empty", "a/b/c", "A.txt"];
+
+    let order = TreeOrder::new(is_dir);
+    assert_eq!(
+        sorted(&order, &paths),
+        ["a", "a/b/c", "a/x", "docs", "empty", "A.txt", "b.txt", "c"]
+    );
+    let order = TreeOrder::new(is_dir).dirs(DirPlacement::Last);
+    assert_eq!(
+        sorted(&order, &paths),
+        ["A.txt", "b.txt", "c", "a", "a/x", "a/b/c", "docs", "empty"]
+    );
+    let order = TreeOrder::new(is_dir).dirs(DirPlacement::Mixed);
+    assert_eq!(
+        sorted(&order, &paths),
+        ["a", "a/b/c", "a/x", "A.txt", "b.txt", "c", "docs", "empty"]
+    );
+
+    let order = TreeOrder::new(|_: &Path| false);
+    assert_eq!(
+        order.compare(Path::new("a/b"), Path::new("a/b")),
+        Ordering::Equal
+    );
+    assert_eq!(sorted(&order, &["x", "../y", "."]), [".", "../y", "x"]);
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn with_file_system() {
+    use sugar_path::VirtualTree;
+
+    let tree: VirtualTree = ["src/lib.rs", "src/a/mod.rs", "src/b.rs", "README"]
+        .into_iter()
+        .collect();
+    let order = TreeOrder::with_file_system(&tree);
+    assert_eq!(
+        sorted(
+            &order,
+            &["src/b.rs", "src/lib.rs", "src/a", "README", "src"]
+        ),
+        ["src", "src/a", "src/b.rs", "src/lib.rs", "README"]
+    );
+}
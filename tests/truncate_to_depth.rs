@@ -0,0 +1,39 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(Path::new("/a/b/c").truncate_to_depth(0), Path::new("/"));
+    assert_eq!(Path::new("/a/b/c").truncate_to_depth(1), Path::new("/a"));
+    assert_eq!(Path::new("/a/b/c").truncate_to_depth(2), Path::new("/a/b"));
+    assert_eq!(
+        Path::new("/a/b/c").truncate_to_depth(10),
+        Path::new("/a/b/c")
+    );
+    assert_eq!(
+        Path::new("a//./b/../c/d").truncate_to_depth(2),
+        Path::new("a/c")
+    );
+    assert_eq!(Path::new("../../a").truncate_to_depth(1), Path::new(".."));
+    assert_eq!(Path::new("a/b").truncate_to_depth(0).as_os_str(), ".");
+    assert_eq!(Path::new("").truncate_to_depth(3).as_os_str(), ".");
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("C:\\a\\b").truncate_to_depth(0),
+        Path::new("C:\\")
+    );
+    assert_eq!(
+        Path::new("C:\\a\\b").truncate_to_depth(1),
+        Path::new("C:\\a")
+    );
+    assert_eq!(Path::new("C:a\\b").truncate_to_depth(0), Path::new("C:."));
+    assert_eq!(
+        Path::new("\\\\server\\share\\a\\b").truncate_to_depth(1),
+        Path::new("\\\\server\\share\\a")
+    );
+}
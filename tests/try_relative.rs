@@ -0,0 +1,75 @@
+use std::path::Path;
+use sugar_path::{SugarPath, TryRelativeError};
+
+#[test]
+fn same_as_relative() {
+    let base = Path::new("base").resolve();
+    for (path, to) in [
+        ("a/b", "a"),
+        ("a", "a/b/c"),
+        ("a", "a"),
+        ("x/y", "a/b"),
+        ("", "a/.."),
+    ] {
+        let (path, to) = (base.join(path), base.join(to));
+        assert_eq!(path.try_relative(&to).unwrap(), path.relative(&to));
+    }
+    assert_eq!(Path::new("a").try_relative("b").unwrap(), Path::new("../a"));
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use sugar_path::{with_default_platform, Platform};
+
+    assert_eq!(
+        Path::new("/a/b").try_relative("/").unwrap(),
+        Path::new("a/b")
+    );
+    assert_eq!(
+        Path::new("/").try_relative("/a/b").unwrap(),
+        Path::new("../..")
+    );
+    assert_eq!(
+        Path::new("/../a").try_relative("/a/..").unwrap(),
+        Path::new("a")
+    );
+
+    with_default_platform(Platform::Windows, || {
+        for (path, to) in [
+            ("C:\\app\\lib", "c:\\APP\\bin"),
+            ("C:\\app", "C:\\app"),
+            ("C:\\app", "D:\\app"),
+        ] {
+            let relative = Path::new(path).relative(to);
+            match Path::new(path).try_relative(to) {
+                Ok(tried) => assert_eq!(tried.as_os_str(), relative.as_os_str()),
+                Err(_) => assert_eq!(relative.as_os_str(), path),
+            }
+        }
+        assert_eq!(
+            Path::new("C:\\app\\lib")
+                .try_relative("c:/APP/bin")
+                .unwrap()
+                .as_os_str(),
+            "..\\lib"
+        );
+        assert_eq!(
+            Path::new("C:\\app").try_relative("D:\\app"),
+            Err(TryRelativeError::DifferentRoots)
+        );
+    });
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("c:/blah\\blah").try_relative("d:/games"),
+        Err(TryRelativeError::DifferentRoots)
+    );
+    assert_eq!(
+        Path::new("c:/blah\\blah").relative("d:/games"),
+        Path::new("c:\\blah\\blah")
+    );
+}
@@ -0,0 +1,59 @@
+use std::path::Path;
+use sugar_path::{AbsolutePathBuf, RelativePathBuf, SugarPath, TypedPathError};
+
+#[test]
+fn absolute() {
+    assert_eq!(
+        AbsolutePathBuf::new("a"),
+        Err(TypedPathError::NotAbsolute("a".into()))
+    );
+    assert_eq!(
+        AbsolutePathBuf::resolve("a/./b").as_path(),
+        Path::new("a/b").resolve()
+    );
+}
+
+#[test]
+fn relative() {
+    assert_eq!(RelativePathBuf::new("").unwrap().as_path(), Path::new("."));
+    assert_eq!(
+        RelativePathBuf::new("a/..//b/").unwrap().as_path(),
+        Path::new("b/")
+    );
+    assert_eq!(
+        RelativePathBuf::new("../a").unwrap().as_path(),
+        Path::new("../a")
+    );
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(
+        AbsolutePathBuf::new("/a/../b").unwrap().as_path(),
+        Path::new("/b")
+    );
+    assert_eq!(
+        RelativePathBuf::new("/a"),
+        Err(TypedPathError::NotRelative("/a".into()))
+    );
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        AbsolutePathBuf::new("C:\\a\\..\\b").unwrap().as_path(),
+        Path::new("C:\\b")
+    );
+    assert!(AbsolutePathBuf::new("\\a").is_err());
+    assert!(AbsolutePathBuf::new("C:a").is_err());
+    for path in ["C:\\a", "C:a", "\\a", "\\\\server\\share\\a"] {
+        assert_eq!(
+            RelativePathBuf::new(path),
+            Err(TypedPathError::NotRelative(path.into())),
+            "{:?}",
+            path
+        );
+    }
+}
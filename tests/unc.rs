@@ -0,0 +1,32 @@
+use std::path::Path;
+use sugar_path::SugarPath;
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(Path::new("//server/share/foo").unc_server(), None);
+    assert_eq!(Path::new("//server/share/foo").unc_share(), None);
+    assert_eq!(Path::new("//server/share/foo").strip_unc_root(), None);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let path = Path::new("\\\\server\\share\\foo\\bar");
+    assert_eq!(path.unc_server().unwrap(), "server");
+    assert_eq!(path.unc_share().unwrap(), "share");
+    assert_eq!(path.strip_unc_root().unwrap(), Path::new("foo\\bar"));
+
+    let path = Path::new("\\\\?\\UNC\\server\\share\\foo");
+    assert_eq!(path.unc_server().unwrap(), "server");
+    assert_eq!(path.unc_share().unwrap(), "share");
+    assert_eq!(path.strip_unc_root().unwrap(), Path::new("foo"));
+
+    let path = Path::new("//server/share");
+    assert_eq!(path.unc_server().unwrap(), "server");
+    assert_eq!(path.strip_unc_root().unwrap(), Path::new(""));
+
+    assert_eq!(Path::new("C:\\foo").unc_server(), None);
+    assert_eq!(Path::new("C:\\foo").strip_unc_root(), None);
+    assert_eq!(Path::new("\\\\?\\C:\\foo").unc_share(), None);
+}
@@ -0,0 +1,65 @@
+use std::ffi::OsStr;
+use sugar_path::windows::{from_unc, to_unc, UncShare};
+
+fn string(path: Option<std::ffi::OsString>) -> Option<String> {
+    path.map(|path| path.into_string().unwrap())
+}
+
+#[test]
+fn admin_share() {
+    let unc = |path: &str| string(to_unc(OsStr::new(path), "srv"));
+    assert_eq!(
+        unc(r"D:\apps\svc\config.json").as_deref(),
+        Some(r"\\srv\d$\apps\svc\config.json")
+    );
+    assert_eq!(unc(r"c:/apps//svc/").as_deref(), Some(r"\\srv\c$\apps\svc"));
+    assert_eq!(unc(r"C:\..\a\..\b").as_deref(), Some(r"\\srv\c$\b"));
+    assert_eq!(unc(r"C:\").as_deref(), Some(r"\\srv\c$"));
+    assert_eq!(unc(r"C:a"), None);
+    assert_eq!(unc(r"\a"), None);
+    assert_eq!(unc(r"\\other\share\a"), None);
+    assert_eq!(unc(r"\\?\C:\a"), None);
+
+    let local = |path: &str| string(from_unc(OsStr::new(path)));
+    assert_eq!(local(r"\\srv\d$\apps\svc").as_deref(), Some(r"D:\apps\svc"));
+    assert_eq!(local(r"//srv/D$/apps/../x").as_deref(), Some(r"D:\x"));
+    assert_eq!(local(r"\\srv\d$").as_deref(), Some(r"D:\"));
+    assert_eq!(local(r"\\srv\d$\..").as_deref(), Some(r"D:\"));
+    assert_eq!(local(r"\\srv\admin$\x"), None);
+    assert_eq!(local(r"\\srv\1$\x"), None);
+    assert_eq!(local(r"D:\x"), None);
+}
+
+#[test]
+fn share() {
+    let share = UncShare::new("srv", "apps", OsStr::new(r"D:\Apps\")).unwrap();
+    let unc = |path: &str| string(share.to_unc(OsStr::new(path)));
+    assert_eq!(
+        unc(r"D:\apps\svc\x.json").as_deref(),
+        Some(r"\\srv\apps\svc\x.json")
+    );
+    assert_eq!(unc(r"d:\APPS").as_deref(), Some(r"\\srv\apps"));
+    assert_eq!(unc(r"D:\apps\..\data"), None);
+    assert_eq!(unc(r"D:\apps2\x"), None);
+    assert_eq!(unc(r"E:\apps\x"), None);
+
+    let local = |path: &str| string(share.to_local(OsStr::new(path)));
+    assert_eq!(
+        local(r"\\srv\apps\svc\x.json").as_deref(),
+        Some(r"D:\Apps\svc\x.json")
+    );
+    assert_eq!(local(r"\\SRV\Apps").as_deref(), Some(r"D:\Apps"));
+    assert_eq!(local(r"\\srv\apps\..\..\x").as_deref(), Some(r"D:\Apps\x"));
+    assert_eq!(local(r"\\other\apps\x"), None);
+    assert_eq!(local(r"\\srv\data\x"), None);
+    assert_eq!(local(r"\\?\UNC\srv\apps\x"), None);
+
+    assert_eq!(UncShare::new("srv", "apps", OsStr::new(r"apps")), None);
+    assert_eq!(UncShare::new("srv", "apps", OsStr::new(r"D:apps")), None);
+}
+
+#[test]
+#[should_panic]
+fn admin_needs_a_letter() {
+    UncShare::admin("srv", b'1');
+}
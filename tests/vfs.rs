@@ -0,0 +1,55 @@
+#![cfg(feature = "vfs")]
+
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+use sugar_path::{FileKind, FileSystem};
+use vfs::{MemoryFS, VfsPath};
+
+fn tree() -> VfsPath {
+    let root = VfsPath::new(MemoryFS::new());
+    root.join("src/util").unwrap().create_dir_all().unwrap();
+    for file in ["src/main.rs", "src/util/mod.rs", "README.md"] {
+        write!(root.join(file).unwrap().create_file().unwrap(), "{}", file).unwrap();
+    }
+    root
+}
+
+#[test]
+fn vfs_path() {
+    let root = tree();
+    let metadata = FileSystem::metadata(&root, Path::new("README.md")).unwrap();
+    assert_eq!(metadata.kind, FileKind::File);
+    assert_eq!(metadata.len, "README.md".len() as u64);
+    assert!(FileSystem::metadata(&root, Path::new("src/util/.."))
+        .unwrap()
+        .is_dir());
+    assert!(FileSystem::metadata(&root, Path::new("")).unwrap().is_dir());
+    assert_eq!(
+        FileSystem::metadata(&root, Path::new("missing"))
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::NotFound
+    );
+    assert!(FileSystem::exists(&root, Path::new("src/util/mod.rs")));
+    assert!(!FileSystem::exists(&root, Path::new("src/util/lib.rs")));
+    assert!(!FileSystem::exists(&root, Path::new("../outside")));
+
+    assert_eq!(
+        FileSystem::read_dir(&root, Path::new(".")).unwrap(),
+        [PathBuf::from("./README.md"), PathBuf::from("./src")]
+    );
+    assert_eq!(
+        FileSystem::read_dir(&root, Path::new("src")).unwrap(),
+        [PathBuf::from("src/main.rs"), PathBuf::from("src/util")]
+    );
+    assert!(FileSystem::read_link(&root, Path::new("src/main.rs")).is_err());
+
+    // Relative paths start from the `VfsPath`, absolute ones from the root of its filesystem.
+    let src = root.join("src").unwrap();
+    assert!(FileSystem::exists(&src, Path::new("main.rs")));
+    assert!(FileSystem::exists(&src, Path::new("/src/main.rs")));
+    assert!(!FileSystem::exists(&src, Path::new("/main.rs")));
+    assert!(FileSystem::exists(&src, Path::new("../README.md")));
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "fs")]
+
+use std::path::{Path, PathBuf};
+use sugar_path::{Pattern, SugarPath, VirtualTree, WalkOptions};
+
+fn tree() -> VirtualTree {
+    [
+        "project/Cargo.toml",
+        "project/src/lib.rs",
+        "project/src/a/mod.rs",
+        "project/src/a/b/deep.rs",
+        "project/tests/it.rs",
+        "project/README.md",
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn virtual_tree() {
+    let tree = tree();
+    let options = WalkOptions::new();
+    let glob = |pattern: &str| {
+        options
+            .glob(&tree, "project", &Pattern::new(pattern).unwrap())
+            .unwrap()
+    };
+    assert_eq!(glob("*.toml"), paths(&["project/Cargo.toml"]));
+    assert_eq!(
+        glob("src/*"),
+        paths(&["project/src/a", "project/src/lib.rs"])
+    );
+    assert_eq!(
+        glob("src/**/*.rs"),
+        paths(&[
+            "project/src/a/b/deep.rs",
+            "project/src/a/mod.rs",
+            "project/src/lib.rs"
+        ])
+    );
+    assert_eq!(glob("missing/*"), paths(&[]));
+
+    let rglob = |options: WalkOptions, pattern: &str| {
+        options
+            .rglob(&tree, "./project/", &Pattern::new(pattern).unwrap())
+            .unwrap()
+    };
+    assert_eq!(
+        rglob(options, "*.rs"),
+        paths(&[
+            "project/src/a/b/deep.rs",
+            "project/src/a/mod.rs",
+            "project/src/lib.rs",
+            "project/tests/it.rs"
+        ])
+    );
+    assert_eq!(
+        rglob(options.max_depth(2), "*.rs"),
+        paths(&["project/src/lib.rs", "project/tests/it.rs"])
+    );
+    assert_eq!(rglob(options.max_depth(0), "*"), paths(&[]));
+
+    assert!(options
+        .glob(&tree, "nowhere", &Pattern::new("*").unwrap())
+        .is_err());
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::{fs, os::unix::fs::symlink};
+
+    let root = std::env::temp_dir().join(format!("sugar_path_walk_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("a/b")).unwrap();
+    fs::create_dir_all(root.join("other")).unwrap();
+    fs::write(root.join("a/b/x.txt"), "").unwrap();
+    fs::write(root.join("other/y.txt"), "").unwrap();
+    symlink(root.join("other"), root.join("a/link")).unwrap();
+    // Points back up the tree, which would never end if followed.
+    symlink("..", root.join("a/b/up")).unwrap();
+
+    let pattern = Pattern::new("*.txt").unwrap();
+    let found = root.rglob(&pattern).unwrap();
+    assert_eq!(found, [root.join("a/b/x.txt"), root.join("other/y.txt")]);
+
+    let found = WalkOptions::new()
+        .follow_symlinks(true)
+        .rglob(&sugar_path::StdFileSystem, &root, &pattern)
+        .unwrap();
+    assert_eq!(
+        found,
+        [
+            root.join("a/b/x.txt"),
+            root.join("a/link/y.txt"),
+            root.join("other/y.txt")
+        ]
+    );
+    assert!(found.iter().all(|path| *path == path.normalize()));
+
+    let found = Path::new(&root)
+        .glob(&Pattern::new("a/*").unwrap())
+        .unwrap();
+    assert_eq!(found, [root.join("a/b"), root.join("a/link")]);
+
+    // A link back to a root given relative to the current directory is still caught.
+    symlink(&root, root.join("other/root")).unwrap();
+    let relative = root.relative(std::env::current_dir().unwrap());
+    let found = WalkOptions::new()
+        .follow_symlinks(true)
+        .rglob(&sugar_path::StdFileSystem, &relative, &pattern)
+        .unwrap();
+    assert_eq!(
+        found,
+        [
+            relative.join("a/b/x.txt"),
+            relative.join("a/link/y.txt"),
+            relative.join("other/y.txt")
+        ]
+    );
+
+    fs::remove_dir_all(&root).unwrap();
+}
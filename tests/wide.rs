@@ -0,0 +1,71 @@
+use std::ffi::OsStr;
+use sugar_path::windows::{from_wide, normalize_wide, to_wide};
+
+fn wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().collect()
+}
+
+#[test]
+fn round_trip() {
+    for path in ["", "a", r"C:\Users\José\😀.txt", r"\\server\share\a b"] {
+        let encoded = to_wide(OsStr::new(path)).unwrap();
+        assert_eq!(encoded.last(), Some(&0));
+        assert_eq!(encoded[..encoded.len() - 1], wide(path)[..]);
+        assert_eq!(from_wide(&encoded).unwrap(), path);
+    }
+    assert_eq!(from_wide(&wide("a\0b")).unwrap(), "a");
+    assert_eq!(from_wide(&[]).unwrap(), "");
+    assert_eq!(to_wide(OsStr::new("a\0")), None);
+}
+
+#[test]
+fn normalize() {
+    for (path, expected) in [
+        (r"C:/a/./b/../c", r"C:\a\c"),
+        (r"C:\a\..\..\b\", r"C:\b\"),
+        (r"//server/share/../x", r"\\server\share\x"),
+        (r"\\?\C:\a\..\b", r"\\?\C:\a\..\b"),
+        ("a/../..", ".."),
+        ("", "."),
+    ] {
+        let normalized = normalize_wide(&wide(path)).unwrap();
+        assert_eq!(from_wide(&normalized).unwrap(), expected, "{}", path);
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    use std::os::unix::ffi::OsStrExt;
+
+    // A lone surrogate can't be held by an `OsString` here.
+    assert_eq!(from_wide(&[b'a' as u16, 0xd800]), None);
+    assert_eq!(normalize_wide(&[0xdc00]), None);
+    assert_eq!(to_wide(OsStr::from_bytes(b"\xff")), None);
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    use std::path::Path;
+    use sugar_path::{AbsolutePathBuf, NormalizedPathBuf, SugarPath};
+
+    // Unpaired surrogates survive the whole way.
+    let mut lone = wide(r"C:\a\..\");
+    lone.push(0xd800);
+    let normalized = normalize_wide(&lone).unwrap();
+    let mut expected = wide(r"C:\");
+    expected.extend([0xd800, 0]);
+    assert_eq!(normalized, expected);
+
+    let path = from_wide(&lone).unwrap();
+    assert_eq!(
+        Path::new(&path).normalize(),
+        Path::new(&from_wide(&expected).unwrap())
+    );
+
+    let typed = NormalizedPathBuf::from_wide(&lone);
+    assert_eq!(typed.to_wide().unwrap(), expected);
+    assert!(AbsolutePathBuf::from_wide(&wide("C:\\a")).is_ok());
+    assert!(AbsolutePathBuf::from_wide(&wide("a")).is_err());
+}
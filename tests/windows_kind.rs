@@ -0,0 +1,72 @@
+use std::ffi::OsStr;
+use sugar_path::windows::{classify_windows_kind, WindowsPathKind};
+
+fn kind(path: &str) -> WindowsPathKind {
+    classify_windows_kind(OsStr::new(path))
+}
+
+#[test]
+fn classify() {
+    use WindowsPathKind::*;
+
+    for (path, expected) in [
+        (r"C:\", DriveAbsolute),
+        ("c:/foo", DriveAbsolute),
+        ("C:", DriveRelative),
+        ("C:foo", DriveRelative),
+        (r"C:..\foo", DriveRelative),
+        (r"\", Rooted),
+        (r"\foo", Rooted),
+        (r"\??\C:\foo", Rooted),
+        (r"\\server\share\foo", Unc),
+        ("//server/share", Unc),
+        (r"\\server", Unc),
+        (r"\\?\C:\foo", Verbatim),
+        (r"\\?\UNC\server\share", Verbatim),
+        (r"\\?\Volume{abc}\foo", Verbatim),
+        (r"\\.\COM1", Device),
+        ("//./pipe/name", Device),
+        ("", Relative),
+        ("foo", Relative),
+        (r"..\foo", Relative),
+        ("1:foo", Relative),
+    ] {
+        assert_eq!(kind(path), expected, "{}", path);
+    }
+    // `\\` alone has no server, so it is just a root.
+    assert_eq!(kind(r"\\"), Rooted);
+}
+
+#[test]
+fn is_absolute() {
+    assert!(kind(r"C:\foo").is_absolute());
+    assert!(kind(r"\\server\share").is_absolute());
+    assert!(kind(r"\\?\C:\foo").is_absolute());
+    assert!(kind(r"\\.\COM1").is_absolute());
+    assert!(!kind("C:foo").is_absolute());
+    assert!(!kind(r"\foo").is_absolute());
+    assert!(!kind("foo").is_absolute());
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    use std::path::Path;
+
+    for path in [
+        r"C:\foo",
+        "C:foo",
+        r"\foo",
+        r"\\server\share\x",
+        r"\\?\C:\x",
+        r"\\.\COM1",
+        "foo",
+    ] {
+        assert_eq!(
+            kind(path).is_absolute(),
+            Path::new(path).is_absolute(),
+            "{}",
+            path
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use std::path::Path;
+use sugar_path::{ComponentError, SugarPath};
+
+#[test]
+fn with_name() {
+    assert_eq!(Path::new("a/b").with_name("c").unwrap(), Path::new("a/c"));
+    assert_eq!(Path::new("b").with_name("c").unwrap(), Path::new("c"));
+    assert_eq!(Path::new("a/b/").with_name("c").unwrap(), Path::new("a/c"));
+    assert_eq!(Path::new("a/b/..").with_name("c").unwrap(), Path::new("c"));
+    assert_eq!(Path::new("../b").with_name("c").unwrap(), Path::new("../c"));
+
+    for name in ["", ".", "..", "../x", "x/y", "x\0y"] {
+        assert_eq!(
+            Path::new("a/b").with_name(name),
+            Err(ComponentError::InvalidSegment(name.into())),
+            "{:?}",
+            name
+        );
+    }
+    for path in ["", ".", "..", "a/../.."] {
+        assert_eq!(
+            Path::new(path).with_name("x"),
+            Err(ComponentError::NoFileName),
+            "{:?}",
+            path
+        );
+    }
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    assert_eq!(Path::new("/a/b").with_name("c").unwrap(), Path::new("/a/c"));
+    assert_eq!(
+        Path::new("/").with_name("c"),
+        Err(ComponentError::NoFileName)
+    );
+    assert_eq!(Path::new("a").with_name("b\\c").unwrap(), Path::new("b\\c"));
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    assert_eq!(
+        Path::new("C:\\a\\b").with_name("c").unwrap(),
+        Path::new("C:\\a\\c")
+    );
+    assert_eq!(
+        Path::new("C:\\").with_name("c"),
+        Err(ComponentError::NoFileName)
+    );
+    assert!(Path::new("a").with_name("b\\c").is_err());
+}
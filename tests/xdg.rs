@@ -0,0 +1,96 @@
+#![cfg(feature = "xdg")]
+
+use std::{ffi::OsString, path::PathBuf};
+use sugar_path::{XdgDir, XdgDirs};
+
+fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<OsString> + 'a {
+    move |name| {
+        vars.iter()
+            .find(|(var, _)| *var == name)
+            .map(|(_, value)| OsString::from(value))
+    }
+}
+
+#[test]
+fn env_var() {
+    assert_eq!(XdgDir::Config.env_var(), "XDG_CONFIG_HOME");
+    assert_eq!(XdgDir::Runtime.env_var(), "XDG_RUNTIME_DIR");
+}
+
+#[test]
+fn no_home() {
+    let dirs = XdgDirs::with_env(env(&[("HOME", ""), ("XDG_CONFIG_HOME", "relative")]));
+    for dir in [
+        XdgDir::Config,
+        XdgDir::Cache,
+        XdgDir::Data,
+        XdgDir::State,
+        XdgDir::Runtime,
+    ] {
+        assert_eq!(dirs.dir(dir), None);
+    }
+    assert_eq!(dirs.join(XdgDir::Config, "x"), None);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn unix() {
+    let dirs = XdgDirs::with_env(env(&[("HOME", "/home/me/")]));
+    assert_eq!(dirs.config_home(), Some(PathBuf::from("/home/me/.config")));
+    assert_eq!(dirs.cache_home(), Some(PathBuf::from("/home/me/.cache")));
+    assert_eq!(
+        dirs.data_home(),
+        Some(PathBuf::from("/home/me/.local/share"))
+    );
+    assert_eq!(
+        dirs.state_home(),
+        Some(PathBuf::from("/home/me/.local/state"))
+    );
+    assert_eq!(dirs.runtime_dir(), None);
+
+    let dirs = XdgDirs::with_env(env(&[
+        ("HOME", "/home/me"),
+        ("XDG_CONFIG_HOME", "/etc/./me/"),
+        ("XDG_CACHE_HOME", ""),
+        ("XDG_DATA_HOME", "data"),
+        ("XDG_STATE_HOME", "/x/../state"),
+        ("XDG_RUNTIME_DIR", "/run/user/1000"),
+    ]));
+    let config = dirs.config_home().unwrap();
+    assert_eq!(config.as_os_str(), "/etc/me");
+    assert_eq!(dirs.cache_home(), Some(PathBuf::from("/home/me/.cache")));
+    assert_eq!(
+        dirs.data_home(),
+        Some(PathBuf::from("/home/me/.local/share"))
+    );
+    assert_eq!(dirs.state_home(), Some(PathBuf::from("/state")));
+    assert_eq!(dirs.runtime_dir(), Some(PathBuf::from("/run/user/1000")));
+    assert_eq!(
+        dirs.join(XdgDir::Runtime, "app.sock"),
+        Some(PathBuf::from("/run/user/1000/app.sock"))
+    );
+    assert_eq!(
+        dirs.join(XdgDir::Config, "/abs"),
+        Some(PathBuf::from("/abs"))
+    );
+
+    let dirs = XdgDirs::with_env(env(&[("HOME", "home/me")]));
+    assert_eq!(dirs.config_home(), None);
+
+    let _ = XdgDirs::new().config_home();
+    let _ = XdgDirs::default().runtime_dir();
+}
+
+#[cfg(target_family = "windows")]
+#[test]
+fn windows() {
+    let dirs = XdgDirs::with_env(env(&[("HOME", "C:\\Users\\me")]));
+    assert_eq!(
+        dirs.config_home(),
+        Some(PathBuf::from("C:\\Users\\me\\.config"))
+    );
+    assert_eq!(
+        dirs.data_home(),
+        Some(PathBuf::from("C:\\Users\\me\\.local\\share"))
+    );
+}